@@ -0,0 +1,79 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.10.0.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+// These functions are ignored because they are not marked as `pub`: `is_key_char`, `parse_a1111_parameters`, `parse_settings_line`, `read_quoted`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `clone`, `eq`, `fmt`
+
+/// AUTOMATIC1111 / Forge 的 `parameters` 文本解析结果
+class A1111Parameters {
+  final String prompt;
+  final String? negativePrompt;
+  final int? steps;
+  final String? sampler;
+  final String? scheduleType;
+  final double? cfgScale;
+  final BigInt? seed;
+  final int? width;
+  final int? height;
+  final String? model;
+  final String? modelHash;
+  final double? denoisingStrength;
+  /// 参数行及其后附加行的全部键值对，按原始顺序保存，引号已去除
+  final List<(String, String)> settings;
+
+  const A1111Parameters({
+    required this.prompt,
+    this.negativePrompt,
+    this.steps,
+    this.sampler,
+    this.scheduleType,
+    this.cfgScale,
+    this.seed,
+    this.width,
+    this.height,
+    this.model,
+    this.modelHash,
+    this.denoisingStrength,
+    required this.settings,
+  });
+
+  @override
+  int get hashCode =>
+      prompt.hashCode ^
+      negativePrompt.hashCode ^
+      steps.hashCode ^
+      sampler.hashCode ^
+      scheduleType.hashCode ^
+      cfgScale.hashCode ^
+      seed.hashCode ^
+      width.hashCode ^
+      height.hashCode ^
+      model.hashCode ^
+      modelHash.hashCode ^
+      denoisingStrength.hashCode ^
+      settings.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is A1111Parameters &&
+          runtimeType == other.runtimeType &&
+          prompt == other.prompt &&
+          negativePrompt == other.negativePrompt &&
+          steps == other.steps &&
+          sampler == other.sampler &&
+          scheduleType == other.scheduleType &&
+          cfgScale == other.cfgScale &&
+          seed == other.seed &&
+          width == other.width &&
+          height == other.height &&
+          model == other.model &&
+          modelHash == other.modelHash &&
+          denoisingStrength == other.denoisingStrength &&
+          settings == other.settings;
+}
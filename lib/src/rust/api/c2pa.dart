@@ -0,0 +1,115 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.10.0.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+// These functions are ignored because they are not marked as `pub`: `cbor_to_json`, `child_superboxes`, `content_value`, `describe_c2pa_manifests`, `has_c2pa_manifest`, `jpeg_c2pa_instances`, `jpeg_jumbf`, `jumbf_instance`, `manifest_store`, `parse_manifest`, `read_assertion`, `read_boxes`, `read_c2pa_manifests`, `read_superbox`, `software_name`, `text`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `clone`, `clone`, `clone`, `eq`, `eq`, `eq`, `fmt`, `fmt`, `fmt`
+
+/// `c2pa.actions` 断言中的一个操作
+class C2paAction {
+  /// 例如 `c2pa.created`、`c2pa.edited`
+  final String action;
+  final String? softwareAgent;
+  /// IPTC 数字来源类型，AI 生成的图片为 `http://cv.iptc.org/newscodes/digitalsourcetype/trainedAlgorithmicMedia`
+  final String? digitalSourceType;
+  final String? when;
+
+  const C2paAction({
+    required this.action,
+    this.softwareAgent,
+    this.digitalSourceType,
+    this.when,
+  });
+
+  @override
+  int get hashCode =>
+      action.hashCode ^
+      softwareAgent.hashCode ^
+      digitalSourceType.hashCode ^
+      when.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is C2paAction &&
+          runtimeType == other.runtimeType &&
+          action == other.action &&
+          softwareAgent == other.softwareAgent &&
+          digitalSourceType == other.digitalSourceType &&
+          when == other.when;
+}
+
+/// `c2pa.ingredient` 断言，记录编辑时使用的原始素材
+class C2paIngredient {
+  final String? title;
+  final String? format;
+  /// `parentOf` / `componentOf` / `inputTo`
+  final String? relationship;
+
+  const C2paIngredient({this.title, this.format, this.relationship});
+
+  @override
+  int get hashCode => title.hashCode ^ format.hashCode ^ relationship.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is C2paIngredient &&
+          runtimeType == other.runtimeType &&
+          title == other.title &&
+          format == other.format &&
+          relationship == other.relationship;
+}
+
+/// 清单存储中的一个 C2PA 清单 (内容凭据)
+class C2paManifest {
+  /// 清单的 JUMBF 标签，例如 `urn:uuid:...`
+  final String label;
+  /// 写入清单的工具，优先使用 `claim_generator_info` 中的名称和版本，例如 `DALL·E`
+  final String? claimGenerator;
+  /// `dc:title`
+  final String? title;
+  /// `dc:format`
+  final String? format;
+  final List<C2paAction> actions;
+  final List<C2paIngredient> ingredients;
+  /// 全部断言的标签，例如 `c2pa.actions.v2`、`c2pa.hash.data`
+  final List<String> assertionLabels;
+
+  const C2paManifest({
+    required this.label,
+    this.claimGenerator,
+    this.title,
+    this.format,
+    required this.actions,
+    required this.ingredients,
+    required this.assertionLabels,
+  });
+
+  @override
+  int get hashCode =>
+      label.hashCode ^
+      claimGenerator.hashCode ^
+      title.hashCode ^
+      format.hashCode ^
+      actions.hashCode ^
+      ingredients.hashCode ^
+      assertionLabels.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is C2paManifest &&
+          runtimeType == other.runtimeType &&
+          label == other.label &&
+          claimGenerator == other.claimGenerator &&
+          title == other.title &&
+          format == other.format &&
+          actions == other.actions &&
+          ingredients == other.ingredients &&
+          assertionLabels == other.assertionLabels;
+}
@@ -0,0 +1,114 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.10.0.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+// These functions are ignored because they are not marked as `pub`: `class_type`, `extract_comfyui_metadata`, `input`, `link_target`, `linked_node`, `node_string_input`, `parse_lora`, `resolve_text`, `resolve_value`, `sorted_nodes`, `summarize_prompt_graph`, `walk_model_chain`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `clone`, `clone`, `clone`, `eq`, `eq`, `eq`, `fmt`, `fmt`, `fmt`
+
+class ComfyUiLora {
+  final String name;
+  final double? strengthModel;
+  final double? strengthClip;
+
+  const ComfyUiLora({
+    required this.name,
+    this.strengthModel,
+    this.strengthClip,
+  });
+
+  @override
+  int get hashCode =>
+      name.hashCode ^ strengthModel.hashCode ^ strengthClip.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is ComfyUiLora &&
+          runtimeType == other.runtimeType &&
+          name == other.name &&
+          strengthModel == other.strengthModel &&
+          strengthClip == other.strengthClip;
+}
+
+/// ComfyUI 图片中的两个文本块，以及从 API 图中解析出的摘要
+class ComfyUiMetadata {
+  /// `prompt` 文本块 (API 格式的节点图)
+  final String? promptGraph;
+  /// `workflow` 文本块 (UI 格式的节点图)
+  final String? workflow;
+  final ComfyUiSummary? summary;
+
+  const ComfyUiMetadata({this.promptGraph, this.workflow, this.summary});
+
+  @override
+  int get hashCode =>
+      promptGraph.hashCode ^ workflow.hashCode ^ summary.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is ComfyUiMetadata &&
+          runtimeType == other.runtimeType &&
+          promptGraph == other.promptGraph &&
+          workflow == other.workflow &&
+          summary == other.summary;
+}
+
+class ComfyUiSummary {
+  final String? positivePrompt;
+  final String? negativePrompt;
+  final BigInt? seed;
+  final int? steps;
+  final double? cfg;
+  final String? samplerName;
+  final String? scheduler;
+  final double? denoise;
+  final String? checkpoint;
+  final List<ComfyUiLora> loras;
+
+  const ComfyUiSummary({
+    this.positivePrompt,
+    this.negativePrompt,
+    this.seed,
+    this.steps,
+    this.cfg,
+    this.samplerName,
+    this.scheduler,
+    this.denoise,
+    this.checkpoint,
+    required this.loras,
+  });
+
+  @override
+  int get hashCode =>
+      positivePrompt.hashCode ^
+      negativePrompt.hashCode ^
+      seed.hashCode ^
+      steps.hashCode ^
+      cfg.hashCode ^
+      samplerName.hashCode ^
+      scheduler.hashCode ^
+      denoise.hashCode ^
+      checkpoint.hashCode ^
+      loras.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is ComfyUiSummary &&
+          runtimeType == other.runtimeType &&
+          positivePrompt == other.positivePrompt &&
+          negativePrompt == other.negativePrompt &&
+          seed == other.seed &&
+          steps == other.steps &&
+          cfg == other.cfg &&
+          samplerName == other.samplerName &&
+          scheduler == other.scheduler &&
+          denoise == other.denoise &&
+          checkpoint == other.checkpoint &&
+          loras == other.loras;
+}
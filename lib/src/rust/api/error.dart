@@ -0,0 +1,22 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.10.0.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `clone`, `eq`, `fmt`, `fmt`, `from`
+
+/// 错误类别，供界面显示和筛选损坏的文件
+enum MetadataErrorKind {
+  unsupportedFormat,
+  decodeFailed,
+  invalidDimensions,
+  truncatedStealthPayload,
+  oversizedStealthPayload,
+  invalidStealthPayload,
+  notFound,
+  permissionDenied,
+  io,
+}
@@ -0,0 +1,25 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.10.0.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+// These functions are ignored because they are not marked as `pub`: `detect_generator`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `clone`, `eq`, `fmt`
+
+/// 生成图片的工具
+enum Generator {
+  novelAi,
+  /// AUTOMATIC1111 WebUI 及 Forge 等兼容的分支
+  a1111,
+  comfyUi,
+  invokeAi,
+  fooocus,
+  swarmUi,
+  midjourney,
+  /// DALL·E 等只写入 C2PA 内容凭据的工具
+  c2pa,
+  unknown,
+}
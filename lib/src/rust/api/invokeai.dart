@@ -0,0 +1,206 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.10.0.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+// These functions are ignored because they are not marked as `pub`: `add_control_layer`, `array`, `dream_metadata`, `extract_invokeai_metadata`, `graph_metadata_node`, `graph_nodes`, `is_enabled`, `legacy_metadata`, `model_name`, `node_type`, `parse_canvas_layers`, `parse_lora`, `prompt_fields`, `summarize_graph`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `clone`, `clone`, `clone`, `clone`, `eq`, `eq`, `eq`, `eq`, `fmt`, `fmt`, `fmt`, `fmt`
+
+class InvokeAiControlLayer {
+  /// `controlnet`、`t2i_adapter` 或 `ip_adapter`
+  final String adapterType;
+  final String? model;
+  final double? weight;
+  final double? beginStepPercent;
+  final double? endStepPercent;
+  final bool isEnabled;
+
+  const InvokeAiControlLayer({
+    required this.adapterType,
+    this.model,
+    this.weight,
+    this.beginStepPercent,
+    this.endStepPercent,
+    required this.isEnabled,
+  });
+
+  @override
+  int get hashCode =>
+      adapterType.hashCode ^
+      model.hashCode ^
+      weight.hashCode ^
+      beginStepPercent.hashCode ^
+      endStepPercent.hashCode ^
+      isEnabled.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is InvokeAiControlLayer &&
+          runtimeType == other.runtimeType &&
+          adapterType == other.adapterType &&
+          model == other.model &&
+          weight == other.weight &&
+          beginStepPercent == other.beginStepPercent &&
+          endStepPercent == other.endStepPercent &&
+          isEnabled == other.isEnabled;
+}
+
+class InvokeAiLora {
+  final String name;
+  final double? weight;
+
+  const InvokeAiLora({required this.name, this.weight});
+
+  @override
+  int get hashCode => name.hashCode ^ weight.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is InvokeAiLora &&
+          runtimeType == other.runtimeType &&
+          name == other.name &&
+          weight == other.weight;
+}
+
+/// InvokeAI 图片中的 `invokeai_metadata` 和 `invokeai_graph` 文本块，以及解析出的生成参数
+class InvokeAiMetadata {
+  /// `invokeai_metadata` 文本块 (生成参数)
+  final String? metadata;
+  /// `invokeai_graph` 文本块 (节点图)
+  final String? graph;
+  final String? appVersion;
+  /// 例如 `txt2img`、`sdxl_img2img`
+  final String? generationMode;
+  final String? positivePrompt;
+  final String? negativePrompt;
+  /// SDXL 的风格提示词
+  final String? positiveStylePrompt;
+  final String? negativeStylePrompt;
+  final BigInt? seed;
+  final int? steps;
+  final double? cfgScale;
+  final double? cfgRescaleMultiplier;
+  final String? scheduler;
+  final int? width;
+  final int? height;
+  final String? model;
+  /// InvokeAI 模型管理器记录的哈希，例如 `blake3:...`
+  final String? modelHash;
+  final String? vae;
+  final List<InvokeAiLora> loras;
+  /// 画布中的区域提示词
+  final List<InvokeAiRegionalPrompt> regionalPrompts;
+  /// ControlNet、T2I-Adapter 和 IP-Adapter
+  final List<InvokeAiControlLayer> controlLayers;
+
+  const InvokeAiMetadata({
+    this.metadata,
+    this.graph,
+    this.appVersion,
+    this.generationMode,
+    this.positivePrompt,
+    this.negativePrompt,
+    this.positiveStylePrompt,
+    this.negativeStylePrompt,
+    this.seed,
+    this.steps,
+    this.cfgScale,
+    this.cfgRescaleMultiplier,
+    this.scheduler,
+    this.width,
+    this.height,
+    this.model,
+    this.modelHash,
+    this.vae,
+    required this.loras,
+    required this.regionalPrompts,
+    required this.controlLayers,
+  });
+
+  @override
+  int get hashCode =>
+      metadata.hashCode ^
+      graph.hashCode ^
+      appVersion.hashCode ^
+      generationMode.hashCode ^
+      positivePrompt.hashCode ^
+      negativePrompt.hashCode ^
+      positiveStylePrompt.hashCode ^
+      negativeStylePrompt.hashCode ^
+      seed.hashCode ^
+      steps.hashCode ^
+      cfgScale.hashCode ^
+      cfgRescaleMultiplier.hashCode ^
+      scheduler.hashCode ^
+      width.hashCode ^
+      height.hashCode ^
+      model.hashCode ^
+      modelHash.hashCode ^
+      vae.hashCode ^
+      loras.hashCode ^
+      regionalPrompts.hashCode ^
+      controlLayers.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is InvokeAiMetadata &&
+          runtimeType == other.runtimeType &&
+          metadata == other.metadata &&
+          graph == other.graph &&
+          appVersion == other.appVersion &&
+          generationMode == other.generationMode &&
+          positivePrompt == other.positivePrompt &&
+          negativePrompt == other.negativePrompt &&
+          positiveStylePrompt == other.positiveStylePrompt &&
+          negativeStylePrompt == other.negativeStylePrompt &&
+          seed == other.seed &&
+          steps == other.steps &&
+          cfgScale == other.cfgScale &&
+          cfgRescaleMultiplier == other.cfgRescaleMultiplier &&
+          scheduler == other.scheduler &&
+          width == other.width &&
+          height == other.height &&
+          model == other.model &&
+          modelHash == other.modelHash &&
+          vae == other.vae &&
+          loras == other.loras &&
+          regionalPrompts == other.regionalPrompts &&
+          controlLayers == other.controlLayers;
+}
+
+class InvokeAiRegionalPrompt {
+  final String? name;
+  final String? positivePrompt;
+  final String? negativePrompt;
+  final bool isEnabled;
+
+  const InvokeAiRegionalPrompt({
+    this.name,
+    this.positivePrompt,
+    this.negativePrompt,
+    required this.isEnabled,
+  });
+
+  @override
+  int get hashCode =>
+      name.hashCode ^
+      positivePrompt.hashCode ^
+      negativePrompt.hashCode ^
+      isEnabled.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is InvokeAiRegionalPrompt &&
+          runtimeType == other.runtimeType &&
+          name == other.name &&
+          positivePrompt == other.positivePrompt &&
+          negativePrompt == other.negativePrompt &&
+          isEnabled == other.isEnabled;
+}
@@ -4,38 +4,107 @@
 // ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
 
 import '../frb_generated.dart';
+import 'a1111.dart';
+import 'c2pa.dart';
+import 'comfyui.dart';
+import 'error.dart';
+import 'generator.dart';
+import 'invokeai.dart';
+import 'nai.dart';
+import 'networks.dart';
 import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+import 'xmp.dart';
 
-// These functions are ignored because they are not marked as `pub`: `extract_exif`, `extract_nai_data`
+// These functions are ignored because they are not marked as `pub`: `decode_compressed_text_chunk`, `decode_international_text_chunk`, `decode_text_chunk`, `decode_user_comment`, `decode_utf16`, `decode_utf16_with`, `extract_general_info`, `extract_nai_data`, `find_stealth_channel`, `find_stealth_payload`, `inflate`, `latin1_to_string`, `may_contain_stealth_data`, `nai_signature_matches`, `non_empty_text`, `png_chunks`, `read_exif_software`, `read_exif_text`, `read_image_info`, `read_png_text_chunks`, `remove_signed_hash`, `split_null`, `webp_chunks`, `write_stealth_bits`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `clone`, `clone`, `eq`, `eq`, `fmt`, `fmt`
+
+/// 把元数据 gzip 压缩后写入像素最低位 (`stealth_pngcomp` / `stealth_rgbcomp`)，返回 PNG 数据
+///
+/// 布局与读取时一致: 按列优先顺序依次写入 magic、以 bit 为单位的 32 位长度和压缩后的数据。
+/// 输出统一为 8 位 PNG，alpha 模式下没有 alpha 通道的图片会补上不透明的 alpha
+Future<Uint8List> embedStealthMetadata({
+  required List<int> inputBytes,
+  required String metadata,
+  required StealthChannel channel,
+}) => RustLib.instance.api.crateApiMetadataEmbedStealthMetadata(
+  inputBytes: inputBytes,
+  metadata: metadata,
+  channel: channel,
+);
 
 ImageInfo extractMetadata({required List<int> inputBytes}) => RustLib
     .instance
     .api
     .crateApiMetadataExtractMetadata(inputBytes: inputBytes);
 
-// Rust type: RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<DataReader>>
-abstract class DataReader implements RustOpaqueInterface {
-  // HINT: Make it `#[frb(sync)]` to let it become the default constructor of Dart class.
-  static Future<DataReader> newInstance({required List<int> data}) =>
-      RustLib.instance.api.crateApiMetadataDataReaderNew(data: data);
-
-  Future<int> readBit();
-
-  Future<int> readByte();
-
-  Future<Uint8List> readBytes({required BigInt n});
-
-  Future<int> readInt32();
-}
+/// 离线验证 NovelAI 图片的 ed25519 签名
+///
+/// `signed_hash` 是对 sha256(RGB 像素 + 去掉 `signed_hash` 后的 Comment) 的签名。
+/// 不包含 alpha 通道，因此隐写数据不影响验证。Comment 优先读取 PNG 文本块，其次是隐写数据
+NaiSignatureStatus verifyNaiSignature({required List<int> inputBytes}) => RustLib.instance.api.crateApiMetadataVerifyNaiSignature(
+  inputBytes: inputBytes,
+);
 
 class ImageInfo {
   final double aspectRatio;
+  final Generator generator;
   final String? metadataString;
+  final NaiMetadata? naiMetadata;
+  final A1111Parameters? a1111Parameters;
+  /// Fooocus 和 SwarmUI 的参数，转换为与 A1111 相同的字段，原始键值对保存在 `settings` 中
+  final A1111Parameters? fooocusParameters;
+  final A1111Parameters? swarmuiParameters;
+  final ComfyUiMetadata? comfyui;
+  final InvokeAiMetadata? invokeai;
+  /// 从 A1111 参数和 ComfyUI 节点图中汇总的 LoRA、嵌入和超网络
+  final List<NetworkReference> networks;
+  final List<PngTextChunk> textChunks;
+  /// PNG `XML:com.adobe.xmp` 文本块、JPEG APP1 段或 WebP `XMP ` 块中的原始 XMP 数据
+  final String? xmpPacket;
+  final XmpMetadata? xmp;
+  /// C2PA 内容凭据 (DALL·E、Adobe Firefly 等)，最后一个是当前图片的清单
+  final List<C2paManifest> c2paManifests;
+  /// 找到 stealth magic 但隐写数据损坏时的错误类别和描述，其余字段仍然来自文本块和 EXIF
+  final MetadataErrorKind? stealthErrorKind;
+  final String? stealthErrorMessage;
 
-  const ImageInfo({required this.aspectRatio, this.metadataString});
+  const ImageInfo({
+    required this.aspectRatio,
+    required this.generator,
+    this.metadataString,
+    this.naiMetadata,
+    this.a1111Parameters,
+    this.fooocusParameters,
+    this.swarmuiParameters,
+    this.comfyui,
+    this.invokeai,
+    required this.networks,
+    required this.textChunks,
+    this.xmpPacket,
+    this.xmp,
+    required this.c2paManifests,
+    this.stealthErrorKind,
+    this.stealthErrorMessage,
+  });
 
   @override
-  int get hashCode => aspectRatio.hashCode ^ metadataString.hashCode;
+  int get hashCode =>
+      aspectRatio.hashCode ^
+      generator.hashCode ^
+      metadataString.hashCode ^
+      naiMetadata.hashCode ^
+      a1111Parameters.hashCode ^
+      fooocusParameters.hashCode ^
+      swarmuiParameters.hashCode ^
+      comfyui.hashCode ^
+      invokeai.hashCode ^
+      networks.hashCode ^
+      textChunks.hashCode ^
+      xmpPacket.hashCode ^
+      xmp.hashCode ^
+      c2paManifests.hashCode ^
+      stealthErrorKind.hashCode ^
+      stealthErrorMessage.hashCode;
 
   @override
   bool operator ==(Object other) =>
@@ -43,5 +112,77 @@ class ImageInfo {
       other is ImageInfo &&
           runtimeType == other.runtimeType &&
           aspectRatio == other.aspectRatio &&
-          metadataString == other.metadataString;
+          generator == other.generator &&
+          metadataString == other.metadataString &&
+          naiMetadata == other.naiMetadata &&
+          a1111Parameters == other.a1111Parameters &&
+          fooocusParameters == other.fooocusParameters &&
+          swarmuiParameters == other.swarmuiParameters &&
+          comfyui == other.comfyui &&
+          invokeai == other.invokeai &&
+          networks == other.networks &&
+          textChunks == other.textChunks &&
+          xmpPacket == other.xmpPacket &&
+          xmp == other.xmp &&
+          c2paManifests == other.c2paManifests &&
+          stealthErrorKind == other.stealthErrorKind &&
+          stealthErrorMessage == other.stealthErrorMessage;
 }
+
+/// NovelAI 图片签名的验证结果
+enum NaiSignatureStatus {
+  /// 签名有效，像素和 Comment 与生成时一致
+  valid,
+  /// 有签名但验证失败，像素或 Comment 被修改过
+  tampered,
+  /// Comment 中没有 `signed_hash` (v3 之前的图片，或不是 NAI 生成的图片)
+  unsigned,
+}
+
+class PngTextChunk {
+  final PngTextChunkType chunkType;
+  final String keyword;
+  final String text;
+  /// 仅 iTXt 块包含语言标签和翻译后的关键字
+  final String? languageTag;
+  final String? translatedKeyword;
+
+  const PngTextChunk({
+    required this.chunkType,
+    required this.keyword,
+    required this.text,
+    this.languageTag,
+    this.translatedKeyword,
+  });
+
+  @override
+  int get hashCode =>
+      chunkType.hashCode ^
+      keyword.hashCode ^
+      text.hashCode ^
+      languageTag.hashCode ^
+      translatedKeyword.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is PngTextChunk &&
+          runtimeType == other.runtimeType &&
+          chunkType == other.chunkType &&
+          keyword == other.keyword &&
+          text == other.text &&
+          languageTag == other.languageTag &&
+          translatedKeyword == other.translatedKeyword;
+}
+
+enum PngTextChunkType {
+  /// tEXt
+  text,
+  /// zTXt
+  compressedText,
+  /// iTXt
+  internationalText,
+}
+
+/// 隐写数据所在的通道: alpha 每个像素 1 bit，RGB 每个像素 3 bit
+enum StealthChannel { alpha, rgb }
@@ -0,0 +1,77 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.10.0.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+// These functions are ignored because they are not marked as `pub`: `hash_model_file`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `clone`, `eq`, `fmt`
+
+/// 递归索引文件夹中的 safetensors / ckpt 模型并计算哈希
+///
+/// 计算完整文件的 sha256 很慢，`cached_models` 中路径、大小和修改时间都一致的文件直接复用已有结果。
+/// 无法读取的文件会被跳过
+Future<List<ModelFile>> indexModelFolder({
+  required String folderPath,
+  required List<ModelFile> cachedModels,
+}) => RustLib.instance.api.crateApiModelsIndexModelFolder(
+  folderPath: folderPath,
+  cachedModels: cachedModels,
+);
+
+/// 根据图片中的模型哈希查找本地模型文件
+///
+/// 接受 AutoV1 (8 位)、AutoV2 (10 位) 或完整的 sha256，大小写不敏感。
+/// 也可以传入 `model.ckpt [a9263745]` 这样的模型标题，此时取最后一段
+ModelFile? resolveModelHash({
+  required String modelHash,
+  required List<ModelFile> models,
+}) => RustLib.instance.api.crateApiModelsResolveModelHash(
+  modelHash: modelHash,
+  models: models,
+);
+
+/// 本地模型文件及其哈希
+class ModelFile {
+  final String filePath;
+  final BigInt fileSize;
+  final BigInt fileLastModified;
+  /// 完整文件的 sha256，小写十六进制
+  final String sha256;
+  /// 旧版 WebUI 的 8 位哈希: 偏移 1 MiB 处 64 KiB 数据的 sha256
+  final String autoV1;
+  /// 新版 WebUI 的 10 位哈希: 完整 sha256 的前 10 位
+  final String autoV2;
+
+  const ModelFile({
+    required this.filePath,
+    required this.fileSize,
+    required this.fileLastModified,
+    required this.sha256,
+    required this.autoV1,
+    required this.autoV2,
+  });
+
+  @override
+  int get hashCode =>
+      filePath.hashCode ^
+      fileSize.hashCode ^
+      fileLastModified.hashCode ^
+      sha256.hashCode ^
+      autoV1.hashCode ^
+      autoV2.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is ModelFile &&
+          runtimeType == other.runtimeType &&
+          filePath == other.filePath &&
+          fileSize == other.fileSize &&
+          fileLastModified == other.fileLastModified &&
+          sha256 == other.sha256 &&
+          autoV1 == other.autoV1 &&
+          autoV2 == other.autoV2;
+}
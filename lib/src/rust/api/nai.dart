@@ -0,0 +1,195 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.10.0.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+// These functions are ignored because they are not marked as `pub`: `get_reference_values`, `nai_metadata_from_root`, `parse_char_caption`, `parse_nai_metadata`, `parse_nai_text_chunks`, `parse_v4_prompt`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `clone`, `clone`, `clone`, `clone`, `eq`, `eq`, `eq`, `eq`, `fmt`, `fmt`, `fmt`, `fmt`
+
+/// V4 角色提示词 (V4 character caption)
+class NaiCharCaption {
+  final String charCaption;
+  final List<NaiCoordinate> centers;
+
+  const NaiCharCaption({required this.charCaption, required this.centers});
+
+  @override
+  int get hashCode => charCaption.hashCode ^ centers.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is NaiCharCaption &&
+          runtimeType == other.runtimeType &&
+          charCaption == other.charCaption &&
+          centers == other.centers;
+}
+
+class NaiCoordinate {
+  final double x;
+  final double y;
+
+  const NaiCoordinate({required this.x, required this.y});
+
+  @override
+  int get hashCode => x.hashCode ^ y.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is NaiCoordinate &&
+          runtimeType == other.runtimeType &&
+          x == other.x &&
+          y == other.y;
+}
+
+/// NovelAI 生成参数 (NovelAI generation parameters)
+class NaiMetadata {
+  final String? title;
+  final String? description;
+  final String? software;
+  final String? source;
+  final double? generationTime;
+  final String? prompt;
+  final String? negativePrompt;
+  final BigInt? seed;
+  final int? steps;
+  final String? sampler;
+  final String? noiseSchedule;
+  final double? scale;
+  final double? cfgRescale;
+  final double? uncondScale;
+  final int? width;
+  final int? height;
+  final int? nSamples;
+  final bool? sm;
+  final bool? smDyn;
+  final String? requestType;
+  final String? signedHash;
+  final NaiV4Prompt? v4Prompt;
+  final NaiV4Prompt? v4NegativePrompt;
+  final List<double> referenceInformationExtracted;
+  final List<double> referenceStrength;
+
+  const NaiMetadata({
+    this.title,
+    this.description,
+    this.software,
+    this.source,
+    this.generationTime,
+    this.prompt,
+    this.negativePrompt,
+    this.seed,
+    this.steps,
+    this.sampler,
+    this.noiseSchedule,
+    this.scale,
+    this.cfgRescale,
+    this.uncondScale,
+    this.width,
+    this.height,
+    this.nSamples,
+    this.sm,
+    this.smDyn,
+    this.requestType,
+    this.signedHash,
+    this.v4Prompt,
+    this.v4NegativePrompt,
+    required this.referenceInformationExtracted,
+    required this.referenceStrength,
+  });
+
+  @override
+  int get hashCode =>
+      title.hashCode ^
+      description.hashCode ^
+      software.hashCode ^
+      source.hashCode ^
+      generationTime.hashCode ^
+      prompt.hashCode ^
+      negativePrompt.hashCode ^
+      seed.hashCode ^
+      steps.hashCode ^
+      sampler.hashCode ^
+      noiseSchedule.hashCode ^
+      scale.hashCode ^
+      cfgRescale.hashCode ^
+      uncondScale.hashCode ^
+      width.hashCode ^
+      height.hashCode ^
+      nSamples.hashCode ^
+      sm.hashCode ^
+      smDyn.hashCode ^
+      requestType.hashCode ^
+      signedHash.hashCode ^
+      v4Prompt.hashCode ^
+      v4NegativePrompt.hashCode ^
+      referenceInformationExtracted.hashCode ^
+      referenceStrength.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is NaiMetadata &&
+          runtimeType == other.runtimeType &&
+          title == other.title &&
+          description == other.description &&
+          software == other.software &&
+          source == other.source &&
+          generationTime == other.generationTime &&
+          prompt == other.prompt &&
+          negativePrompt == other.negativePrompt &&
+          seed == other.seed &&
+          steps == other.steps &&
+          sampler == other.sampler &&
+          noiseSchedule == other.noiseSchedule &&
+          scale == other.scale &&
+          cfgRescale == other.cfgRescale &&
+          uncondScale == other.uncondScale &&
+          width == other.width &&
+          height == other.height &&
+          nSamples == other.nSamples &&
+          sm == other.sm &&
+          smDyn == other.smDyn &&
+          requestType == other.requestType &&
+          signedHash == other.signedHash &&
+          v4Prompt == other.v4Prompt &&
+          v4NegativePrompt == other.v4NegativePrompt &&
+          referenceInformationExtracted == other.referenceInformationExtracted &&
+          referenceStrength == other.referenceStrength;
+}
+
+/// V4 模型的结构化提示词 (Structured V4 prompt)
+class NaiV4Prompt {
+  final String baseCaption;
+  final List<NaiCharCaption> charCaptions;
+  final bool? useCoords;
+  final bool? useOrder;
+
+  const NaiV4Prompt({
+    required this.baseCaption,
+    required this.charCaptions,
+    this.useCoords,
+    this.useOrder,
+  });
+
+  @override
+  int get hashCode =>
+      baseCaption.hashCode ^
+      charCaptions.hashCode ^
+      useCoords.hashCode ^
+      useOrder.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is NaiV4Prompt &&
+          runtimeType == other.runtimeType &&
+          baseCaption == other.baseCaption &&
+          charCaptions == other.charCaptions &&
+          useCoords == other.useCoords &&
+          useOrder == other.useOrder;
+}
@@ -0,0 +1,58 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.10.0.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+// These functions are ignored because they are not marked as `pub`: `add_network`, `collect_a1111_networks`, `collect_fooocus_networks`, `collect_network_references`, `collect_swarmui_networks`, `comfyui_embeddings`, `network`, `normalize_name`, `parse_hashes`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `clone`, `clone`, `eq`, `eq`, `fmt`, `fmt`
+
+enum NetworkKind {
+  /// LoRA / LyCORIS
+  lora,
+  /// Textual Inversion
+  embedding,
+  hypernetwork,
+}
+
+/// 生成图片时使用的附加网络
+class NetworkReference {
+  final NetworkKind kind;
+  /// 去除目录和扩展名后的名称，例如 `loras/style.safetensors` 记为 `style`
+  final String name;
+  /// LoRA 和超网络为强度 (ComfyUI 中为 `strength_model`)，嵌入为提示词中的权重
+  final double? weight;
+  /// ComfyUI LoRA 加载器的 `strength_clip`
+  final double? clipWeight;
+  /// A1111 `Lora hashes` / `TI hashes` / `Hypernet hash` 中记录的哈希
+  final String? hash;
+
+  const NetworkReference({
+    required this.kind,
+    required this.name,
+    this.weight,
+    this.clipWeight,
+    this.hash,
+  });
+
+  @override
+  int get hashCode =>
+      kind.hashCode ^
+      name.hashCode ^
+      weight.hashCode ^
+      clipWeight.hashCode ^
+      hash.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is NetworkReference &&
+          runtimeType == other.runtimeType &&
+          kind == other.kind &&
+          name == other.name &&
+          weight == other.weight &&
+          clipWeight == other.clipWeight &&
+          hash == other.hash;
+}
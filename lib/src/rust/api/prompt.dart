@@ -0,0 +1,105 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.10.0.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+// These functions are ignored because they are not marked as `pub`: `escape_a1111`, `format_number`, `pieces_number`, `push_text_tag`, `push_words`, `render_a1111_tag`, `render_nai_tag`, `round_weight`, `split_tags`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `clone`, `clone`, `clone`, `eq`, `eq`, `eq`, `fmt`, `fmt`, `fmt`
+
+/// 把提示词从一种语法转换为另一种，等价于 `parse_prompt` 后再 `render_prompt`
+String convertPrompt({
+  required String prompt,
+  required PromptDialect from,
+  required PromptDialect to,
+}) => RustLib.instance.api.crateApiPromptConvertPrompt(
+  prompt: prompt,
+  from: from,
+  to: to,
+);
+
+/// 把提示词拆分为标签，标签之间以逗号或换行分隔
+///
+/// 括号不匹配时按 WebUI 的行为处理: 未闭合的括号作用到结尾，多余的右括号忽略
+List<PromptTag> parsePrompt({
+  required String prompt,
+  required PromptDialect dialect,
+}) => RustLib.instance.api.crateApiPromptParsePrompt(
+  prompt: prompt,
+  dialect: dialect,
+);
+
+/// 把标签按指定语法重新拼接为提示词，标签之间以 `, ` 分隔
+///
+/// 权重的取整规则:
+/// - A1111: 权重保留 2 位小数，为 1 时不加括号，否则写作 `(tag:1.1)`
+/// - NAI: 权重与 1.05 的整数次幂相差小于 0.005 时写作对应层数的 `{}` / `[]`，最多 20 层，
+///   否则写作保留 2 位小数的 `1.2::tag::`
+///
+/// 因此 `{{tag}}` (1.1025) 转换为 `(tag:1.1)`，`(tag:1.1)` 再转换回 `{{tag}}`；
+/// 20 层以内的 NAI 括号和 2 位小数的 A1111 权重都可以无损往返。
+///
+/// NAI 不支持的 LoRA、超网络、`BREAK` 和提示词编辑会被丢弃，只保留标签本身
+String renderPrompt({
+  required List<PromptTag> tags,
+  required PromptDialect dialect,
+}) => RustLib.instance.api.crateApiPromptRenderPrompt(
+  tags: tags,
+  dialect: dialect,
+);
+
+/// 提示词语法
+enum PromptDialect {
+  /// `{tag}` / `[tag]` 强调和 `1.2::tag::` 权重
+  novelAi,
+  /// `(tag:1.2)`、`[from:to:when]`、`BREAK` 和 `<lora:name:0.8>`
+  a1111,
+}
+
+/// 提示词中的一个标签及其实际权重
+class PromptTag {
+  final String text;
+  /// 嵌套括号的权重相乘后的结果，保留 4 位小数；LoRA 为网络强度
+  final double weight;
+  final PromptTagKind kind;
+  /// 提示词编辑 `[from:to:when]` 中开始和停止生效的位置，小于 1 时为步数比例
+  final double? startsAt;
+  final double? endsAt;
+
+  const PromptTag({
+    required this.text,
+    required this.weight,
+    required this.kind,
+    this.startsAt,
+    this.endsAt,
+  });
+
+  @override
+  int get hashCode =>
+      text.hashCode ^
+      weight.hashCode ^
+      kind.hashCode ^
+      startsAt.hashCode ^
+      endsAt.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is PromptTag &&
+          runtimeType == other.runtimeType &&
+          text == other.text &&
+          weight == other.weight &&
+          kind == other.kind &&
+          startsAt == other.startsAt &&
+          endsAt == other.endsAt;
+}
+
+enum PromptTagKind {
+  tag,
+  lora,
+  hypernetwork,
+  /// A1111 的 `BREAK`，把提示词分成独立的 75 token 块
+  break,
+}
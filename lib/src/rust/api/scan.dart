@@ -4,6 +4,7 @@
 // ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
 
 import '../frb_generated.dart';
+import 'error.dart';
 import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
 
 // These functions are ignored because they are not marked as `pub`: `process_single_image`
@@ -46,13 +47,19 @@ class ImageScanResult {
   final String filePath;
   final BigInt fileLastModified;
   final double? imageAspectRatio;
+  /// 用于搜索的元数据文本，只有 C2PA 清单的图片使用清单摘要
   final String? metadataText;
+  /// 文件无法读取或解析时的错误类别和描述。隐写数据损坏时也会记录，此时其余字段仍然有效
+  final MetadataErrorKind? errorKind;
+  final String? errorMessage;
 
   const ImageScanResult({
     required this.filePath,
     required this.fileLastModified,
     this.imageAspectRatio,
     this.metadataText,
+    this.errorKind,
+    this.errorMessage,
   });
 
   @override
@@ -60,7 +67,9 @@ class ImageScanResult {
       filePath.hashCode ^
       fileLastModified.hashCode ^
       imageAspectRatio.hashCode ^
-      metadataText.hashCode;
+      metadataText.hashCode ^
+      errorKind.hashCode ^
+      errorMessage.hashCode;
 
   @override
   bool operator ==(Object other) =>
@@ -70,7 +79,9 @@ class ImageScanResult {
           filePath == other.filePath &&
           fileLastModified == other.fileLastModified &&
           imageAspectRatio == other.imageAspectRatio &&
-          metadataText == other.metadataText;
+          metadataText == other.metadataText &&
+          errorKind == other.errorKind &&
+          errorMessage == other.errorMessage;
 }
 
 class ScanProgress {
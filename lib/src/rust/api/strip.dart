@@ -0,0 +1,72 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.10.0.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+// These functions are ignored because they are not marked as `pub`: `clear_stealth_bits`, `encode_image`, `original_prompt`, `strip_jpeg`, `strip_png`, `strip_webp`, `verify_stripped`, `write_prompt`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `clone`, `fmt`
+
+/// 生成去除元数据后的图片副本，格式保持不变
+///
+/// 完成后重新提取一次元数据，确认选中的内容都已删除，否则返回错误
+Future<Uint8List> stripMetadata({
+  required List<int> inputBytes,
+  required StripOptions options,
+}) => RustLib.instance.api.crateApiStripStripMetadata(
+  inputBytes: inputBytes,
+  options: options,
+);
+
+/// 导出时要删除的元数据
+class StripOptions {
+  /// PNG 的 tEXt / zTXt / iTXt 块 (包括 PNG 中的 XMP) 和 JPEG 的 COM 注释
+  final bool removeTextChunks;
+  /// PNG 的 eXIf 块、JPEG 的 APP1 EXIF 段和 WebP 的 `EXIF` 块
+  final bool removeExif;
+  /// PNG 的 `XML:com.adobe.xmp` 文本块、JPEG 的 APP1 XMP 段和 WebP 的 `XMP ` 块
+  final bool removeXmp;
+  /// C2PA 内容凭据: PNG 的 `caBX` 块、JPEG 的 APP11 JUMBF 段和 WebP 的 `C2PA` 块
+  final bool removeC2pa;
+  /// 像素最低位中的 stealth 数据，普通的删除数据块无法去除，需要无损地重新编码像素
+  final bool removeStealth;
+  /// 只保留正向提示词，种子、模型路径等其他参数都不保留
+  ///
+  /// 无论其他选项如何，原有的文本 (PNG 的文本块、JPEG 的 COM 注释、EXIF 的 UserComment、
+  /// ImageDescription 和 XPComment) 和 stealth 数据都会删除，再把提示词写入 PNG 的
+  /// `Description` 文本块或 EXIF ImageDescription。其余 EXIF 字段和 XMP 仍按
+  /// `remove_exif` / `remove_xmp` 处理
+  final bool keepPrompt;
+
+  const StripOptions({
+    required this.removeTextChunks,
+    required this.removeExif,
+    required this.removeXmp,
+    required this.removeC2pa,
+    required this.removeStealth,
+    required this.keepPrompt,
+  });
+
+  @override
+  int get hashCode =>
+      removeTextChunks.hashCode ^
+      removeExif.hashCode ^
+      removeXmp.hashCode ^
+      removeC2pa.hashCode ^
+      removeStealth.hashCode ^
+      keepPrompt.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is StripOptions &&
+          runtimeType == other.runtimeType &&
+          removeTextChunks == other.removeTextChunks &&
+          removeExif == other.removeExif &&
+          removeXmp == other.removeXmp &&
+          removeC2pa == other.removeC2pa &&
+          removeStealth == other.removeStealth &&
+          keepPrompt == other.keepPrompt;
+}
@@ -0,0 +1,27 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.10.0.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'metadata.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+// These functions are ignored because they are not marked as `pub`: `build_exif`, `complete_png_chunks`, `complete_webp_chunks`, `deflate`, `encode_text_chunk`, `encode_user_comment`, `exif_texts`, `exif_thumbnail`, `jpeg_exif`, `jpeg_segments`, `rewrite_jpeg_exif`, `rewrite_metadata`, `rewrite_png_text_chunks`, `rewrite_webp_exif`, `riff_container`, `to_latin1`, `vp8l_has_alpha`, `write_png_chunk`, `write_webp_chunk`
+
+/// 把文本写回图片文件，不重新编码像素，其他数据块保持原样
+///
+/// - PNG: 用 `chunks` 替换文件中全部 tEXt / zTXt / iTXt 块
+/// - JPEG / WebP: 写入 EXIF，关键字为 `ImageDescription` 的文本写入 ImageDescription，
+///   其他关键字 (例如 `parameters`) 写入 UserComment，两者各自最多一个
+///
+/// `chunks` 是写入后的完整文本列表，文件中原有但不在列表里的文本会被删除
+///
+/// 先写入同目录下的临时文件再替换原文件，失败时原文件不受影响
+Future<void> writeMetadata({
+  required String path,
+  required List<PngTextChunk> chunks,
+}) => RustLib.instance.api.crateApiWriterWriteMetadata(
+  path: path,
+  chunks: chunks,
+);
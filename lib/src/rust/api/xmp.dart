@@ -0,0 +1,81 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.10.0.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+// These functions are ignored because they are not marked as `pub`: `exceeds_element_depth`, `join_key`, `parse_xmp`, `qualified_name`, `read_fields`, `read_value`, `top_level_values`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `clone`, `clone`, `eq`, `eq`, `fmt`, `fmt`
+
+/// XMP 数据包中的常用字段，以及全部属性的键值对
+class XmpMetadata {
+  /// `dc:description`，多语言时优先使用 `x-default`
+  final String? description;
+  /// `dc:subject` (关键字)
+  final List<String> subject;
+  /// `xmp:Rating`，-1 表示拒绝，0 表示未评级
+  final double? rating;
+  /// `exif:UserComment`
+  final String? userComment;
+  final List<XmpProperty> properties;
+
+  const XmpMetadata({
+    this.description,
+    required this.subject,
+    this.rating,
+    this.userComment,
+    required this.properties,
+  });
+
+  @override
+  int get hashCode =>
+      description.hashCode ^
+      subject.hashCode ^
+      rating.hashCode ^
+      userComment.hashCode ^
+      properties.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is XmpMetadata &&
+          runtimeType == other.runtimeType &&
+          description == other.description &&
+          subject == other.subject &&
+          rating == other.rating &&
+          userComment == other.userComment &&
+          properties == other.properties;
+}
+
+/// XMP 中的一个值，数组的每一项各占一个
+class XmpProperty {
+  final String namespace;
+  /// 带前缀的名称，例如 `dc:subject`；结构体的字段以 `/` 连接，例如 `exif:Flash/exif:Fired`
+  final String key;
+  final String value;
+  /// 多语言数组 (`rdf:Alt`) 中的 `xml:lang`
+  final String? language;
+
+  const XmpProperty({
+    required this.namespace,
+    required this.key,
+    required this.value,
+    this.language,
+  });
+
+  @override
+  int get hashCode =>
+      namespace.hashCode ^ key.hashCode ^ value.hashCode ^ language.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is XmpProperty &&
+          runtimeType == other.runtimeType &&
+          namespace == other.namespace &&
+          key == other.key &&
+          value == other.value &&
+          language == other.language;
+}
@@ -3,9 +3,24 @@
 
 // ignore_for_file: unused_import, unused_element, unnecessary_import, duplicate_ignore, invalid_use_of_internal_member, annotate_overrides, non_constant_identifier_names, curly_braces_in_flow_control_structures, prefer_const_literals_to_create_immutables, unused_field
 
+import 'api/a1111.dart';
+import 'api/c2pa.dart';
+import 'api/comfyui.dart';
+import 'api/error.dart';
+import 'api/fooocus.dart';
+import 'api/generator.dart';
+import 'api/invokeai.dart';
 import 'api/metadata.dart';
+import 'api/models.dart';
+import 'api/nai.dart';
+import 'api/networks.dart';
+import 'api/prompt.dart';
 import 'api/scan.dart';
 import 'api/simple.dart';
+import 'api/strip.dart';
+import 'api/swarmui.dart';
+import 'api/writer.dart';
+import 'api/xmp.dart';
 import 'dart:async';
 import 'dart:convert';
 import 'frb_generated.dart';
@@ -66,7 +81,7 @@ class RustLib extends BaseEntrypoint<RustLibApi, RustLibApiImpl, RustLibWire> {
   String get codegenVersion => '2.10.0';
 
   @override
-  int get rustContentHash => 302778711;
+  int get rustContentHash => 306968300;
 
   static const kDefaultExternalLibraryLoaderConfig =
       ExternalLibraryLoaderConfig(
@@ -77,37 +92,62 @@ class RustLib extends BaseEntrypoint<RustLibApi, RustLibApiImpl, RustLibWire> {
 }
 
 abstract class RustLibApi extends BaseApi {
-  Future<DataReader> crateApiMetadataDataReaderNew({required List<int> data});
+  Future<Uint8List> crateApiMetadataEmbedStealthMetadata({
+    required List<int> inputBytes,
+    required String metadata,
+    required StealthChannel channel,
+  });
+
+  ImageInfo crateApiMetadataExtractMetadata({required List<int> inputBytes});
 
-  Future<int> crateApiMetadataDataReaderReadBit({required DataReader that});
+  NaiSignatureStatus crateApiMetadataVerifyNaiSignature({
+    required List<int> inputBytes,
+  });
 
-  Future<int> crateApiMetadataDataReaderReadByte({required DataReader that});
+  Future<List<ModelFile>> crateApiModelsIndexModelFolder({
+    required String folderPath,
+    required List<ModelFile> cachedModels,
+  });
 
-  Future<Uint8List> crateApiMetadataDataReaderReadBytes({
-    required DataReader that,
-    required BigInt n,
+  ModelFile? crateApiModelsResolveModelHash({
+    required String modelHash,
+    required List<ModelFile> models,
   });
 
-  Future<int> crateApiMetadataDataReaderReadInt32({required DataReader that});
+  String crateApiPromptConvertPrompt({
+    required String prompt,
+    required PromptDialect from,
+    required PromptDialect to,
+  });
 
-  ImageInfo crateApiMetadataExtractMetadata({required List<int> inputBytes});
+  List<PromptTag> crateApiPromptParsePrompt({
+    required String prompt,
+    required PromptDialect dialect,
+  });
+
+  String crateApiPromptRenderPrompt({
+    required List<PromptTag> tags,
+    required PromptDialect dialect,
+  });
 
   String crateApiSimpleGreet({required String name});
 
   Future<void> crateApiSimpleInitApp();
 
+  Future<Uint8List> crateApiStripStripMetadata({
+    required List<int> inputBytes,
+    required StripOptions options,
+  });
+
+  Future<void> crateApiWriterWriteMetadata({
+    required String path,
+    required List<PngTextChunk> chunks,
+  });
+
   Stream<ScanProgress> crateApiScanScanFolder({
     required String folderPath,
     required Map<String, BigInt> existingImages,
   });
-
-  RustArcIncrementStrongCountFnType
-  get rust_arc_increment_strong_count_DataReader;
-
-  RustArcDecrementStrongCountFnType
-  get rust_arc_decrement_strong_count_DataReader;
-
-  CrossPlatformFinalizerArg get rust_arc_decrement_strong_count_DataReaderPtr;
 }
 
 class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
@@ -119,12 +159,18 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   });
 
   @override
-  Future<DataReader> crateApiMetadataDataReaderNew({required List<int> data}) {
+  Future<Uint8List> crateApiMetadataEmbedStealthMetadata({
+    required List<int> inputBytes,
+    required String metadata,
+    required StealthChannel channel,
+  }) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_list_prim_u_8_loose(data, serializer);
+          sse_encode_list_prim_u_8_loose(inputBytes, serializer);
+          sse_encode_String(metadata, serializer);
+          sse_encode_stealth_channel(channel, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
@@ -133,99 +179,87 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           );
         },
         codec: SseCodec(
-          decodeSuccessData:
-              sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDataReader,
-          decodeErrorData: null,
+          decodeSuccessData: sse_decode_list_prim_u_8_strict,
+          decodeErrorData: sse_decode_AnyhowException,
         ),
-        constMeta: kCrateApiMetadataDataReaderNewConstMeta,
-        argValues: [data],
+        constMeta: kCrateApiMetadataEmbedStealthMetadataConstMeta,
+        argValues: [inputBytes, metadata, channel],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiMetadataDataReaderNewConstMeta =>
-      const TaskConstMeta(debugName: "DataReader_new", argNames: ["data"]);
+  TaskConstMeta get kCrateApiMetadataEmbedStealthMetadataConstMeta =>
+      const TaskConstMeta(
+        debugName: "embed_stealth_metadata",
+        argNames: ["inputBytes", "metadata", "channel"],
+      );
 
   @override
-  Future<int> crateApiMetadataDataReaderReadBit({required DataReader that}) {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
+  ImageInfo crateApiMetadataExtractMetadata({required List<int> inputBytes}) {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDataReader(
-            that,
-            serializer,
-          );
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 2,
-            port: port_,
-          );
+          sse_encode_list_prim_u_8_loose(inputBytes, serializer);
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 2)!;
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_u_8,
-          decodeErrorData: null,
+          decodeSuccessData: sse_decode_image_info,
+          decodeErrorData: sse_decode_AnyhowException,
         ),
-        constMeta: kCrateApiMetadataDataReaderReadBitConstMeta,
-        argValues: [that],
+        constMeta: kCrateApiMetadataExtractMetadataConstMeta,
+        argValues: [inputBytes],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiMetadataDataReaderReadBitConstMeta =>
-      const TaskConstMeta(debugName: "DataReader_read_bit", argNames: ["that"]);
+  TaskConstMeta get kCrateApiMetadataExtractMetadataConstMeta =>
+      const TaskConstMeta(
+        debugName: "extract_metadata",
+        argNames: ["inputBytes"],
+      );
 
   @override
-  Future<int> crateApiMetadataDataReaderReadByte({required DataReader that}) {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
+  NaiSignatureStatus crateApiMetadataVerifyNaiSignature({
+    required List<int> inputBytes,
+  }) {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDataReader(
-            that,
-            serializer,
-          );
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 3,
-            port: port_,
-          );
+          sse_encode_list_prim_u_8_loose(inputBytes, serializer);
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 3)!;
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_u_8,
-          decodeErrorData: null,
+          decodeSuccessData: sse_decode_nai_signature_status,
+          decodeErrorData: sse_decode_AnyhowException,
         ),
-        constMeta: kCrateApiMetadataDataReaderReadByteConstMeta,
-        argValues: [that],
+        constMeta: kCrateApiMetadataVerifyNaiSignatureConstMeta,
+        argValues: [inputBytes],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiMetadataDataReaderReadByteConstMeta =>
+  TaskConstMeta get kCrateApiMetadataVerifyNaiSignatureConstMeta =>
       const TaskConstMeta(
-        debugName: "DataReader_read_byte",
-        argNames: ["that"],
+        debugName: "verify_nai_signature",
+        argNames: ["inputBytes"],
       );
 
   @override
-  Future<Uint8List> crateApiMetadataDataReaderReadBytes({
-    required DataReader that,
-    required BigInt n,
+  Future<List<ModelFile>> crateApiModelsIndexModelFolder({
+    required String folderPath,
+    required List<ModelFile> cachedModels,
   }) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDataReader(
-            that,
-            serializer,
-          );
-          sse_encode_usize(n, serializer);
+          sse_encode_String(folderPath, serializer);
+          sse_encode_list_model_file(cachedModels, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
@@ -234,82 +268,142 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_list_prim_u_8_strict,
-          decodeErrorData: null,
+          decodeSuccessData: sse_decode_list_model_file,
+          decodeErrorData: sse_decode_AnyhowException,
         ),
-        constMeta: kCrateApiMetadataDataReaderReadBytesConstMeta,
-        argValues: [that, n],
+        constMeta: kCrateApiModelsIndexModelFolderConstMeta,
+        argValues: [folderPath, cachedModels],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiMetadataDataReaderReadBytesConstMeta =>
+  TaskConstMeta get kCrateApiModelsIndexModelFolderConstMeta =>
       const TaskConstMeta(
-        debugName: "DataReader_read_bytes",
-        argNames: ["that", "n"],
+        debugName: "index_model_folder",
+        argNames: ["folderPath", "cachedModels"],
       );
 
   @override
-  Future<int> crateApiMetadataDataReaderReadInt32({required DataReader that}) {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
+  ModelFile? crateApiModelsResolveModelHash({
+    required String modelHash,
+    required List<ModelFile> models,
+  }) {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDataReader(
-            that,
-            serializer,
-          );
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 5,
-            port: port_,
-          );
+          sse_encode_String(modelHash, serializer);
+          sse_encode_list_model_file(models, serializer);
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 5)!;
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_i_32,
+          decodeSuccessData: sse_decode_opt_box_autoadd_model_file,
           decodeErrorData: null,
         ),
-        constMeta: kCrateApiMetadataDataReaderReadInt32ConstMeta,
-        argValues: [that],
+        constMeta: kCrateApiModelsResolveModelHashConstMeta,
+        argValues: [modelHash, models],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiMetadataDataReaderReadInt32ConstMeta =>
+  TaskConstMeta get kCrateApiModelsResolveModelHashConstMeta =>
       const TaskConstMeta(
-        debugName: "DataReader_read_int32",
-        argNames: ["that"],
+        debugName: "resolve_model_hash",
+        argNames: ["modelHash", "models"],
       );
 
   @override
-  ImageInfo crateApiMetadataExtractMetadata({required List<int> inputBytes}) {
+  String crateApiPromptConvertPrompt({
+    required String prompt,
+    required PromptDialect from,
+    required PromptDialect to,
+  }) {
     return handler.executeSync(
       SyncTask(
         callFfi: () {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_list_prim_u_8_loose(inputBytes, serializer);
+          sse_encode_String(prompt, serializer);
+          sse_encode_prompt_dialect(from, serializer);
+          sse_encode_prompt_dialect(to, serializer);
           return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 6)!;
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_image_info,
-          decodeErrorData: sse_decode_AnyhowException,
+          decodeSuccessData: sse_decode_String,
+          decodeErrorData: null,
         ),
-        constMeta: kCrateApiMetadataExtractMetadataConstMeta,
-        argValues: [inputBytes],
+        constMeta: kCrateApiPromptConvertPromptConstMeta,
+        argValues: [prompt, from, to],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiMetadataExtractMetadataConstMeta =>
+  TaskConstMeta get kCrateApiPromptConvertPromptConstMeta =>
       const TaskConstMeta(
-        debugName: "extract_metadata",
-        argNames: ["inputBytes"],
+        debugName: "convert_prompt",
+        argNames: ["prompt", "from", "to"],
       );
 
+  @override
+  List<PromptTag> crateApiPromptParsePrompt({
+    required String prompt,
+    required PromptDialect dialect,
+  }) {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(prompt, serializer);
+          sse_encode_prompt_dialect(dialect, serializer);
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 7)!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_list_prompt_tag,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiPromptParsePromptConstMeta,
+        argValues: [prompt, dialect],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiPromptParsePromptConstMeta => const TaskConstMeta(
+    debugName: "parse_prompt",
+    argNames: ["prompt", "dialect"],
+  );
+
+  @override
+  String crateApiPromptRenderPrompt({
+    required List<PromptTag> tags,
+    required PromptDialect dialect,
+  }) {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_list_prompt_tag(tags, serializer);
+          sse_encode_prompt_dialect(dialect, serializer);
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 8)!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_String,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiPromptRenderPromptConstMeta,
+        argValues: [tags, dialect],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiPromptRenderPromptConstMeta => const TaskConstMeta(
+    debugName: "render_prompt",
+    argNames: ["tags", "dialect"],
+  );
+
   @override
   String crateApiSimpleGreet({required String name}) {
     return handler.executeSync(
@@ -317,7 +411,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
         callFfi: () {
           final serializer = SseSerializer(generalizedFrbRustBinding);
           sse_encode_String(name, serializer);
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 7)!;
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 9)!;
         },
         codec: SseCodec(
           decodeSuccessData: sse_decode_String,
@@ -342,7 +436,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 8,
+            funcId: 10,
             port: port_,
           );
         },
@@ -360,6 +454,75 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   TaskConstMeta get kCrateApiSimpleInitAppConstMeta =>
       const TaskConstMeta(debugName: "init_app", argNames: []);
 
+  @override
+  Future<Uint8List> crateApiStripStripMetadata({
+    required List<int> inputBytes,
+    required StripOptions options,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_list_prim_u_8_loose(inputBytes, serializer);
+          sse_encode_box_autoadd_strip_options(options, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 11,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_list_prim_u_8_strict,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiStripStripMetadataConstMeta,
+        argValues: [inputBytes, options],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiStripStripMetadataConstMeta => const TaskConstMeta(
+    debugName: "strip_metadata",
+    argNames: ["inputBytes", "options"],
+  );
+
+  @override
+  Future<void> crateApiWriterWriteMetadata({
+    required String path,
+    required List<PngTextChunk> chunks,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(path, serializer);
+          sse_encode_list_png_text_chunk(chunks, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 12,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiWriterWriteMetadataConstMeta,
+        argValues: [path, chunks],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiWriterWriteMetadataConstMeta =>
+      const TaskConstMeta(
+        debugName: "write_metadata",
+        argNames: ["path", "chunks"],
+      );
+
   @override
   Stream<ScanProgress> crateApiScanScanFolder({
     required String folderPath,
@@ -377,7 +540,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
             pdeCallFfi(
               generalizedFrbRustBinding,
               serializer,
-              funcId: 9,
+              funcId: 13,
               port: port_,
             );
           },
@@ -399,38 +562,12 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     argNames: ["sink", "folderPath", "existingImages"],
   );
 
-  RustArcIncrementStrongCountFnType
-  get rust_arc_increment_strong_count_DataReader => wire
-      .rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDataReader;
-
-  RustArcDecrementStrongCountFnType
-  get rust_arc_decrement_strong_count_DataReader => wire
-      .rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDataReader;
-
   @protected
   AnyhowException dco_decode_AnyhowException(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
     return AnyhowException(raw as String);
   }
 
-  @protected
-  DataReader
-  dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDataReader(
-    dynamic raw,
-  ) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return DataReaderImpl.frbInternalDcoDecode(raw as List<dynamic>);
-  }
-
-  @protected
-  DataReader
-  dco_decode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDataReader(
-    dynamic raw,
-  ) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return DataReaderImpl.frbInternalDcoDecode(raw as List<dynamic>);
-  }
-
   @protected
   Map<String, BigInt> dco_decode_Map_String_u_64_None(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
@@ -439,15 +576,6 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     );
   }
 
-  @protected
-  DataReader
-  dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDataReader(
-    dynamic raw,
-  ) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return DataReaderImpl.frbInternalDcoDecode(raw as List<dynamic>);
-  }
-
   @protected
   RustStreamSink<ScanProgress> dco_decode_StreamSink_scan_progress_Sse(
     dynamic raw,
@@ -463,670 +591,3084 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   }
 
   @protected
-  double dco_decode_box_autoadd_f_64(dynamic raw) {
+  A1111Parameters dco_decode_a1111_parameters(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw as double;
+    final arr = raw as List<dynamic>;
+    if (arr.length != 13)
+      throw Exception('unexpected arr length: expect 13 but see ${arr.length}');
+    return A1111Parameters(
+      prompt: dco_decode_String(arr[0]),
+      negativePrompt: dco_decode_opt_String(arr[1]),
+      steps: dco_decode_opt_box_autoadd_u_32(arr[2]),
+      sampler: dco_decode_opt_String(arr[3]),
+      scheduleType: dco_decode_opt_String(arr[4]),
+      cfgScale: dco_decode_opt_box_autoadd_f_64(arr[5]),
+      seed: dco_decode_opt_box_autoadd_u_64(arr[6]),
+      width: dco_decode_opt_box_autoadd_u_32(arr[7]),
+      height: dco_decode_opt_box_autoadd_u_32(arr[8]),
+      model: dco_decode_opt_String(arr[9]),
+      modelHash: dco_decode_opt_String(arr[10]),
+      denoisingStrength: dco_decode_opt_box_autoadd_f_64(arr[11]),
+      settings: dco_decode_list_record_string_string(arr[12]),
+    );
   }
 
   @protected
-  FolderScanResult dco_decode_box_autoadd_folder_scan_result(dynamic raw) {
+  bool dco_decode_bool(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
-    return dco_decode_folder_scan_result(raw);
+    return raw as bool;
   }
 
   @protected
-  double dco_decode_f_64(dynamic raw) {
+  A1111Parameters dco_decode_box_autoadd_a1111_parameters(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw as double;
+    return dco_decode_a1111_parameters(raw);
   }
 
   @protected
-  FolderScanResult dco_decode_folder_scan_result(dynamic raw) {
+  bool dco_decode_box_autoadd_bool(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
-    final arr = raw as List<dynamic>;
-    if (arr.length != 3)
-      throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
-    return FolderScanResult(
-      folderPath: dco_decode_String(arr[0]),
-      scanTimestamp: dco_decode_u_64(arr[1]),
-      totalImageCount: dco_decode_u_32(arr[2]),
-    );
+    return raw as bool;
   }
 
   @protected
-  int dco_decode_i_32(dynamic raw) {
+  ComfyUiMetadata dco_decode_box_autoadd_comfy_ui_metadata(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw as int;
+    return dco_decode_comfy_ui_metadata(raw);
   }
 
   @protected
-  ImageInfo dco_decode_image_info(dynamic raw) {
+  ComfyUiSummary dco_decode_box_autoadd_comfy_ui_summary(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
-    final arr = raw as List<dynamic>;
-    if (arr.length != 2)
-      throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
-    return ImageInfo(
-      aspectRatio: dco_decode_f_64(arr[0]),
-      metadataString: dco_decode_opt_String(arr[1]),
-    );
+    return dco_decode_comfy_ui_summary(raw);
   }
 
   @protected
-  ImageScanResult dco_decode_image_scan_result(dynamic raw) {
+  double dco_decode_box_autoadd_f_64(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
-    final arr = raw as List<dynamic>;
-    if (arr.length != 4)
-      throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
-    return ImageScanResult(
-      filePath: dco_decode_String(arr[0]),
-      fileLastModified: dco_decode_u_64(arr[1]),
-      imageAspectRatio: dco_decode_opt_box_autoadd_f_64(arr[2]),
-      metadataText: dco_decode_opt_String(arr[3]),
-    );
+    return raw as double;
   }
 
   @protected
-  List<ImageScanResult> dco_decode_list_image_scan_result(dynamic raw) {
+  FolderScanResult dco_decode_box_autoadd_folder_scan_result(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
-    return (raw as List<dynamic>).map(dco_decode_image_scan_result).toList();
+    return dco_decode_folder_scan_result(raw);
   }
 
   @protected
-  List<int> dco_decode_list_prim_u_8_loose(dynamic raw) {
+  InvokeAiMetadata dco_decode_box_autoadd_invoke_ai_metadata(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw as List<int>;
+    return dco_decode_invoke_ai_metadata(raw);
   }
 
   @protected
-  Uint8List dco_decode_list_prim_u_8_strict(dynamic raw) {
+  MetadataErrorKind dco_decode_box_autoadd_metadata_error_kind(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw as Uint8List;
+    return dco_decode_metadata_error_kind(raw);
   }
 
   @protected
-  List<(String, BigInt)> dco_decode_list_record_string_u_64(dynamic raw) {
+  ModelFile dco_decode_box_autoadd_model_file(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
-    return (raw as List<dynamic>).map(dco_decode_record_string_u_64).toList();
+    return dco_decode_model_file(raw);
   }
 
   @protected
-  String? dco_decode_opt_String(dynamic raw) {
+  NaiMetadata dco_decode_box_autoadd_nai_metadata(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw == null ? null : dco_decode_String(raw);
+    return dco_decode_nai_metadata(raw);
   }
 
   @protected
-  double? dco_decode_opt_box_autoadd_f_64(dynamic raw) {
+  NaiV4Prompt dco_decode_box_autoadd_nai_v4_prompt(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw == null ? null : dco_decode_box_autoadd_f_64(raw);
+    return dco_decode_nai_v4_prompt(raw);
   }
 
   @protected
-  FolderScanResult? dco_decode_opt_box_autoadd_folder_scan_result(dynamic raw) {
+  StripOptions dco_decode_box_autoadd_strip_options(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw == null ? null : dco_decode_box_autoadd_folder_scan_result(raw);
+    return dco_decode_strip_options(raw);
   }
 
   @protected
-  List<ImageScanResult>? dco_decode_opt_list_image_scan_result(dynamic raw) {
+  int dco_decode_box_autoadd_u_32(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw == null ? null : dco_decode_list_image_scan_result(raw);
+    return raw as int;
   }
 
   @protected
-  (String, BigInt) dco_decode_record_string_u_64(dynamic raw) {
+  BigInt dco_decode_box_autoadd_u_64(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dcoDecodeU64(raw);
+  }
+
+  @protected
+  XmpMetadata dco_decode_box_autoadd_xmp_metadata(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_xmp_metadata(raw);
+  }
+
+  @protected
+  C2paAction dco_decode_c2pa_action(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
     final arr = raw as List<dynamic>;
-    if (arr.length != 2) {
-      throw Exception('Expected 2 elements, got ${arr.length}');
-    }
-    return (dco_decode_String(arr[0]), dco_decode_u_64(arr[1]));
+    if (arr.length != 4)
+      throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+    return C2paAction(
+      action: dco_decode_String(arr[0]),
+      softwareAgent: dco_decode_opt_String(arr[1]),
+      digitalSourceType: dco_decode_opt_String(arr[2]),
+      when: dco_decode_opt_String(arr[3]),
+    );
+  }
+
+  @protected
+  C2paIngredient dco_decode_c2pa_ingredient(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 3)
+      throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+    return C2paIngredient(
+      title: dco_decode_opt_String(arr[0]),
+      format: dco_decode_opt_String(arr[1]),
+      relationship: dco_decode_opt_String(arr[2]),
+    );
+  }
+
+  @protected
+  C2paManifest dco_decode_c2pa_manifest(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 7)
+      throw Exception('unexpected arr length: expect 7 but see ${arr.length}');
+    return C2paManifest(
+      label: dco_decode_String(arr[0]),
+      claimGenerator: dco_decode_opt_String(arr[1]),
+      title: dco_decode_opt_String(arr[2]),
+      format: dco_decode_opt_String(arr[3]),
+      actions: dco_decode_list_c2pa_action(arr[4]),
+      ingredients: dco_decode_list_c2pa_ingredient(arr[5]),
+      assertionLabels: dco_decode_list_String(arr[6]),
+    );
+  }
+
+  @protected
+  ComfyUiLora dco_decode_comfy_ui_lora(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 3)
+      throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+    return ComfyUiLora(
+      name: dco_decode_String(arr[0]),
+      strengthModel: dco_decode_opt_box_autoadd_f_64(arr[1]),
+      strengthClip: dco_decode_opt_box_autoadd_f_64(arr[2]),
+    );
+  }
+
+  @protected
+  ComfyUiMetadata dco_decode_comfy_ui_metadata(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 3)
+      throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+    return ComfyUiMetadata(
+      promptGraph: dco_decode_opt_String(arr[0]),
+      workflow: dco_decode_opt_String(arr[1]),
+      summary: dco_decode_opt_box_autoadd_comfy_ui_summary(arr[2]),
+    );
+  }
+
+  @protected
+  ComfyUiSummary dco_decode_comfy_ui_summary(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 10)
+      throw Exception('unexpected arr length: expect 10 but see ${arr.length}');
+    return ComfyUiSummary(
+      positivePrompt: dco_decode_opt_String(arr[0]),
+      negativePrompt: dco_decode_opt_String(arr[1]),
+      seed: dco_decode_opt_box_autoadd_u_64(arr[2]),
+      steps: dco_decode_opt_box_autoadd_u_32(arr[3]),
+      cfg: dco_decode_opt_box_autoadd_f_64(arr[4]),
+      samplerName: dco_decode_opt_String(arr[5]),
+      scheduler: dco_decode_opt_String(arr[6]),
+      denoise: dco_decode_opt_box_autoadd_f_64(arr[7]),
+      checkpoint: dco_decode_opt_String(arr[8]),
+      loras: dco_decode_list_comfy_ui_lora(arr[9]),
+    );
+  }
+
+  @protected
+  double dco_decode_f_64(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw as double;
+  }
+
+  @protected
+  FolderScanResult dco_decode_folder_scan_result(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 3)
+      throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+    return FolderScanResult(
+      folderPath: dco_decode_String(arr[0]),
+      scanTimestamp: dco_decode_u_64(arr[1]),
+      totalImageCount: dco_decode_u_32(arr[2]),
+    );
+  }
+
+  @protected
+  Generator dco_decode_generator(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return Generator.values[raw as int];
+  }
+
+  @protected
+  int dco_decode_i_32(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw as int;
+  }
+
+  @protected
+  ImageInfo dco_decode_image_info(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 16)
+      throw Exception('unexpected arr length: expect 16 but see ${arr.length}');
+    return ImageInfo(
+      aspectRatio: dco_decode_f_64(arr[0]),
+      generator: dco_decode_generator(arr[1]),
+      metadataString: dco_decode_opt_String(arr[2]),
+      naiMetadata: dco_decode_opt_box_autoadd_nai_metadata(arr[3]),
+      a1111Parameters: dco_decode_opt_box_autoadd_a1111_parameters(arr[4]),
+      fooocusParameters: dco_decode_opt_box_autoadd_a1111_parameters(arr[5]),
+      swarmuiParameters: dco_decode_opt_box_autoadd_a1111_parameters(arr[6]),
+      comfyui: dco_decode_opt_box_autoadd_comfy_ui_metadata(arr[7]),
+      invokeai: dco_decode_opt_box_autoadd_invoke_ai_metadata(arr[8]),
+      networks: dco_decode_list_network_reference(arr[9]),
+      textChunks: dco_decode_list_png_text_chunk(arr[10]),
+      xmpPacket: dco_decode_opt_String(arr[11]),
+      xmp: dco_decode_opt_box_autoadd_xmp_metadata(arr[12]),
+      c2paManifests: dco_decode_list_c2pa_manifest(arr[13]),
+      stealthErrorKind: dco_decode_opt_box_autoadd_metadata_error_kind(arr[14]),
+      stealthErrorMessage: dco_decode_opt_String(arr[15]),
+    );
+  }
+
+  @protected
+  ImageScanResult dco_decode_image_scan_result(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 6)
+      throw Exception('unexpected arr length: expect 6 but see ${arr.length}');
+    return ImageScanResult(
+      filePath: dco_decode_String(arr[0]),
+      fileLastModified: dco_decode_u_64(arr[1]),
+      imageAspectRatio: dco_decode_opt_box_autoadd_f_64(arr[2]),
+      metadataText: dco_decode_opt_String(arr[3]),
+      errorKind: dco_decode_opt_box_autoadd_metadata_error_kind(arr[4]),
+      errorMessage: dco_decode_opt_String(arr[5]),
+    );
+  }
+
+  @protected
+  InvokeAiControlLayer dco_decode_invoke_ai_control_layer(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 6)
+      throw Exception('unexpected arr length: expect 6 but see ${arr.length}');
+    return InvokeAiControlLayer(
+      adapterType: dco_decode_String(arr[0]),
+      model: dco_decode_opt_String(arr[1]),
+      weight: dco_decode_opt_box_autoadd_f_64(arr[2]),
+      beginStepPercent: dco_decode_opt_box_autoadd_f_64(arr[3]),
+      endStepPercent: dco_decode_opt_box_autoadd_f_64(arr[4]),
+      isEnabled: dco_decode_bool(arr[5]),
+    );
+  }
+
+  @protected
+  InvokeAiLora dco_decode_invoke_ai_lora(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2)
+      throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+    return InvokeAiLora(
+      name: dco_decode_String(arr[0]),
+      weight: dco_decode_opt_box_autoadd_f_64(arr[1]),
+    );
+  }
+
+  @protected
+  InvokeAiMetadata dco_decode_invoke_ai_metadata(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 21)
+      throw Exception('unexpected arr length: expect 21 but see ${arr.length}');
+    return InvokeAiMetadata(
+      metadata: dco_decode_opt_String(arr[0]),
+      graph: dco_decode_opt_String(arr[1]),
+      appVersion: dco_decode_opt_String(arr[2]),
+      generationMode: dco_decode_opt_String(arr[3]),
+      positivePrompt: dco_decode_opt_String(arr[4]),
+      negativePrompt: dco_decode_opt_String(arr[5]),
+      positiveStylePrompt: dco_decode_opt_String(arr[6]),
+      negativeStylePrompt: dco_decode_opt_String(arr[7]),
+      seed: dco_decode_opt_box_autoadd_u_64(arr[8]),
+      steps: dco_decode_opt_box_autoadd_u_32(arr[9]),
+      cfgScale: dco_decode_opt_box_autoadd_f_64(arr[10]),
+      cfgRescaleMultiplier: dco_decode_opt_box_autoadd_f_64(arr[11]),
+      scheduler: dco_decode_opt_String(arr[12]),
+      width: dco_decode_opt_box_autoadd_u_32(arr[13]),
+      height: dco_decode_opt_box_autoadd_u_32(arr[14]),
+      model: dco_decode_opt_String(arr[15]),
+      modelHash: dco_decode_opt_String(arr[16]),
+      vae: dco_decode_opt_String(arr[17]),
+      loras: dco_decode_list_invoke_ai_lora(arr[18]),
+      regionalPrompts: dco_decode_list_invoke_ai_regional_prompt(arr[19]),
+      controlLayers: dco_decode_list_invoke_ai_control_layer(arr[20]),
+    );
+  }
+
+  @protected
+  InvokeAiRegionalPrompt dco_decode_invoke_ai_regional_prompt(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 4)
+      throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+    return InvokeAiRegionalPrompt(
+      name: dco_decode_opt_String(arr[0]),
+      positivePrompt: dco_decode_opt_String(arr[1]),
+      negativePrompt: dco_decode_opt_String(arr[2]),
+      isEnabled: dco_decode_bool(arr[3]),
+    );
+  }
+
+  @protected
+  List<String> dco_decode_list_String(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_String).toList();
+  }
+
+  @protected
+  List<C2paAction> dco_decode_list_c2pa_action(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_c2pa_action).toList();
+  }
+
+  @protected
+  List<C2paIngredient> dco_decode_list_c2pa_ingredient(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_c2pa_ingredient).toList();
+  }
+
+  @protected
+  List<C2paManifest> dco_decode_list_c2pa_manifest(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_c2pa_manifest).toList();
+  }
+
+  @protected
+  List<ComfyUiLora> dco_decode_list_comfy_ui_lora(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_comfy_ui_lora).toList();
+  }
+
+  @protected
+  List<ImageScanResult> dco_decode_list_image_scan_result(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_image_scan_result).toList();
+  }
+
+  @protected
+  List<InvokeAiControlLayer> dco_decode_list_invoke_ai_control_layer(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>)
+        .map(dco_decode_invoke_ai_control_layer)
+        .toList();
+  }
+
+  @protected
+  List<InvokeAiLora> dco_decode_list_invoke_ai_lora(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_invoke_ai_lora).toList();
+  }
+
+  @protected
+  List<InvokeAiRegionalPrompt> dco_decode_list_invoke_ai_regional_prompt(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>)
+        .map(dco_decode_invoke_ai_regional_prompt)
+        .toList();
+  }
+
+  @protected
+  List<ModelFile> dco_decode_list_model_file(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_model_file).toList();
+  }
+
+  @protected
+  List<NaiCharCaption> dco_decode_list_nai_char_caption(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_nai_char_caption).toList();
+  }
+
+  @protected
+  List<NaiCoordinate> dco_decode_list_nai_coordinate(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_nai_coordinate).toList();
+  }
+
+  @protected
+  List<NetworkReference> dco_decode_list_network_reference(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_network_reference).toList();
+  }
+
+  @protected
+  List<PngTextChunk> dco_decode_list_png_text_chunk(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_png_text_chunk).toList();
+  }
+
+  @protected
+  List<double> dco_decode_list_prim_f_64_strict(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_f_64).toList();
+  }
+
+  @protected
+  List<int> dco_decode_list_prim_u_8_loose(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw as List<int>;
+  }
+
+  @protected
+  Uint8List dco_decode_list_prim_u_8_strict(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw as Uint8List;
+  }
+
+  @protected
+  List<PromptTag> dco_decode_list_prompt_tag(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_prompt_tag).toList();
+  }
+
+  @protected
+  List<(String, String)> dco_decode_list_record_string_string(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_record_string_string).toList();
+  }
+
+  @protected
+  List<(String, BigInt)> dco_decode_list_record_string_u_64(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_record_string_u_64).toList();
+  }
+
+  @protected
+  List<XmpProperty> dco_decode_list_xmp_property(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_xmp_property).toList();
+  }
+
+  @protected
+  MetadataErrorKind dco_decode_metadata_error_kind(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return MetadataErrorKind.values[raw as int];
+  }
+
+  @protected
+  ModelFile dco_decode_model_file(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 6)
+      throw Exception('unexpected arr length: expect 6 but see ${arr.length}');
+    return ModelFile(
+      filePath: dco_decode_String(arr[0]),
+      fileSize: dco_decode_u_64(arr[1]),
+      fileLastModified: dco_decode_u_64(arr[2]),
+      sha256: dco_decode_String(arr[3]),
+      autoV1: dco_decode_String(arr[4]),
+      autoV2: dco_decode_String(arr[5]),
+    );
+  }
+
+  @protected
+  NaiCharCaption dco_decode_nai_char_caption(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2)
+      throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+    return NaiCharCaption(
+      charCaption: dco_decode_String(arr[0]),
+      centers: dco_decode_list_nai_coordinate(arr[1]),
+    );
+  }
+
+  @protected
+  NaiCoordinate dco_decode_nai_coordinate(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2)
+      throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+    return NaiCoordinate(
+      x: dco_decode_f_64(arr[0]),
+      y: dco_decode_f_64(arr[1]),
+    );
+  }
+
+  @protected
+  NaiMetadata dco_decode_nai_metadata(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 25)
+      throw Exception('unexpected arr length: expect 25 but see ${arr.length}');
+    return NaiMetadata(
+      title: dco_decode_opt_String(arr[0]),
+      description: dco_decode_opt_String(arr[1]),
+      software: dco_decode_opt_String(arr[2]),
+      source: dco_decode_opt_String(arr[3]),
+      generationTime: dco_decode_opt_box_autoadd_f_64(arr[4]),
+      prompt: dco_decode_opt_String(arr[5]),
+      negativePrompt: dco_decode_opt_String(arr[6]),
+      seed: dco_decode_opt_box_autoadd_u_64(arr[7]),
+      steps: dco_decode_opt_box_autoadd_u_32(arr[8]),
+      sampler: dco_decode_opt_String(arr[9]),
+      noiseSchedule: dco_decode_opt_String(arr[10]),
+      scale: dco_decode_opt_box_autoadd_f_64(arr[11]),
+      cfgRescale: dco_decode_opt_box_autoadd_f_64(arr[12]),
+      uncondScale: dco_decode_opt_box_autoadd_f_64(arr[13]),
+      width: dco_decode_opt_box_autoadd_u_32(arr[14]),
+      height: dco_decode_opt_box_autoadd_u_32(arr[15]),
+      nSamples: dco_decode_opt_box_autoadd_u_32(arr[16]),
+      sm: dco_decode_opt_box_autoadd_bool(arr[17]),
+      smDyn: dco_decode_opt_box_autoadd_bool(arr[18]),
+      requestType: dco_decode_opt_String(arr[19]),
+      signedHash: dco_decode_opt_String(arr[20]),
+      v4Prompt: dco_decode_opt_box_autoadd_nai_v4_prompt(arr[21]),
+      v4NegativePrompt: dco_decode_opt_box_autoadd_nai_v4_prompt(arr[22]),
+      referenceInformationExtracted: dco_decode_list_prim_f_64_strict(arr[23]),
+      referenceStrength: dco_decode_list_prim_f_64_strict(arr[24]),
+    );
+  }
+
+  @protected
+  NaiSignatureStatus dco_decode_nai_signature_status(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return NaiSignatureStatus.values[raw as int];
+  }
+
+  @protected
+  NaiV4Prompt dco_decode_nai_v4_prompt(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 4)
+      throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+    return NaiV4Prompt(
+      baseCaption: dco_decode_String(arr[0]),
+      charCaptions: dco_decode_list_nai_char_caption(arr[1]),
+      useCoords: dco_decode_opt_box_autoadd_bool(arr[2]),
+      useOrder: dco_decode_opt_box_autoadd_bool(arr[3]),
+    );
+  }
+
+  @protected
+  NetworkKind dco_decode_network_kind(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return NetworkKind.values[raw as int];
+  }
+
+  @protected
+  NetworkReference dco_decode_network_reference(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 5)
+      throw Exception('unexpected arr length: expect 5 but see ${arr.length}');
+    return NetworkReference(
+      kind: dco_decode_network_kind(arr[0]),
+      name: dco_decode_String(arr[1]),
+      weight: dco_decode_opt_box_autoadd_f_64(arr[2]),
+      clipWeight: dco_decode_opt_box_autoadd_f_64(arr[3]),
+      hash: dco_decode_opt_String(arr[4]),
+    );
+  }
+
+  @protected
+  String? dco_decode_opt_String(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_String(raw);
+  }
+
+  @protected
+  A1111Parameters? dco_decode_opt_box_autoadd_a1111_parameters(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_box_autoadd_a1111_parameters(raw);
+  }
+
+  @protected
+  bool? dco_decode_opt_box_autoadd_bool(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_box_autoadd_bool(raw);
+  }
+
+  @protected
+  ComfyUiMetadata? dco_decode_opt_box_autoadd_comfy_ui_metadata(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_box_autoadd_comfy_ui_metadata(raw);
+  }
+
+  @protected
+  ComfyUiSummary? dco_decode_opt_box_autoadd_comfy_ui_summary(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_box_autoadd_comfy_ui_summary(raw);
+  }
+
+  @protected
+  double? dco_decode_opt_box_autoadd_f_64(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_box_autoadd_f_64(raw);
+  }
+
+  @protected
+  FolderScanResult? dco_decode_opt_box_autoadd_folder_scan_result(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_box_autoadd_folder_scan_result(raw);
+  }
+
+  @protected
+  InvokeAiMetadata? dco_decode_opt_box_autoadd_invoke_ai_metadata(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_box_autoadd_invoke_ai_metadata(raw);
+  }
+
+  @protected
+  MetadataErrorKind? dco_decode_opt_box_autoadd_metadata_error_kind(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_box_autoadd_metadata_error_kind(raw);
+  }
+
+  @protected
+  ModelFile? dco_decode_opt_box_autoadd_model_file(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_box_autoadd_model_file(raw);
+  }
+
+  @protected
+  NaiMetadata? dco_decode_opt_box_autoadd_nai_metadata(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_box_autoadd_nai_metadata(raw);
+  }
+
+  @protected
+  NaiV4Prompt? dco_decode_opt_box_autoadd_nai_v4_prompt(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_box_autoadd_nai_v4_prompt(raw);
+  }
+
+  @protected
+  int? dco_decode_opt_box_autoadd_u_32(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_box_autoadd_u_32(raw);
+  }
+
+  @protected
+  BigInt? dco_decode_opt_box_autoadd_u_64(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_box_autoadd_u_64(raw);
+  }
+
+  @protected
+  XmpMetadata? dco_decode_opt_box_autoadd_xmp_metadata(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_box_autoadd_xmp_metadata(raw);
+  }
+
+  @protected
+  List<ImageScanResult>? dco_decode_opt_list_image_scan_result(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_list_image_scan_result(raw);
+  }
+
+  @protected
+  PngTextChunk dco_decode_png_text_chunk(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 5)
+      throw Exception('unexpected arr length: expect 5 but see ${arr.length}');
+    return PngTextChunk(
+      chunkType: dco_decode_png_text_chunk_type(arr[0]),
+      keyword: dco_decode_String(arr[1]),
+      text: dco_decode_String(arr[2]),
+      languageTag: dco_decode_opt_String(arr[3]),
+      translatedKeyword: dco_decode_opt_String(arr[4]),
+    );
+  }
+
+  @protected
+  PngTextChunkType dco_decode_png_text_chunk_type(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return PngTextChunkType.values[raw as int];
+  }
+
+  @protected
+  PromptDialect dco_decode_prompt_dialect(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return PromptDialect.values[raw as int];
+  }
+
+  @protected
+  PromptTag dco_decode_prompt_tag(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 5)
+      throw Exception('unexpected arr length: expect 5 but see ${arr.length}');
+    return PromptTag(
+      text: dco_decode_String(arr[0]),
+      weight: dco_decode_f_64(arr[1]),
+      kind: dco_decode_prompt_tag_kind(arr[2]),
+      startsAt: dco_decode_opt_box_autoadd_f_64(arr[3]),
+      endsAt: dco_decode_opt_box_autoadd_f_64(arr[4]),
+    );
+  }
+
+  @protected
+  PromptTagKind dco_decode_prompt_tag_kind(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return PromptTagKind.values[raw as int];
+  }
+
+  @protected
+  (String, String) dco_decode_record_string_string(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2) {
+      throw Exception('Expected 2 elements, got ${arr.length}');
+    }
+    return (dco_decode_String(arr[0]), dco_decode_String(arr[1]));
+  }
+
+  @protected
+  (String, BigInt) dco_decode_record_string_u_64(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2) {
+      throw Exception('Expected 2 elements, got ${arr.length}');
+    }
+    return (dco_decode_String(arr[0]), dco_decode_u_64(arr[1]));
+  }
+
+  @protected
+  ScanProgress dco_decode_scan_progress(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 4)
+      throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+    return ScanProgress(
+      totalToProcess: dco_decode_u_32(arr[0]),
+      processed: dco_decode_u_32(arr[1]),
+      imageScanResults: dco_decode_opt_list_image_scan_result(arr[2]),
+      folderScanResult: dco_decode_opt_box_autoadd_folder_scan_result(arr[3]),
+    );
+  }
+
+  @protected
+  StealthChannel dco_decode_stealth_channel(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return StealthChannel.values[raw as int];
+  }
+
+  @protected
+  StripOptions dco_decode_strip_options(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 6)
+      throw Exception('unexpected arr length: expect 6 but see ${arr.length}');
+    return StripOptions(
+      removeTextChunks: dco_decode_bool(arr[0]),
+      removeExif: dco_decode_bool(arr[1]),
+      removeXmp: dco_decode_bool(arr[2]),
+      removeC2pa: dco_decode_bool(arr[3]),
+      removeStealth: dco_decode_bool(arr[4]),
+      keepPrompt: dco_decode_bool(arr[5]),
+    );
+  }
+
+  @protected
+  int dco_decode_u_32(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw as int;
+  }
+
+  @protected
+  BigInt dco_decode_u_64(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dcoDecodeU64(raw);
+  }
+
+  @protected
+  int dco_decode_u_8(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw as int;
+  }
+
+  @protected
+  void dco_decode_unit(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return;
+  }
+
+  @protected
+  XmpMetadata dco_decode_xmp_metadata(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 5)
+      throw Exception('unexpected arr length: expect 5 but see ${arr.length}');
+    return XmpMetadata(
+      description: dco_decode_opt_String(arr[0]),
+      subject: dco_decode_list_String(arr[1]),
+      rating: dco_decode_opt_box_autoadd_f_64(arr[2]),
+      userComment: dco_decode_opt_String(arr[3]),
+      properties: dco_decode_list_xmp_property(arr[4]),
+    );
+  }
+
+  @protected
+  XmpProperty dco_decode_xmp_property(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 4)
+      throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+    return XmpProperty(
+      namespace: dco_decode_String(arr[0]),
+      key: dco_decode_String(arr[1]),
+      value: dco_decode_String(arr[2]),
+      language: dco_decode_opt_String(arr[3]),
+    );
+  }
+
+  @protected
+  AnyhowException sse_decode_AnyhowException(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_String(deserializer);
+    return AnyhowException(inner);
+  }
+
+  @protected
+  Map<String, BigInt> sse_decode_Map_String_u_64_None(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_list_record_string_u_64(deserializer);
+    return Map.fromEntries(inner.map((e) => MapEntry(e.$1, e.$2)));
+  }
+
+  @protected
+  RustStreamSink<ScanProgress> sse_decode_StreamSink_scan_progress_Sse(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    throw UnimplementedError('Unreachable ()');
+  }
+
+  @protected
+  String sse_decode_String(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_list_prim_u_8_strict(deserializer);
+    return utf8.decoder.convert(inner);
+  }
+
+  @protected
+  A1111Parameters sse_decode_a1111_parameters(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_prompt = sse_decode_String(deserializer);
+    var var_negativePrompt = sse_decode_opt_String(deserializer);
+    var var_steps = sse_decode_opt_box_autoadd_u_32(deserializer);
+    var var_sampler = sse_decode_opt_String(deserializer);
+    var var_scheduleType = sse_decode_opt_String(deserializer);
+    var var_cfgScale = sse_decode_opt_box_autoadd_f_64(deserializer);
+    var var_seed = sse_decode_opt_box_autoadd_u_64(deserializer);
+    var var_width = sse_decode_opt_box_autoadd_u_32(deserializer);
+    var var_height = sse_decode_opt_box_autoadd_u_32(deserializer);
+    var var_model = sse_decode_opt_String(deserializer);
+    var var_modelHash = sse_decode_opt_String(deserializer);
+    var var_denoisingStrength = sse_decode_opt_box_autoadd_f_64(deserializer);
+    var var_settings = sse_decode_list_record_string_string(deserializer);
+    return A1111Parameters(
+      prompt: var_prompt,
+      negativePrompt: var_negativePrompt,
+      steps: var_steps,
+      sampler: var_sampler,
+      scheduleType: var_scheduleType,
+      cfgScale: var_cfgScale,
+      seed: var_seed,
+      width: var_width,
+      height: var_height,
+      model: var_model,
+      modelHash: var_modelHash,
+      denoisingStrength: var_denoisingStrength,
+      settings: var_settings,
+    );
+  }
+
+  @protected
+  bool sse_decode_bool(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return deserializer.buffer.getUint8() != 0;
+  }
+
+  @protected
+  A1111Parameters sse_decode_box_autoadd_a1111_parameters(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_a1111_parameters(deserializer));
+  }
+
+  @protected
+  bool sse_decode_box_autoadd_bool(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_bool(deserializer));
+  }
+
+  @protected
+  ComfyUiMetadata sse_decode_box_autoadd_comfy_ui_metadata(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_comfy_ui_metadata(deserializer));
+  }
+
+  @protected
+  ComfyUiSummary sse_decode_box_autoadd_comfy_ui_summary(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_comfy_ui_summary(deserializer));
+  }
+
+  @protected
+  double sse_decode_box_autoadd_f_64(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_f_64(deserializer));
+  }
+
+  @protected
+  FolderScanResult sse_decode_box_autoadd_folder_scan_result(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_folder_scan_result(deserializer));
+  }
+
+  @protected
+  InvokeAiMetadata sse_decode_box_autoadd_invoke_ai_metadata(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_invoke_ai_metadata(deserializer));
+  }
+
+  @protected
+  MetadataErrorKind sse_decode_box_autoadd_metadata_error_kind(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_metadata_error_kind(deserializer));
+  }
+
+  @protected
+  ModelFile sse_decode_box_autoadd_model_file(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_model_file(deserializer));
+  }
+
+  @protected
+  NaiMetadata sse_decode_box_autoadd_nai_metadata(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_nai_metadata(deserializer));
+  }
+
+  @protected
+  NaiV4Prompt sse_decode_box_autoadd_nai_v4_prompt(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_nai_v4_prompt(deserializer));
+  }
+
+  @protected
+  StripOptions sse_decode_box_autoadd_strip_options(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_strip_options(deserializer));
+  }
+
+  @protected
+  int sse_decode_box_autoadd_u_32(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_u_32(deserializer));
+  }
+
+  @protected
+  BigInt sse_decode_box_autoadd_u_64(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_u_64(deserializer));
+  }
+
+  @protected
+  XmpMetadata sse_decode_box_autoadd_xmp_metadata(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_xmp_metadata(deserializer));
+  }
+
+  @protected
+  C2paAction sse_decode_c2pa_action(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_action = sse_decode_String(deserializer);
+    var var_softwareAgent = sse_decode_opt_String(deserializer);
+    var var_digitalSourceType = sse_decode_opt_String(deserializer);
+    var var_when = sse_decode_opt_String(deserializer);
+    return C2paAction(
+      action: var_action,
+      softwareAgent: var_softwareAgent,
+      digitalSourceType: var_digitalSourceType,
+      when: var_when,
+    );
+  }
+
+  @protected
+  C2paIngredient sse_decode_c2pa_ingredient(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_title = sse_decode_opt_String(deserializer);
+    var var_format = sse_decode_opt_String(deserializer);
+    var var_relationship = sse_decode_opt_String(deserializer);
+    return C2paIngredient(
+      title: var_title,
+      format: var_format,
+      relationship: var_relationship,
+    );
+  }
+
+  @protected
+  C2paManifest sse_decode_c2pa_manifest(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_label = sse_decode_String(deserializer);
+    var var_claimGenerator = sse_decode_opt_String(deserializer);
+    var var_title = sse_decode_opt_String(deserializer);
+    var var_format = sse_decode_opt_String(deserializer);
+    var var_actions = sse_decode_list_c2pa_action(deserializer);
+    var var_ingredients = sse_decode_list_c2pa_ingredient(deserializer);
+    var var_assertionLabels = sse_decode_list_String(deserializer);
+    return C2paManifest(
+      label: var_label,
+      claimGenerator: var_claimGenerator,
+      title: var_title,
+      format: var_format,
+      actions: var_actions,
+      ingredients: var_ingredients,
+      assertionLabels: var_assertionLabels,
+    );
+  }
+
+  @protected
+  ComfyUiLora sse_decode_comfy_ui_lora(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_name = sse_decode_String(deserializer);
+    var var_strengthModel = sse_decode_opt_box_autoadd_f_64(deserializer);
+    var var_strengthClip = sse_decode_opt_box_autoadd_f_64(deserializer);
+    return ComfyUiLora(
+      name: var_name,
+      strengthModel: var_strengthModel,
+      strengthClip: var_strengthClip,
+    );
+  }
+
+  @protected
+  ComfyUiMetadata sse_decode_comfy_ui_metadata(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_promptGraph = sse_decode_opt_String(deserializer);
+    var var_workflow = sse_decode_opt_String(deserializer);
+    var var_summary = sse_decode_opt_box_autoadd_comfy_ui_summary(deserializer);
+    return ComfyUiMetadata(
+      promptGraph: var_promptGraph,
+      workflow: var_workflow,
+      summary: var_summary,
+    );
+  }
+
+  @protected
+  ComfyUiSummary sse_decode_comfy_ui_summary(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_positivePrompt = sse_decode_opt_String(deserializer);
+    var var_negativePrompt = sse_decode_opt_String(deserializer);
+    var var_seed = sse_decode_opt_box_autoadd_u_64(deserializer);
+    var var_steps = sse_decode_opt_box_autoadd_u_32(deserializer);
+    var var_cfg = sse_decode_opt_box_autoadd_f_64(deserializer);
+    var var_samplerName = sse_decode_opt_String(deserializer);
+    var var_scheduler = sse_decode_opt_String(deserializer);
+    var var_denoise = sse_decode_opt_box_autoadd_f_64(deserializer);
+    var var_checkpoint = sse_decode_opt_String(deserializer);
+    var var_loras = sse_decode_list_comfy_ui_lora(deserializer);
+    return ComfyUiSummary(
+      positivePrompt: var_positivePrompt,
+      negativePrompt: var_negativePrompt,
+      seed: var_seed,
+      steps: var_steps,
+      cfg: var_cfg,
+      samplerName: var_samplerName,
+      scheduler: var_scheduler,
+      denoise: var_denoise,
+      checkpoint: var_checkpoint,
+      loras: var_loras,
+    );
+  }
+
+  @protected
+  double sse_decode_f_64(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return deserializer.buffer.getFloat64();
+  }
+
+  @protected
+  FolderScanResult sse_decode_folder_scan_result(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_folderPath = sse_decode_String(deserializer);
+    var var_scanTimestamp = sse_decode_u_64(deserializer);
+    var var_totalImageCount = sse_decode_u_32(deserializer);
+    return FolderScanResult(
+      folderPath: var_folderPath,
+      scanTimestamp: var_scanTimestamp,
+      totalImageCount: var_totalImageCount,
+    );
+  }
+
+  @protected
+  Generator sse_decode_generator(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_i_32(deserializer);
+    return Generator.values[inner];
+  }
+
+  @protected
+  int sse_decode_i_32(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return deserializer.buffer.getInt32();
+  }
+
+  @protected
+  ImageInfo sse_decode_image_info(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_aspectRatio = sse_decode_f_64(deserializer);
+    var var_generator = sse_decode_generator(deserializer);
+    var var_metadataString = sse_decode_opt_String(deserializer);
+    var var_naiMetadata = sse_decode_opt_box_autoadd_nai_metadata(deserializer);
+    var var_a1111Parameters = sse_decode_opt_box_autoadd_a1111_parameters(
+      deserializer,
+    );
+    var var_fooocusParameters = sse_decode_opt_box_autoadd_a1111_parameters(
+      deserializer,
+    );
+    var var_swarmuiParameters = sse_decode_opt_box_autoadd_a1111_parameters(
+      deserializer,
+    );
+    var var_comfyui = sse_decode_opt_box_autoadd_comfy_ui_metadata(
+      deserializer,
+    );
+    var var_invokeai = sse_decode_opt_box_autoadd_invoke_ai_metadata(
+      deserializer,
+    );
+    var var_networks = sse_decode_list_network_reference(deserializer);
+    var var_textChunks = sse_decode_list_png_text_chunk(deserializer);
+    var var_xmpPacket = sse_decode_opt_String(deserializer);
+    var var_xmp = sse_decode_opt_box_autoadd_xmp_metadata(deserializer);
+    var var_c2paManifests = sse_decode_list_c2pa_manifest(deserializer);
+    var var_stealthErrorKind = sse_decode_opt_box_autoadd_metadata_error_kind(
+      deserializer,
+    );
+    var var_stealthErrorMessage = sse_decode_opt_String(deserializer);
+    return ImageInfo(
+      aspectRatio: var_aspectRatio,
+      generator: var_generator,
+      metadataString: var_metadataString,
+      naiMetadata: var_naiMetadata,
+      a1111Parameters: var_a1111Parameters,
+      fooocusParameters: var_fooocusParameters,
+      swarmuiParameters: var_swarmuiParameters,
+      comfyui: var_comfyui,
+      invokeai: var_invokeai,
+      networks: var_networks,
+      textChunks: var_textChunks,
+      xmpPacket: var_xmpPacket,
+      xmp: var_xmp,
+      c2paManifests: var_c2paManifests,
+      stealthErrorKind: var_stealthErrorKind,
+      stealthErrorMessage: var_stealthErrorMessage,
+    );
+  }
+
+  @protected
+  ImageScanResult sse_decode_image_scan_result(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_filePath = sse_decode_String(deserializer);
+    var var_fileLastModified = sse_decode_u_64(deserializer);
+    var var_imageAspectRatio = sse_decode_opt_box_autoadd_f_64(deserializer);
+    var var_metadataText = sse_decode_opt_String(deserializer);
+    var var_errorKind = sse_decode_opt_box_autoadd_metadata_error_kind(
+      deserializer,
+    );
+    var var_errorMessage = sse_decode_opt_String(deserializer);
+    return ImageScanResult(
+      filePath: var_filePath,
+      fileLastModified: var_fileLastModified,
+      imageAspectRatio: var_imageAspectRatio,
+      metadataText: var_metadataText,
+      errorKind: var_errorKind,
+      errorMessage: var_errorMessage,
+    );
+  }
+
+  @protected
+  InvokeAiControlLayer sse_decode_invoke_ai_control_layer(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_adapterType = sse_decode_String(deserializer);
+    var var_model = sse_decode_opt_String(deserializer);
+    var var_weight = sse_decode_opt_box_autoadd_f_64(deserializer);
+    var var_beginStepPercent = sse_decode_opt_box_autoadd_f_64(deserializer);
+    var var_endStepPercent = sse_decode_opt_box_autoadd_f_64(deserializer);
+    var var_isEnabled = sse_decode_bool(deserializer);
+    return InvokeAiControlLayer(
+      adapterType: var_adapterType,
+      model: var_model,
+      weight: var_weight,
+      beginStepPercent: var_beginStepPercent,
+      endStepPercent: var_endStepPercent,
+      isEnabled: var_isEnabled,
+    );
+  }
+
+  @protected
+  InvokeAiLora sse_decode_invoke_ai_lora(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_name = sse_decode_String(deserializer);
+    var var_weight = sse_decode_opt_box_autoadd_f_64(deserializer);
+    return InvokeAiLora(name: var_name, weight: var_weight);
+  }
+
+  @protected
+  InvokeAiMetadata sse_decode_invoke_ai_metadata(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_metadata = sse_decode_opt_String(deserializer);
+    var var_graph = sse_decode_opt_String(deserializer);
+    var var_appVersion = sse_decode_opt_String(deserializer);
+    var var_generationMode = sse_decode_opt_String(deserializer);
+    var var_positivePrompt = sse_decode_opt_String(deserializer);
+    var var_negativePrompt = sse_decode_opt_String(deserializer);
+    var var_positiveStylePrompt = sse_decode_opt_String(deserializer);
+    var var_negativeStylePrompt = sse_decode_opt_String(deserializer);
+    var var_seed = sse_decode_opt_box_autoadd_u_64(deserializer);
+    var var_steps = sse_decode_opt_box_autoadd_u_32(deserializer);
+    var var_cfgScale = sse_decode_opt_box_autoadd_f_64(deserializer);
+    var var_cfgRescaleMultiplier = sse_decode_opt_box_autoadd_f_64(
+      deserializer,
+    );
+    var var_scheduler = sse_decode_opt_String(deserializer);
+    var var_width = sse_decode_opt_box_autoadd_u_32(deserializer);
+    var var_height = sse_decode_opt_box_autoadd_u_32(deserializer);
+    var var_model = sse_decode_opt_String(deserializer);
+    var var_modelHash = sse_decode_opt_String(deserializer);
+    var var_vae = sse_decode_opt_String(deserializer);
+    var var_loras = sse_decode_list_invoke_ai_lora(deserializer);
+    var var_regionalPrompts = sse_decode_list_invoke_ai_regional_prompt(
+      deserializer,
+    );
+    var var_controlLayers = sse_decode_list_invoke_ai_control_layer(
+      deserializer,
+    );
+    return InvokeAiMetadata(
+      metadata: var_metadata,
+      graph: var_graph,
+      appVersion: var_appVersion,
+      generationMode: var_generationMode,
+      positivePrompt: var_positivePrompt,
+      negativePrompt: var_negativePrompt,
+      positiveStylePrompt: var_positiveStylePrompt,
+      negativeStylePrompt: var_negativeStylePrompt,
+      seed: var_seed,
+      steps: var_steps,
+      cfgScale: var_cfgScale,
+      cfgRescaleMultiplier: var_cfgRescaleMultiplier,
+      scheduler: var_scheduler,
+      width: var_width,
+      height: var_height,
+      model: var_model,
+      modelHash: var_modelHash,
+      vae: var_vae,
+      loras: var_loras,
+      regionalPrompts: var_regionalPrompts,
+      controlLayers: var_controlLayers,
+    );
+  }
+
+  @protected
+  InvokeAiRegionalPrompt sse_decode_invoke_ai_regional_prompt(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_name = sse_decode_opt_String(deserializer);
+    var var_positivePrompt = sse_decode_opt_String(deserializer);
+    var var_negativePrompt = sse_decode_opt_String(deserializer);
+    var var_isEnabled = sse_decode_bool(deserializer);
+    return InvokeAiRegionalPrompt(
+      name: var_name,
+      positivePrompt: var_positivePrompt,
+      negativePrompt: var_negativePrompt,
+      isEnabled: var_isEnabled,
+    );
+  }
+
+  @protected
+  List<String> sse_decode_list_String(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <String>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_String(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<C2paAction> sse_decode_list_c2pa_action(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <C2paAction>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_c2pa_action(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<C2paIngredient> sse_decode_list_c2pa_ingredient(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <C2paIngredient>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_c2pa_ingredient(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<C2paManifest> sse_decode_list_c2pa_manifest(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <C2paManifest>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_c2pa_manifest(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<ComfyUiLora> sse_decode_list_comfy_ui_lora(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <ComfyUiLora>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_comfy_ui_lora(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<ImageScanResult> sse_decode_list_image_scan_result(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <ImageScanResult>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_image_scan_result(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<InvokeAiControlLayer> sse_decode_list_invoke_ai_control_layer(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <InvokeAiControlLayer>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_invoke_ai_control_layer(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<InvokeAiLora> sse_decode_list_invoke_ai_lora(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <InvokeAiLora>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_invoke_ai_lora(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<InvokeAiRegionalPrompt> sse_decode_list_invoke_ai_regional_prompt(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <InvokeAiRegionalPrompt>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_invoke_ai_regional_prompt(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<ModelFile> sse_decode_list_model_file(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <ModelFile>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_model_file(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<NaiCharCaption> sse_decode_list_nai_char_caption(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <NaiCharCaption>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_nai_char_caption(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<NaiCoordinate> sse_decode_list_nai_coordinate(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <NaiCoordinate>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_nai_coordinate(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<NetworkReference> sse_decode_list_network_reference(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <NetworkReference>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_network_reference(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<PngTextChunk> sse_decode_list_png_text_chunk(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <PngTextChunk>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_png_text_chunk(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<double> sse_decode_list_prim_f_64_strict(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <double>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_f_64(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<int> sse_decode_list_prim_u_8_loose(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var len_ = sse_decode_i_32(deserializer);
+    return deserializer.buffer.getUint8List(len_);
+  }
+
+  @protected
+  Uint8List sse_decode_list_prim_u_8_strict(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var len_ = sse_decode_i_32(deserializer);
+    return deserializer.buffer.getUint8List(len_);
+  }
+
+  @protected
+  List<PromptTag> sse_decode_list_prompt_tag(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <PromptTag>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_prompt_tag(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<(String, String)> sse_decode_list_record_string_string(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <(String, String)>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_record_string_string(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<(String, BigInt)> sse_decode_list_record_string_u_64(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <(String, BigInt)>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_record_string_u_64(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<XmpProperty> sse_decode_list_xmp_property(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <XmpProperty>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_xmp_property(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  MetadataErrorKind sse_decode_metadata_error_kind(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_i_32(deserializer);
+    return MetadataErrorKind.values[inner];
+  }
+
+  @protected
+  ModelFile sse_decode_model_file(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_filePath = sse_decode_String(deserializer);
+    var var_fileSize = sse_decode_u_64(deserializer);
+    var var_fileLastModified = sse_decode_u_64(deserializer);
+    var var_sha256 = sse_decode_String(deserializer);
+    var var_autoV1 = sse_decode_String(deserializer);
+    var var_autoV2 = sse_decode_String(deserializer);
+    return ModelFile(
+      filePath: var_filePath,
+      fileSize: var_fileSize,
+      fileLastModified: var_fileLastModified,
+      sha256: var_sha256,
+      autoV1: var_autoV1,
+      autoV2: var_autoV2,
+    );
+  }
+
+  @protected
+  NaiCharCaption sse_decode_nai_char_caption(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_charCaption = sse_decode_String(deserializer);
+    var var_centers = sse_decode_list_nai_coordinate(deserializer);
+    return NaiCharCaption(charCaption: var_charCaption, centers: var_centers);
+  }
+
+  @protected
+  NaiCoordinate sse_decode_nai_coordinate(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_x = sse_decode_f_64(deserializer);
+    var var_y = sse_decode_f_64(deserializer);
+    return NaiCoordinate(x: var_x, y: var_y);
+  }
+
+  @protected
+  NaiMetadata sse_decode_nai_metadata(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_title = sse_decode_opt_String(deserializer);
+    var var_description = sse_decode_opt_String(deserializer);
+    var var_software = sse_decode_opt_String(deserializer);
+    var var_source = sse_decode_opt_String(deserializer);
+    var var_generationTime = sse_decode_opt_box_autoadd_f_64(deserializer);
+    var var_prompt = sse_decode_opt_String(deserializer);
+    var var_negativePrompt = sse_decode_opt_String(deserializer);
+    var var_seed = sse_decode_opt_box_autoadd_u_64(deserializer);
+    var var_steps = sse_decode_opt_box_autoadd_u_32(deserializer);
+    var var_sampler = sse_decode_opt_String(deserializer);
+    var var_noiseSchedule = sse_decode_opt_String(deserializer);
+    var var_scale = sse_decode_opt_box_autoadd_f_64(deserializer);
+    var var_cfgRescale = sse_decode_opt_box_autoadd_f_64(deserializer);
+    var var_uncondScale = sse_decode_opt_box_autoadd_f_64(deserializer);
+    var var_width = sse_decode_opt_box_autoadd_u_32(deserializer);
+    var var_height = sse_decode_opt_box_autoadd_u_32(deserializer);
+    var var_nSamples = sse_decode_opt_box_autoadd_u_32(deserializer);
+    var var_sm = sse_decode_opt_box_autoadd_bool(deserializer);
+    var var_smDyn = sse_decode_opt_box_autoadd_bool(deserializer);
+    var var_requestType = sse_decode_opt_String(deserializer);
+    var var_signedHash = sse_decode_opt_String(deserializer);
+    var var_v4Prompt = sse_decode_opt_box_autoadd_nai_v4_prompt(deserializer);
+    var var_v4NegativePrompt = sse_decode_opt_box_autoadd_nai_v4_prompt(
+      deserializer,
+    );
+    var var_referenceInformationExtracted = sse_decode_list_prim_f_64_strict(
+      deserializer,
+    );
+    var var_referenceStrength = sse_decode_list_prim_f_64_strict(deserializer);
+    return NaiMetadata(
+      title: var_title,
+      description: var_description,
+      software: var_software,
+      source: var_source,
+      generationTime: var_generationTime,
+      prompt: var_prompt,
+      negativePrompt: var_negativePrompt,
+      seed: var_seed,
+      steps: var_steps,
+      sampler: var_sampler,
+      noiseSchedule: var_noiseSchedule,
+      scale: var_scale,
+      cfgRescale: var_cfgRescale,
+      uncondScale: var_uncondScale,
+      width: var_width,
+      height: var_height,
+      nSamples: var_nSamples,
+      sm: var_sm,
+      smDyn: var_smDyn,
+      requestType: var_requestType,
+      signedHash: var_signedHash,
+      v4Prompt: var_v4Prompt,
+      v4NegativePrompt: var_v4NegativePrompt,
+      referenceInformationExtracted: var_referenceInformationExtracted,
+      referenceStrength: var_referenceStrength,
+    );
+  }
+
+  @protected
+  NaiSignatureStatus sse_decode_nai_signature_status(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_i_32(deserializer);
+    return NaiSignatureStatus.values[inner];
+  }
+
+  @protected
+  NaiV4Prompt sse_decode_nai_v4_prompt(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_baseCaption = sse_decode_String(deserializer);
+    var var_charCaptions = sse_decode_list_nai_char_caption(deserializer);
+    var var_useCoords = sse_decode_opt_box_autoadd_bool(deserializer);
+    var var_useOrder = sse_decode_opt_box_autoadd_bool(deserializer);
+    return NaiV4Prompt(
+      baseCaption: var_baseCaption,
+      charCaptions: var_charCaptions,
+      useCoords: var_useCoords,
+      useOrder: var_useOrder,
+    );
+  }
+
+  @protected
+  NetworkKind sse_decode_network_kind(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_i_32(deserializer);
+    return NetworkKind.values[inner];
+  }
+
+  @protected
+  NetworkReference sse_decode_network_reference(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_kind = sse_decode_network_kind(deserializer);
+    var var_name = sse_decode_String(deserializer);
+    var var_weight = sse_decode_opt_box_autoadd_f_64(deserializer);
+    var var_clipWeight = sse_decode_opt_box_autoadd_f_64(deserializer);
+    var var_hash = sse_decode_opt_String(deserializer);
+    return NetworkReference(
+      kind: var_kind,
+      name: var_name,
+      weight: var_weight,
+      clipWeight: var_clipWeight,
+      hash: var_hash,
+    );
+  }
+
+  @protected
+  String? sse_decode_opt_String(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_String(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  A1111Parameters? sse_decode_opt_box_autoadd_a1111_parameters(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_a1111_parameters(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  bool? sse_decode_opt_box_autoadd_bool(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_bool(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  ComfyUiMetadata? sse_decode_opt_box_autoadd_comfy_ui_metadata(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_comfy_ui_metadata(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  ComfyUiSummary? sse_decode_opt_box_autoadd_comfy_ui_summary(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_comfy_ui_summary(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  double? sse_decode_opt_box_autoadd_f_64(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_f_64(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  FolderScanResult? sse_decode_opt_box_autoadd_folder_scan_result(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_folder_scan_result(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  InvokeAiMetadata? sse_decode_opt_box_autoadd_invoke_ai_metadata(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_invoke_ai_metadata(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  MetadataErrorKind? sse_decode_opt_box_autoadd_metadata_error_kind(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_metadata_error_kind(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  ModelFile? sse_decode_opt_box_autoadd_model_file(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_model_file(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  NaiMetadata? sse_decode_opt_box_autoadd_nai_metadata(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_nai_metadata(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  NaiV4Prompt? sse_decode_opt_box_autoadd_nai_v4_prompt(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_nai_v4_prompt(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  int? sse_decode_opt_box_autoadd_u_32(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_u_32(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  BigInt? sse_decode_opt_box_autoadd_u_64(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_u_64(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  XmpMetadata? sse_decode_opt_box_autoadd_xmp_metadata(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_xmp_metadata(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  List<ImageScanResult>? sse_decode_opt_list_image_scan_result(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_list_image_scan_result(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  PngTextChunk sse_decode_png_text_chunk(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_chunkType = sse_decode_png_text_chunk_type(deserializer);
+    var var_keyword = sse_decode_String(deserializer);
+    var var_text = sse_decode_String(deserializer);
+    var var_languageTag = sse_decode_opt_String(deserializer);
+    var var_translatedKeyword = sse_decode_opt_String(deserializer);
+    return PngTextChunk(
+      chunkType: var_chunkType,
+      keyword: var_keyword,
+      text: var_text,
+      languageTag: var_languageTag,
+      translatedKeyword: var_translatedKeyword,
+    );
+  }
+
+  @protected
+  PngTextChunkType sse_decode_png_text_chunk_type(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_i_32(deserializer);
+    return PngTextChunkType.values[inner];
+  }
+
+  @protected
+  PromptDialect sse_decode_prompt_dialect(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_i_32(deserializer);
+    return PromptDialect.values[inner];
+  }
+
+  @protected
+  PromptTag sse_decode_prompt_tag(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_text = sse_decode_String(deserializer);
+    var var_weight = sse_decode_f_64(deserializer);
+    var var_kind = sse_decode_prompt_tag_kind(deserializer);
+    var var_startsAt = sse_decode_opt_box_autoadd_f_64(deserializer);
+    var var_endsAt = sse_decode_opt_box_autoadd_f_64(deserializer);
+    return PromptTag(
+      text: var_text,
+      weight: var_weight,
+      kind: var_kind,
+      startsAt: var_startsAt,
+      endsAt: var_endsAt,
+    );
+  }
+
+  @protected
+  PromptTagKind sse_decode_prompt_tag_kind(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_i_32(deserializer);
+    return PromptTagKind.values[inner];
+  }
+
+  @protected
+  (String, String) sse_decode_record_string_string(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_field0 = sse_decode_String(deserializer);
+    var var_field1 = sse_decode_String(deserializer);
+    return (var_field0, var_field1);
+  }
+
+  @protected
+  (String, BigInt) sse_decode_record_string_u_64(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_field0 = sse_decode_String(deserializer);
+    var var_field1 = sse_decode_u_64(deserializer);
+    return (var_field0, var_field1);
+  }
+
+  @protected
+  ScanProgress sse_decode_scan_progress(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_totalToProcess = sse_decode_u_32(deserializer);
+    var var_processed = sse_decode_u_32(deserializer);
+    var var_imageScanResults = sse_decode_opt_list_image_scan_result(
+      deserializer,
+    );
+    var var_folderScanResult = sse_decode_opt_box_autoadd_folder_scan_result(
+      deserializer,
+    );
+    return ScanProgress(
+      totalToProcess: var_totalToProcess,
+      processed: var_processed,
+      imageScanResults: var_imageScanResults,
+      folderScanResult: var_folderScanResult,
+    );
+  }
+
+  @protected
+  StealthChannel sse_decode_stealth_channel(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_i_32(deserializer);
+    return StealthChannel.values[inner];
+  }
+
+  @protected
+  StripOptions sse_decode_strip_options(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_removeTextChunks = sse_decode_bool(deserializer);
+    var var_removeExif = sse_decode_bool(deserializer);
+    var var_removeXmp = sse_decode_bool(deserializer);
+    var var_removeC2pa = sse_decode_bool(deserializer);
+    var var_removeStealth = sse_decode_bool(deserializer);
+    var var_keepPrompt = sse_decode_bool(deserializer);
+    return StripOptions(
+      removeTextChunks: var_removeTextChunks,
+      removeExif: var_removeExif,
+      removeXmp: var_removeXmp,
+      removeC2pa: var_removeC2pa,
+      removeStealth: var_removeStealth,
+      keepPrompt: var_keepPrompt,
+    );
+  }
+
+  @protected
+  int sse_decode_u_32(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return deserializer.buffer.getUint32();
+  }
+
+  @protected
+  BigInt sse_decode_u_64(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return deserializer.buffer.getBigUint64();
+  }
+
+  @protected
+  int sse_decode_u_8(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return deserializer.buffer.getUint8();
+  }
+
+  @protected
+  void sse_decode_unit(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+  }
+
+  @protected
+  XmpMetadata sse_decode_xmp_metadata(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_description = sse_decode_opt_String(deserializer);
+    var var_subject = sse_decode_list_String(deserializer);
+    var var_rating = sse_decode_opt_box_autoadd_f_64(deserializer);
+    var var_userComment = sse_decode_opt_String(deserializer);
+    var var_properties = sse_decode_list_xmp_property(deserializer);
+    return XmpMetadata(
+      description: var_description,
+      subject: var_subject,
+      rating: var_rating,
+      userComment: var_userComment,
+      properties: var_properties,
+    );
+  }
+
+  @protected
+  XmpProperty sse_decode_xmp_property(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_namespace = sse_decode_String(deserializer);
+    var var_key = sse_decode_String(deserializer);
+    var var_value = sse_decode_String(deserializer);
+    var var_language = sse_decode_opt_String(deserializer);
+    return XmpProperty(
+      namespace: var_namespace,
+      key: var_key,
+      value: var_value,
+      language: var_language,
+    );
+  }
+
+  @protected
+  void sse_encode_AnyhowException(
+    AnyhowException self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.message, serializer);
+  }
+
+  @protected
+  void sse_encode_Map_String_u_64_None(
+    Map<String, BigInt> self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_list_record_string_u_64(
+      self.entries.map((e) => (e.key, e.value)).toList(),
+      serializer,
+    );
+  }
+
+  @protected
+  void sse_encode_StreamSink_scan_progress_Sse(
+    RustStreamSink<ScanProgress> self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(
+      self.setupAndSerialize(
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_scan_progress,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+      ),
+      serializer,
+    );
+  }
+
+  @protected
+  void sse_encode_String(String self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_list_prim_u_8_strict(utf8.encoder.convert(self), serializer);
+  }
+
+  @protected
+  void sse_encode_a1111_parameters(
+    A1111Parameters self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.prompt, serializer);
+    sse_encode_opt_String(self.negativePrompt, serializer);
+    sse_encode_opt_box_autoadd_u_32(self.steps, serializer);
+    sse_encode_opt_String(self.sampler, serializer);
+    sse_encode_opt_String(self.scheduleType, serializer);
+    sse_encode_opt_box_autoadd_f_64(self.cfgScale, serializer);
+    sse_encode_opt_box_autoadd_u_64(self.seed, serializer);
+    sse_encode_opt_box_autoadd_u_32(self.width, serializer);
+    sse_encode_opt_box_autoadd_u_32(self.height, serializer);
+    sse_encode_opt_String(self.model, serializer);
+    sse_encode_opt_String(self.modelHash, serializer);
+    sse_encode_opt_box_autoadd_f_64(self.denoisingStrength, serializer);
+    sse_encode_list_record_string_string(self.settings, serializer);
+  }
+
+  @protected
+  void sse_encode_bool(bool self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    serializer.buffer.putUint8(self ? 1 : 0);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_a1111_parameters(
+    A1111Parameters self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_a1111_parameters(self, serializer);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_bool(bool self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_bool(self, serializer);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_comfy_ui_metadata(
+    ComfyUiMetadata self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_comfy_ui_metadata(self, serializer);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_comfy_ui_summary(
+    ComfyUiSummary self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_comfy_ui_summary(self, serializer);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_f_64(double self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_f_64(self, serializer);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_folder_scan_result(
+    FolderScanResult self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_folder_scan_result(self, serializer);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_invoke_ai_metadata(
+    InvokeAiMetadata self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_invoke_ai_metadata(self, serializer);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_metadata_error_kind(
+    MetadataErrorKind self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_metadata_error_kind(self, serializer);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_model_file(
+    ModelFile self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_model_file(self, serializer);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_nai_metadata(
+    NaiMetadata self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_nai_metadata(self, serializer);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_nai_v4_prompt(
+    NaiV4Prompt self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_nai_v4_prompt(self, serializer);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_strip_options(
+    StripOptions self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_strip_options(self, serializer);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_u_32(int self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_u_32(self, serializer);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_u_64(BigInt self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_u_64(self, serializer);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_xmp_metadata(
+    XmpMetadata self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_xmp_metadata(self, serializer);
+  }
+
+  @protected
+  void sse_encode_c2pa_action(C2paAction self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.action, serializer);
+    sse_encode_opt_String(self.softwareAgent, serializer);
+    sse_encode_opt_String(self.digitalSourceType, serializer);
+    sse_encode_opt_String(self.when, serializer);
+  }
+
+  @protected
+  void sse_encode_c2pa_ingredient(
+    C2paIngredient self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_opt_String(self.title, serializer);
+    sse_encode_opt_String(self.format, serializer);
+    sse_encode_opt_String(self.relationship, serializer);
+  }
+
+  @protected
+  void sse_encode_c2pa_manifest(C2paManifest self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.label, serializer);
+    sse_encode_opt_String(self.claimGenerator, serializer);
+    sse_encode_opt_String(self.title, serializer);
+    sse_encode_opt_String(self.format, serializer);
+    sse_encode_list_c2pa_action(self.actions, serializer);
+    sse_encode_list_c2pa_ingredient(self.ingredients, serializer);
+    sse_encode_list_String(self.assertionLabels, serializer);
   }
 
   @protected
-  ScanProgress dco_decode_scan_progress(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    final arr = raw as List<dynamic>;
-    if (arr.length != 4)
-      throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
-    return ScanProgress(
-      totalToProcess: dco_decode_u_32(arr[0]),
-      processed: dco_decode_u_32(arr[1]),
-      imageScanResults: dco_decode_opt_list_image_scan_result(arr[2]),
-      folderScanResult: dco_decode_opt_box_autoadd_folder_scan_result(arr[3]),
-    );
+  void sse_encode_comfy_ui_lora(ComfyUiLora self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.name, serializer);
+    sse_encode_opt_box_autoadd_f_64(self.strengthModel, serializer);
+    sse_encode_opt_box_autoadd_f_64(self.strengthClip, serializer);
   }
 
   @protected
-  int dco_decode_u_32(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw as int;
+  void sse_encode_comfy_ui_metadata(
+    ComfyUiMetadata self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_opt_String(self.promptGraph, serializer);
+    sse_encode_opt_String(self.workflow, serializer);
+    sse_encode_opt_box_autoadd_comfy_ui_summary(self.summary, serializer);
   }
 
   @protected
-  BigInt dco_decode_u_64(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return dcoDecodeU64(raw);
+  void sse_encode_comfy_ui_summary(
+    ComfyUiSummary self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_opt_String(self.positivePrompt, serializer);
+    sse_encode_opt_String(self.negativePrompt, serializer);
+    sse_encode_opt_box_autoadd_u_64(self.seed, serializer);
+    sse_encode_opt_box_autoadd_u_32(self.steps, serializer);
+    sse_encode_opt_box_autoadd_f_64(self.cfg, serializer);
+    sse_encode_opt_String(self.samplerName, serializer);
+    sse_encode_opt_String(self.scheduler, serializer);
+    sse_encode_opt_box_autoadd_f_64(self.denoise, serializer);
+    sse_encode_opt_String(self.checkpoint, serializer);
+    sse_encode_list_comfy_ui_lora(self.loras, serializer);
   }
 
   @protected
-  int dco_decode_u_8(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw as int;
+  void sse_encode_f_64(double self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    serializer.buffer.putFloat64(self);
   }
 
   @protected
-  void dco_decode_unit(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return;
+  void sse_encode_folder_scan_result(
+    FolderScanResult self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.folderPath, serializer);
+    sse_encode_u_64(self.scanTimestamp, serializer);
+    sse_encode_u_32(self.totalImageCount, serializer);
   }
 
   @protected
-  BigInt dco_decode_usize(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return dcoDecodeU64(raw);
+  void sse_encode_generator(Generator self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.index, serializer);
   }
 
   @protected
-  AnyhowException sse_decode_AnyhowException(SseDeserializer deserializer) {
+  void sse_encode_i_32(int self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    var inner = sse_decode_String(deserializer);
-    return AnyhowException(inner);
+    serializer.buffer.putInt32(self);
   }
 
   @protected
-  DataReader
-  sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDataReader(
-    SseDeserializer deserializer,
-  ) {
+  void sse_encode_image_info(ImageInfo self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    return DataReaderImpl.frbInternalSseDecode(
-      sse_decode_usize(deserializer),
-      sse_decode_i_32(deserializer),
+    sse_encode_f_64(self.aspectRatio, serializer);
+    sse_encode_generator(self.generator, serializer);
+    sse_encode_opt_String(self.metadataString, serializer);
+    sse_encode_opt_box_autoadd_nai_metadata(self.naiMetadata, serializer);
+    sse_encode_opt_box_autoadd_a1111_parameters(
+      self.a1111Parameters,
+      serializer,
     );
+    sse_encode_opt_box_autoadd_a1111_parameters(
+      self.fooocusParameters,
+      serializer,
+    );
+    sse_encode_opt_box_autoadd_a1111_parameters(
+      self.swarmuiParameters,
+      serializer,
+    );
+    sse_encode_opt_box_autoadd_comfy_ui_metadata(self.comfyui, serializer);
+    sse_encode_opt_box_autoadd_invoke_ai_metadata(self.invokeai, serializer);
+    sse_encode_list_network_reference(self.networks, serializer);
+    sse_encode_list_png_text_chunk(self.textChunks, serializer);
+    sse_encode_opt_String(self.xmpPacket, serializer);
+    sse_encode_opt_box_autoadd_xmp_metadata(self.xmp, serializer);
+    sse_encode_list_c2pa_manifest(self.c2paManifests, serializer);
+    sse_encode_opt_box_autoadd_metadata_error_kind(
+      self.stealthErrorKind,
+      serializer,
+    );
+    sse_encode_opt_String(self.stealthErrorMessage, serializer);
   }
 
   @protected
-  DataReader
-  sse_decode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDataReader(
-    SseDeserializer deserializer,
+  void sse_encode_image_scan_result(
+    ImageScanResult self,
+    SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    return DataReaderImpl.frbInternalSseDecode(
-      sse_decode_usize(deserializer),
-      sse_decode_i_32(deserializer),
-    );
+    sse_encode_String(self.filePath, serializer);
+    sse_encode_u_64(self.fileLastModified, serializer);
+    sse_encode_opt_box_autoadd_f_64(self.imageAspectRatio, serializer);
+    sse_encode_opt_String(self.metadataText, serializer);
+    sse_encode_opt_box_autoadd_metadata_error_kind(self.errorKind, serializer);
+    sse_encode_opt_String(self.errorMessage, serializer);
   }
 
   @protected
-  Map<String, BigInt> sse_decode_Map_String_u_64_None(
-    SseDeserializer deserializer,
+  void sse_encode_invoke_ai_control_layer(
+    InvokeAiControlLayer self,
+    SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    var inner = sse_decode_list_record_string_u_64(deserializer);
-    return Map.fromEntries(inner.map((e) => MapEntry(e.$1, e.$2)));
+    sse_encode_String(self.adapterType, serializer);
+    sse_encode_opt_String(self.model, serializer);
+    sse_encode_opt_box_autoadd_f_64(self.weight, serializer);
+    sse_encode_opt_box_autoadd_f_64(self.beginStepPercent, serializer);
+    sse_encode_opt_box_autoadd_f_64(self.endStepPercent, serializer);
+    sse_encode_bool(self.isEnabled, serializer);
   }
 
   @protected
-  DataReader
-  sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDataReader(
-    SseDeserializer deserializer,
-  ) {
+  void sse_encode_invoke_ai_lora(InvokeAiLora self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    return DataReaderImpl.frbInternalSseDecode(
-      sse_decode_usize(deserializer),
-      sse_decode_i_32(deserializer),
-    );
+    sse_encode_String(self.name, serializer);
+    sse_encode_opt_box_autoadd_f_64(self.weight, serializer);
   }
 
   @protected
-  RustStreamSink<ScanProgress> sse_decode_StreamSink_scan_progress_Sse(
-    SseDeserializer deserializer,
+  void sse_encode_invoke_ai_metadata(
+    InvokeAiMetadata self,
+    SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    throw UnimplementedError('Unreachable ()');
+    sse_encode_opt_String(self.metadata, serializer);
+    sse_encode_opt_String(self.graph, serializer);
+    sse_encode_opt_String(self.appVersion, serializer);
+    sse_encode_opt_String(self.generationMode, serializer);
+    sse_encode_opt_String(self.positivePrompt, serializer);
+    sse_encode_opt_String(self.negativePrompt, serializer);
+    sse_encode_opt_String(self.positiveStylePrompt, serializer);
+    sse_encode_opt_String(self.negativeStylePrompt, serializer);
+    sse_encode_opt_box_autoadd_u_64(self.seed, serializer);
+    sse_encode_opt_box_autoadd_u_32(self.steps, serializer);
+    sse_encode_opt_box_autoadd_f_64(self.cfgScale, serializer);
+    sse_encode_opt_box_autoadd_f_64(self.cfgRescaleMultiplier, serializer);
+    sse_encode_opt_String(self.scheduler, serializer);
+    sse_encode_opt_box_autoadd_u_32(self.width, serializer);
+    sse_encode_opt_box_autoadd_u_32(self.height, serializer);
+    sse_encode_opt_String(self.model, serializer);
+    sse_encode_opt_String(self.modelHash, serializer);
+    sse_encode_opt_String(self.vae, serializer);
+    sse_encode_list_invoke_ai_lora(self.loras, serializer);
+    sse_encode_list_invoke_ai_regional_prompt(self.regionalPrompts, serializer);
+    sse_encode_list_invoke_ai_control_layer(self.controlLayers, serializer);
+  }
+
+  @protected
+  void sse_encode_invoke_ai_regional_prompt(
+    InvokeAiRegionalPrompt self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_opt_String(self.name, serializer);
+    sse_encode_opt_String(self.positivePrompt, serializer);
+    sse_encode_opt_String(self.negativePrompt, serializer);
+    sse_encode_bool(self.isEnabled, serializer);
   }
 
   @protected
-  String sse_decode_String(SseDeserializer deserializer) {
+  void sse_encode_list_String(List<String> self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    var inner = sse_decode_list_prim_u_8_strict(deserializer);
-    return utf8.decoder.convert(inner);
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_String(item, serializer);
+    }
   }
 
   @protected
-  double sse_decode_box_autoadd_f_64(SseDeserializer deserializer) {
+  void sse_encode_list_c2pa_action(
+    List<C2paAction> self,
+    SseSerializer serializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    return (sse_decode_f_64(deserializer));
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_c2pa_action(item, serializer);
+    }
   }
 
   @protected
-  FolderScanResult sse_decode_box_autoadd_folder_scan_result(
-    SseDeserializer deserializer,
+  void sse_encode_list_c2pa_ingredient(
+    List<C2paIngredient> self,
+    SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    return (sse_decode_folder_scan_result(deserializer));
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_c2pa_ingredient(item, serializer);
+    }
   }
 
   @protected
-  double sse_decode_f_64(SseDeserializer deserializer) {
+  void sse_encode_list_c2pa_manifest(
+    List<C2paManifest> self,
+    SseSerializer serializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    return deserializer.buffer.getFloat64();
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_c2pa_manifest(item, serializer);
+    }
   }
 
   @protected
-  FolderScanResult sse_decode_folder_scan_result(SseDeserializer deserializer) {
+  void sse_encode_list_comfy_ui_lora(
+    List<ComfyUiLora> self,
+    SseSerializer serializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    var var_folderPath = sse_decode_String(deserializer);
-    var var_scanTimestamp = sse_decode_u_64(deserializer);
-    var var_totalImageCount = sse_decode_u_32(deserializer);
-    return FolderScanResult(
-      folderPath: var_folderPath,
-      scanTimestamp: var_scanTimestamp,
-      totalImageCount: var_totalImageCount,
-    );
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_comfy_ui_lora(item, serializer);
+    }
   }
 
   @protected
-  int sse_decode_i_32(SseDeserializer deserializer) {
+  void sse_encode_list_image_scan_result(
+    List<ImageScanResult> self,
+    SseSerializer serializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    return deserializer.buffer.getInt32();
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_image_scan_result(item, serializer);
+    }
   }
 
   @protected
-  ImageInfo sse_decode_image_info(SseDeserializer deserializer) {
+  void sse_encode_list_invoke_ai_control_layer(
+    List<InvokeAiControlLayer> self,
+    SseSerializer serializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    var var_aspectRatio = sse_decode_f_64(deserializer);
-    var var_metadataString = sse_decode_opt_String(deserializer);
-    return ImageInfo(
-      aspectRatio: var_aspectRatio,
-      metadataString: var_metadataString,
-    );
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_invoke_ai_control_layer(item, serializer);
+    }
   }
 
   @protected
-  ImageScanResult sse_decode_image_scan_result(SseDeserializer deserializer) {
+  void sse_encode_list_invoke_ai_lora(
+    List<InvokeAiLora> self,
+    SseSerializer serializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    var var_filePath = sse_decode_String(deserializer);
-    var var_fileLastModified = sse_decode_u_64(deserializer);
-    var var_imageAspectRatio = sse_decode_opt_box_autoadd_f_64(deserializer);
-    var var_metadataText = sse_decode_opt_String(deserializer);
-    return ImageScanResult(
-      filePath: var_filePath,
-      fileLastModified: var_fileLastModified,
-      imageAspectRatio: var_imageAspectRatio,
-      metadataText: var_metadataText,
-    );
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_invoke_ai_lora(item, serializer);
+    }
   }
 
   @protected
-  List<ImageScanResult> sse_decode_list_image_scan_result(
-    SseDeserializer deserializer,
+  void sse_encode_list_invoke_ai_regional_prompt(
+    List<InvokeAiRegionalPrompt> self,
+    SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    var len_ = sse_decode_i_32(deserializer);
-    var ans_ = <ImageScanResult>[];
-    for (var idx_ = 0; idx_ < len_; ++idx_) {
-      ans_.add(sse_decode_image_scan_result(deserializer));
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_invoke_ai_regional_prompt(item, serializer);
     }
-    return ans_;
   }
 
   @protected
-  List<int> sse_decode_list_prim_u_8_loose(SseDeserializer deserializer) {
+  void sse_encode_list_model_file(
+    List<ModelFile> self,
+    SseSerializer serializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    var len_ = sse_decode_i_32(deserializer);
-    return deserializer.buffer.getUint8List(len_);
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_model_file(item, serializer);
+    }
   }
 
   @protected
-  Uint8List sse_decode_list_prim_u_8_strict(SseDeserializer deserializer) {
+  void sse_encode_list_nai_char_caption(
+    List<NaiCharCaption> self,
+    SseSerializer serializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    var len_ = sse_decode_i_32(deserializer);
-    return deserializer.buffer.getUint8List(len_);
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_nai_char_caption(item, serializer);
+    }
   }
 
   @protected
-  List<(String, BigInt)> sse_decode_list_record_string_u_64(
-    SseDeserializer deserializer,
+  void sse_encode_list_nai_coordinate(
+    List<NaiCoordinate> self,
+    SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    var len_ = sse_decode_i_32(deserializer);
-    var ans_ = <(String, BigInt)>[];
-    for (var idx_ = 0; idx_ < len_; ++idx_) {
-      ans_.add(sse_decode_record_string_u_64(deserializer));
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_nai_coordinate(item, serializer);
     }
-    return ans_;
   }
 
   @protected
-  String? sse_decode_opt_String(SseDeserializer deserializer) {
+  void sse_encode_list_network_reference(
+    List<NetworkReference> self,
+    SseSerializer serializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    if (sse_decode_bool(deserializer)) {
-      return (sse_decode_String(deserializer));
-    } else {
-      return null;
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_network_reference(item, serializer);
     }
   }
 
   @protected
-  double? sse_decode_opt_box_autoadd_f_64(SseDeserializer deserializer) {
+  void sse_encode_list_png_text_chunk(
+    List<PngTextChunk> self,
+    SseSerializer serializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_png_text_chunk(item, serializer);
+    }
+  }
 
-    if (sse_decode_bool(deserializer)) {
-      return (sse_decode_box_autoadd_f_64(deserializer));
-    } else {
-      return null;
+  @protected
+  void sse_encode_list_prim_f_64_strict(
+    List<double> self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_f_64(item, serializer);
     }
   }
 
   @protected
-  FolderScanResult? sse_decode_opt_box_autoadd_folder_scan_result(
-    SseDeserializer deserializer,
+  void sse_encode_list_prim_u_8_loose(
+    List<int> self,
+    SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    serializer.buffer.putUint8List(
+      self is Uint8List ? self : Uint8List.fromList(self),
+    );
+  }
 
-    if (sse_decode_bool(deserializer)) {
-      return (sse_decode_box_autoadd_folder_scan_result(deserializer));
-    } else {
-      return null;
-    }
+  @protected
+  void sse_encode_list_prim_u_8_strict(
+    Uint8List self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    serializer.buffer.putUint8List(self);
   }
 
   @protected
-  List<ImageScanResult>? sse_decode_opt_list_image_scan_result(
-    SseDeserializer deserializer,
+  void sse_encode_list_prompt_tag(
+    List<PromptTag> self,
+    SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    if (sse_decode_bool(deserializer)) {
-      return (sse_decode_list_image_scan_result(deserializer));
-    } else {
-      return null;
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_prompt_tag(item, serializer);
     }
   }
 
   @protected
-  (String, BigInt) sse_decode_record_string_u_64(SseDeserializer deserializer) {
+  void sse_encode_list_record_string_string(
+    List<(String, String)> self,
+    SseSerializer serializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    var var_field0 = sse_decode_String(deserializer);
-    var var_field1 = sse_decode_u_64(deserializer);
-    return (var_field0, var_field1);
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_record_string_string(item, serializer);
+    }
   }
 
   @protected
-  ScanProgress sse_decode_scan_progress(SseDeserializer deserializer) {
+  void sse_encode_list_record_string_u_64(
+    List<(String, BigInt)> self,
+    SseSerializer serializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    var var_totalToProcess = sse_decode_u_32(deserializer);
-    var var_processed = sse_decode_u_32(deserializer);
-    var var_imageScanResults = sse_decode_opt_list_image_scan_result(
-      deserializer,
-    );
-    var var_folderScanResult = sse_decode_opt_box_autoadd_folder_scan_result(
-      deserializer,
-    );
-    return ScanProgress(
-      totalToProcess: var_totalToProcess,
-      processed: var_processed,
-      imageScanResults: var_imageScanResults,
-      folderScanResult: var_folderScanResult,
-    );
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_record_string_u_64(item, serializer);
+    }
   }
 
   @protected
-  int sse_decode_u_32(SseDeserializer deserializer) {
+  void sse_encode_list_xmp_property(
+    List<XmpProperty> self,
+    SseSerializer serializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    return deserializer.buffer.getUint32();
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_xmp_property(item, serializer);
+    }
   }
 
   @protected
-  BigInt sse_decode_u_64(SseDeserializer deserializer) {
+  void sse_encode_metadata_error_kind(
+    MetadataErrorKind self,
+    SseSerializer serializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    return deserializer.buffer.getBigUint64();
+    sse_encode_i_32(self.index, serializer);
   }
 
   @protected
-  int sse_decode_u_8(SseDeserializer deserializer) {
+  void sse_encode_model_file(ModelFile self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    return deserializer.buffer.getUint8();
+    sse_encode_String(self.filePath, serializer);
+    sse_encode_u_64(self.fileSize, serializer);
+    sse_encode_u_64(self.fileLastModified, serializer);
+    sse_encode_String(self.sha256, serializer);
+    sse_encode_String(self.autoV1, serializer);
+    sse_encode_String(self.autoV2, serializer);
   }
 
   @protected
-  void sse_decode_unit(SseDeserializer deserializer) {
+  void sse_encode_nai_char_caption(
+    NaiCharCaption self,
+    SseSerializer serializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.charCaption, serializer);
+    sse_encode_list_nai_coordinate(self.centers, serializer);
   }
 
   @protected
-  BigInt sse_decode_usize(SseDeserializer deserializer) {
+  void sse_encode_nai_coordinate(NaiCoordinate self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    return deserializer.buffer.getBigUint64();
+    sse_encode_f_64(self.x, serializer);
+    sse_encode_f_64(self.y, serializer);
   }
 
   @protected
-  bool sse_decode_bool(SseDeserializer deserializer) {
+  void sse_encode_nai_metadata(NaiMetadata self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    return deserializer.buffer.getUint8() != 0;
+    sse_encode_opt_String(self.title, serializer);
+    sse_encode_opt_String(self.description, serializer);
+    sse_encode_opt_String(self.software, serializer);
+    sse_encode_opt_String(self.source, serializer);
+    sse_encode_opt_box_autoadd_f_64(self.generationTime, serializer);
+    sse_encode_opt_String(self.prompt, serializer);
+    sse_encode_opt_String(self.negativePrompt, serializer);
+    sse_encode_opt_box_autoadd_u_64(self.seed, serializer);
+    sse_encode_opt_box_autoadd_u_32(self.steps, serializer);
+    sse_encode_opt_String(self.sampler, serializer);
+    sse_encode_opt_String(self.noiseSchedule, serializer);
+    sse_encode_opt_box_autoadd_f_64(self.scale, serializer);
+    sse_encode_opt_box_autoadd_f_64(self.cfgRescale, serializer);
+    sse_encode_opt_box_autoadd_f_64(self.uncondScale, serializer);
+    sse_encode_opt_box_autoadd_u_32(self.width, serializer);
+    sse_encode_opt_box_autoadd_u_32(self.height, serializer);
+    sse_encode_opt_box_autoadd_u_32(self.nSamples, serializer);
+    sse_encode_opt_box_autoadd_bool(self.sm, serializer);
+    sse_encode_opt_box_autoadd_bool(self.smDyn, serializer);
+    sse_encode_opt_String(self.requestType, serializer);
+    sse_encode_opt_String(self.signedHash, serializer);
+    sse_encode_opt_box_autoadd_nai_v4_prompt(self.v4Prompt, serializer);
+    sse_encode_opt_box_autoadd_nai_v4_prompt(self.v4NegativePrompt, serializer);
+    sse_encode_list_prim_f_64_strict(
+      self.referenceInformationExtracted,
+      serializer,
+    );
+    sse_encode_list_prim_f_64_strict(self.referenceStrength, serializer);
   }
 
   @protected
-  void sse_encode_AnyhowException(
-    AnyhowException self,
+  void sse_encode_nai_signature_status(
+    NaiSignatureStatus self,
     SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_String(self.message, serializer);
+    sse_encode_i_32(self.index, serializer);
   }
 
   @protected
-  void
-  sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDataReader(
-    DataReader self,
-    SseSerializer serializer,
-  ) {
+  void sse_encode_nai_v4_prompt(NaiV4Prompt self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_usize(
-      (self as DataReaderImpl).frbInternalSseEncode(move: true),
-      serializer,
-    );
+    sse_encode_String(self.baseCaption, serializer);
+    sse_encode_list_nai_char_caption(self.charCaptions, serializer);
+    sse_encode_opt_box_autoadd_bool(self.useCoords, serializer);
+    sse_encode_opt_box_autoadd_bool(self.useOrder, serializer);
   }
 
   @protected
-  void
-  sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDataReader(
-    DataReader self,
-    SseSerializer serializer,
-  ) {
+  void sse_encode_network_kind(NetworkKind self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_usize(
-      (self as DataReaderImpl).frbInternalSseEncode(move: false),
-      serializer,
-    );
+    sse_encode_i_32(self.index, serializer);
   }
 
   @protected
-  void sse_encode_Map_String_u_64_None(
-    Map<String, BigInt> self,
+  void sse_encode_network_reference(
+    NetworkReference self,
     SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_list_record_string_u_64(
-      self.entries.map((e) => (e.key, e.value)).toList(),
-      serializer,
-    );
+    sse_encode_network_kind(self.kind, serializer);
+    sse_encode_String(self.name, serializer);
+    sse_encode_opt_box_autoadd_f_64(self.weight, serializer);
+    sse_encode_opt_box_autoadd_f_64(self.clipWeight, serializer);
+    sse_encode_opt_String(self.hash, serializer);
   }
 
   @protected
-  void
-  sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDataReader(
-    DataReader self,
-    SseSerializer serializer,
-  ) {
+  void sse_encode_opt_String(String? self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_usize(
-      (self as DataReaderImpl).frbInternalSseEncode(move: null),
-      serializer,
-    );
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_String(self, serializer);
+    }
   }
 
   @protected
-  void sse_encode_StreamSink_scan_progress_Sse(
-    RustStreamSink<ScanProgress> self,
+  void sse_encode_opt_box_autoadd_a1111_parameters(
+    A1111Parameters? self,
     SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_String(
-      self.setupAndSerialize(
-        codec: SseCodec(
-          decodeSuccessData: sse_decode_scan_progress,
-          decodeErrorData: sse_decode_AnyhowException,
-        ),
-      ),
-      serializer,
-    );
-  }
 
-  @protected
-  void sse_encode_String(String self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_list_prim_u_8_strict(utf8.encoder.convert(self), serializer);
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_a1111_parameters(self, serializer);
+    }
   }
 
   @protected
-  void sse_encode_box_autoadd_f_64(double self, SseSerializer serializer) {
+  void sse_encode_opt_box_autoadd_bool(bool? self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_f_64(self, serializer);
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_bool(self, serializer);
+    }
   }
 
   @protected
-  void sse_encode_box_autoadd_folder_scan_result(
-    FolderScanResult self,
+  void sse_encode_opt_box_autoadd_comfy_ui_metadata(
+    ComfyUiMetadata? self,
     SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_folder_scan_result(self, serializer);
-  }
 
-  @protected
-  void sse_encode_f_64(double self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    serializer.buffer.putFloat64(self);
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_comfy_ui_metadata(self, serializer);
+    }
   }
 
   @protected
-  void sse_encode_folder_scan_result(
-    FolderScanResult self,
+  void sse_encode_opt_box_autoadd_comfy_ui_summary(
+    ComfyUiSummary? self,
     SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_String(self.folderPath, serializer);
-    sse_encode_u_64(self.scanTimestamp, serializer);
-    sse_encode_u_32(self.totalImageCount, serializer);
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_comfy_ui_summary(self, serializer);
+    }
   }
 
   @protected
-  void sse_encode_i_32(int self, SseSerializer serializer) {
+  void sse_encode_opt_box_autoadd_f_64(double? self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    serializer.buffer.putInt32(self);
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_f_64(self, serializer);
+    }
   }
 
   @protected
-  void sse_encode_image_info(ImageInfo self, SseSerializer serializer) {
+  void sse_encode_opt_box_autoadd_folder_scan_result(
+    FolderScanResult? self,
+    SseSerializer serializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_f_64(self.aspectRatio, serializer);
-    sse_encode_opt_String(self.metadataString, serializer);
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_folder_scan_result(self, serializer);
+    }
   }
 
   @protected
-  void sse_encode_image_scan_result(
-    ImageScanResult self,
+  void sse_encode_opt_box_autoadd_invoke_ai_metadata(
+    InvokeAiMetadata? self,
     SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_String(self.filePath, serializer);
-    sse_encode_u_64(self.fileLastModified, serializer);
-    sse_encode_opt_box_autoadd_f_64(self.imageAspectRatio, serializer);
-    sse_encode_opt_String(self.metadataText, serializer);
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_invoke_ai_metadata(self, serializer);
+    }
   }
 
   @protected
-  void sse_encode_list_image_scan_result(
-    List<ImageScanResult> self,
+  void sse_encode_opt_box_autoadd_metadata_error_kind(
+    MetadataErrorKind? self,
     SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    for (final item in self) {
-      sse_encode_image_scan_result(item, serializer);
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_metadata_error_kind(self, serializer);
     }
   }
 
   @protected
-  void sse_encode_list_prim_u_8_loose(
-    List<int> self,
+  void sse_encode_opt_box_autoadd_model_file(
+    ModelFile? self,
     SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    serializer.buffer.putUint8List(
-      self is Uint8List ? self : Uint8List.fromList(self),
-    );
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_model_file(self, serializer);
+    }
   }
 
   @protected
-  void sse_encode_list_prim_u_8_strict(
-    Uint8List self,
+  void sse_encode_opt_box_autoadd_nai_metadata(
+    NaiMetadata? self,
     SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    serializer.buffer.putUint8List(self);
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_nai_metadata(self, serializer);
+    }
   }
 
   @protected
-  void sse_encode_list_record_string_u_64(
-    List<(String, BigInt)> self,
+  void sse_encode_opt_box_autoadd_nai_v4_prompt(
+    NaiV4Prompt? self,
     SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    for (final item in self) {
-      sse_encode_record_string_u_64(item, serializer);
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_nai_v4_prompt(self, serializer);
     }
   }
 
   @protected
-  void sse_encode_opt_String(String? self, SseSerializer serializer) {
+  void sse_encode_opt_box_autoadd_u_32(int? self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
 
     sse_encode_bool(self != null, serializer);
     if (self != null) {
-      sse_encode_String(self, serializer);
+      sse_encode_box_autoadd_u_32(self, serializer);
     }
   }
 
   @protected
-  void sse_encode_opt_box_autoadd_f_64(double? self, SseSerializer serializer) {
+  void sse_encode_opt_box_autoadd_u_64(BigInt? self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
 
     sse_encode_bool(self != null, serializer);
     if (self != null) {
-      sse_encode_box_autoadd_f_64(self, serializer);
+      sse_encode_box_autoadd_u_64(self, serializer);
     }
   }
 
   @protected
-  void sse_encode_opt_box_autoadd_folder_scan_result(
-    FolderScanResult? self,
+  void sse_encode_opt_box_autoadd_xmp_metadata(
+    XmpMetadata? self,
     SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
 
     sse_encode_bool(self != null, serializer);
     if (self != null) {
-      sse_encode_box_autoadd_folder_scan_result(self, serializer);
+      sse_encode_box_autoadd_xmp_metadata(self, serializer);
     }
   }
 
@@ -1143,6 +3685,60 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     }
   }
 
+  @protected
+  void sse_encode_png_text_chunk(PngTextChunk self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_png_text_chunk_type(self.chunkType, serializer);
+    sse_encode_String(self.keyword, serializer);
+    sse_encode_String(self.text, serializer);
+    sse_encode_opt_String(self.languageTag, serializer);
+    sse_encode_opt_String(self.translatedKeyword, serializer);
+  }
+
+  @protected
+  void sse_encode_png_text_chunk_type(
+    PngTextChunkType self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.index, serializer);
+  }
+
+  @protected
+  void sse_encode_prompt_dialect(PromptDialect self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.index, serializer);
+  }
+
+  @protected
+  void sse_encode_prompt_tag(PromptTag self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.text, serializer);
+    sse_encode_f_64(self.weight, serializer);
+    sse_encode_prompt_tag_kind(self.kind, serializer);
+    sse_encode_opt_box_autoadd_f_64(self.startsAt, serializer);
+    sse_encode_opt_box_autoadd_f_64(self.endsAt, serializer);
+  }
+
+  @protected
+  void sse_encode_prompt_tag_kind(
+    PromptTagKind self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.index, serializer);
+  }
+
+  @protected
+  void sse_encode_record_string_string(
+    (String, String) self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.$1, serializer);
+    sse_encode_String(self.$2, serializer);
+  }
+
   @protected
   void sse_encode_record_string_u_64(
     (String, BigInt) self,
@@ -1165,6 +3761,26 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     );
   }
 
+  @protected
+  void sse_encode_stealth_channel(
+    StealthChannel self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.index, serializer);
+  }
+
+  @protected
+  void sse_encode_strip_options(StripOptions self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_bool(self.removeTextChunks, serializer);
+    sse_encode_bool(self.removeExif, serializer);
+    sse_encode_bool(self.removeXmp, serializer);
+    sse_encode_bool(self.removeC2pa, serializer);
+    sse_encode_bool(self.removeStealth, serializer);
+    sse_encode_bool(self.keepPrompt, serializer);
+  }
+
   @protected
   void sse_encode_u_32(int self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -1189,46 +3805,21 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   }
 
   @protected
-  void sse_encode_usize(BigInt self, SseSerializer serializer) {
+  void sse_encode_xmp_metadata(XmpMetadata self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    serializer.buffer.putBigUint64(self);
+    sse_encode_opt_String(self.description, serializer);
+    sse_encode_list_String(self.subject, serializer);
+    sse_encode_opt_box_autoadd_f_64(self.rating, serializer);
+    sse_encode_opt_String(self.userComment, serializer);
+    sse_encode_list_xmp_property(self.properties, serializer);
   }
 
   @protected
-  void sse_encode_bool(bool self, SseSerializer serializer) {
+  void sse_encode_xmp_property(XmpProperty self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    serializer.buffer.putUint8(self ? 1 : 0);
+    sse_encode_String(self.namespace, serializer);
+    sse_encode_String(self.key, serializer);
+    sse_encode_String(self.value, serializer);
+    sse_encode_opt_String(self.language, serializer);
   }
 }
-
-@sealed
-class DataReaderImpl extends RustOpaque implements DataReader {
-  // Not to be used by end users
-  DataReaderImpl.frbInternalDcoDecode(List<dynamic> wire)
-    : super.frbInternalDcoDecode(wire, _kStaticData);
-
-  // Not to be used by end users
-  DataReaderImpl.frbInternalSseDecode(BigInt ptr, int externalSizeOnNative)
-    : super.frbInternalSseDecode(ptr, externalSizeOnNative, _kStaticData);
-
-  static final _kStaticData = RustArcStaticData(
-    rustArcIncrementStrongCount:
-        RustLib.instance.api.rust_arc_increment_strong_count_DataReader,
-    rustArcDecrementStrongCount:
-        RustLib.instance.api.rust_arc_decrement_strong_count_DataReader,
-    rustArcDecrementStrongCountPtr:
-        RustLib.instance.api.rust_arc_decrement_strong_count_DataReaderPtr,
-  );
-
-  Future<int> readBit() =>
-      RustLib.instance.api.crateApiMetadataDataReaderReadBit(that: this);
-
-  Future<int> readByte() =>
-      RustLib.instance.api.crateApiMetadataDataReaderReadByte(that: this);
-
-  Future<Uint8List> readBytes({required BigInt n}) => RustLib.instance.api
-      .crateApiMetadataDataReaderReadBytes(that: this, n: n);
-
-  Future<int> readInt32() =>
-      RustLib.instance.api.crateApiMetadataDataReaderReadInt32(that: this);
-}
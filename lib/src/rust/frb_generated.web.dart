@@ -6,9 +6,24 @@
 // Static analysis wrongly picks the IO variant, thus ignore this
 // ignore_for_file: argument_type_not_assignable
 
+import 'api/a1111.dart';
+import 'api/c2pa.dart';
+import 'api/comfyui.dart';
+import 'api/error.dart';
+import 'api/fooocus.dart';
+import 'api/generator.dart';
+import 'api/invokeai.dart';
 import 'api/metadata.dart';
+import 'api/models.dart';
+import 'api/nai.dart';
+import 'api/networks.dart';
+import 'api/prompt.dart';
 import 'api/scan.dart';
 import 'api/simple.dart';
+import 'api/strip.dart';
+import 'api/swarmui.dart';
+import 'api/writer.dart';
+import 'api/xmp.dart';
 import 'dart:async';
 import 'dart:convert';
 import 'frb_generated.dart';
@@ -22,323 +37,1095 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     required super.portManager,
   });
 
-  CrossPlatformFinalizerArg
-  get rust_arc_decrement_strong_count_DataReaderPtr => wire
-      .rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDataReader;
+  @protected
+  AnyhowException dco_decode_AnyhowException(dynamic raw);
+
+  @protected
+  Map<String, BigInt> dco_decode_Map_String_u_64_None(dynamic raw);
+
+  @protected
+  RustStreamSink<ScanProgress> dco_decode_StreamSink_scan_progress_Sse(
+    dynamic raw,
+  );
+
+  @protected
+  String dco_decode_String(dynamic raw);
+
+  @protected
+  A1111Parameters dco_decode_a1111_parameters(dynamic raw);
+
+  @protected
+  bool dco_decode_bool(dynamic raw);
+
+  @protected
+  A1111Parameters dco_decode_box_autoadd_a1111_parameters(dynamic raw);
+
+  @protected
+  bool dco_decode_box_autoadd_bool(dynamic raw);
+
+  @protected
+  ComfyUiMetadata dco_decode_box_autoadd_comfy_ui_metadata(dynamic raw);
+
+  @protected
+  ComfyUiSummary dco_decode_box_autoadd_comfy_ui_summary(dynamic raw);
+
+  @protected
+  double dco_decode_box_autoadd_f_64(dynamic raw);
+
+  @protected
+  FolderScanResult dco_decode_box_autoadd_folder_scan_result(dynamic raw);
+
+  @protected
+  InvokeAiMetadata dco_decode_box_autoadd_invoke_ai_metadata(dynamic raw);
+
+  @protected
+  MetadataErrorKind dco_decode_box_autoadd_metadata_error_kind(dynamic raw);
+
+  @protected
+  ModelFile dco_decode_box_autoadd_model_file(dynamic raw);
+
+  @protected
+  NaiMetadata dco_decode_box_autoadd_nai_metadata(dynamic raw);
+
+  @protected
+  NaiV4Prompt dco_decode_box_autoadd_nai_v4_prompt(dynamic raw);
+
+  @protected
+  StripOptions dco_decode_box_autoadd_strip_options(dynamic raw);
+
+  @protected
+  int dco_decode_box_autoadd_u_32(dynamic raw);
+
+  @protected
+  BigInt dco_decode_box_autoadd_u_64(dynamic raw);
+
+  @protected
+  XmpMetadata dco_decode_box_autoadd_xmp_metadata(dynamic raw);
+
+  @protected
+  C2paAction dco_decode_c2pa_action(dynamic raw);
+
+  @protected
+  C2paIngredient dco_decode_c2pa_ingredient(dynamic raw);
+
+  @protected
+  C2paManifest dco_decode_c2pa_manifest(dynamic raw);
+
+  @protected
+  ComfyUiLora dco_decode_comfy_ui_lora(dynamic raw);
+
+  @protected
+  ComfyUiMetadata dco_decode_comfy_ui_metadata(dynamic raw);
+
+  @protected
+  ComfyUiSummary dco_decode_comfy_ui_summary(dynamic raw);
+
+  @protected
+  double dco_decode_f_64(dynamic raw);
+
+  @protected
+  FolderScanResult dco_decode_folder_scan_result(dynamic raw);
+
+  @protected
+  Generator dco_decode_generator(dynamic raw);
+
+  @protected
+  int dco_decode_i_32(dynamic raw);
+
+  @protected
+  ImageInfo dco_decode_image_info(dynamic raw);
+
+  @protected
+  ImageScanResult dco_decode_image_scan_result(dynamic raw);
+
+  @protected
+  InvokeAiControlLayer dco_decode_invoke_ai_control_layer(dynamic raw);
+
+  @protected
+  InvokeAiLora dco_decode_invoke_ai_lora(dynamic raw);
+
+  @protected
+  InvokeAiMetadata dco_decode_invoke_ai_metadata(dynamic raw);
+
+  @protected
+  InvokeAiRegionalPrompt dco_decode_invoke_ai_regional_prompt(dynamic raw);
+
+  @protected
+  List<String> dco_decode_list_String(dynamic raw);
+
+  @protected
+  List<C2paAction> dco_decode_list_c2pa_action(dynamic raw);
+
+  @protected
+  List<C2paIngredient> dco_decode_list_c2pa_ingredient(dynamic raw);
+
+  @protected
+  List<C2paManifest> dco_decode_list_c2pa_manifest(dynamic raw);
+
+  @protected
+  List<ComfyUiLora> dco_decode_list_comfy_ui_lora(dynamic raw);
+
+  @protected
+  List<ImageScanResult> dco_decode_list_image_scan_result(dynamic raw);
+
+  @protected
+  List<InvokeAiControlLayer> dco_decode_list_invoke_ai_control_layer(
+    dynamic raw,
+  );
+
+  @protected
+  List<InvokeAiLora> dco_decode_list_invoke_ai_lora(dynamic raw);
+
+  @protected
+  List<InvokeAiRegionalPrompt> dco_decode_list_invoke_ai_regional_prompt(
+    dynamic raw,
+  );
+
+  @protected
+  List<ModelFile> dco_decode_list_model_file(dynamic raw);
+
+  @protected
+  List<NaiCharCaption> dco_decode_list_nai_char_caption(dynamic raw);
+
+  @protected
+  List<NaiCoordinate> dco_decode_list_nai_coordinate(dynamic raw);
+
+  @protected
+  List<NetworkReference> dco_decode_list_network_reference(dynamic raw);
+
+  @protected
+  List<PngTextChunk> dco_decode_list_png_text_chunk(dynamic raw);
+
+  @protected
+  List<double> dco_decode_list_prim_f_64_strict(dynamic raw);
+
+  @protected
+  List<int> dco_decode_list_prim_u_8_loose(dynamic raw);
+
+  @protected
+  Uint8List dco_decode_list_prim_u_8_strict(dynamic raw);
+
+  @protected
+  List<PromptTag> dco_decode_list_prompt_tag(dynamic raw);
+
+  @protected
+  List<(String, String)> dco_decode_list_record_string_string(dynamic raw);
+
+  @protected
+  List<(String, BigInt)> dco_decode_list_record_string_u_64(dynamic raw);
+
+  @protected
+  List<XmpProperty> dco_decode_list_xmp_property(dynamic raw);
+
+  @protected
+  MetadataErrorKind dco_decode_metadata_error_kind(dynamic raw);
+
+  @protected
+  ModelFile dco_decode_model_file(dynamic raw);
+
+  @protected
+  NaiCharCaption dco_decode_nai_char_caption(dynamic raw);
+
+  @protected
+  NaiCoordinate dco_decode_nai_coordinate(dynamic raw);
+
+  @protected
+  NaiMetadata dco_decode_nai_metadata(dynamic raw);
+
+  @protected
+  NaiSignatureStatus dco_decode_nai_signature_status(dynamic raw);
+
+  @protected
+  NaiV4Prompt dco_decode_nai_v4_prompt(dynamic raw);
+
+  @protected
+  NetworkKind dco_decode_network_kind(dynamic raw);
+
+  @protected
+  NetworkReference dco_decode_network_reference(dynamic raw);
+
+  @protected
+  String? dco_decode_opt_String(dynamic raw);
+
+  @protected
+  A1111Parameters? dco_decode_opt_box_autoadd_a1111_parameters(dynamic raw);
+
+  @protected
+  bool? dco_decode_opt_box_autoadd_bool(dynamic raw);
+
+  @protected
+  ComfyUiMetadata? dco_decode_opt_box_autoadd_comfy_ui_metadata(dynamic raw);
+
+  @protected
+  ComfyUiSummary? dco_decode_opt_box_autoadd_comfy_ui_summary(dynamic raw);
+
+  @protected
+  double? dco_decode_opt_box_autoadd_f_64(dynamic raw);
+
+  @protected
+  FolderScanResult? dco_decode_opt_box_autoadd_folder_scan_result(dynamic raw);
+
+  @protected
+  InvokeAiMetadata? dco_decode_opt_box_autoadd_invoke_ai_metadata(dynamic raw);
+
+  @protected
+  MetadataErrorKind? dco_decode_opt_box_autoadd_metadata_error_kind(
+    dynamic raw,
+  );
+
+  @protected
+  ModelFile? dco_decode_opt_box_autoadd_model_file(dynamic raw);
+
+  @protected
+  NaiMetadata? dco_decode_opt_box_autoadd_nai_metadata(dynamic raw);
+
+  @protected
+  NaiV4Prompt? dco_decode_opt_box_autoadd_nai_v4_prompt(dynamic raw);
+
+  @protected
+  int? dco_decode_opt_box_autoadd_u_32(dynamic raw);
+
+  @protected
+  BigInt? dco_decode_opt_box_autoadd_u_64(dynamic raw);
+
+  @protected
+  XmpMetadata? dco_decode_opt_box_autoadd_xmp_metadata(dynamic raw);
+
+  @protected
+  List<ImageScanResult>? dco_decode_opt_list_image_scan_result(dynamic raw);
+
+  @protected
+  PngTextChunk dco_decode_png_text_chunk(dynamic raw);
+
+  @protected
+  PngTextChunkType dco_decode_png_text_chunk_type(dynamic raw);
+
+  @protected
+  PromptDialect dco_decode_prompt_dialect(dynamic raw);
+
+  @protected
+  PromptTag dco_decode_prompt_tag(dynamic raw);
+
+  @protected
+  PromptTagKind dco_decode_prompt_tag_kind(dynamic raw);
+
+  @protected
+  (String, String) dco_decode_record_string_string(dynamic raw);
+
+  @protected
+  (String, BigInt) dco_decode_record_string_u_64(dynamic raw);
+
+  @protected
+  ScanProgress dco_decode_scan_progress(dynamic raw);
+
+  @protected
+  StealthChannel dco_decode_stealth_channel(dynamic raw);
+
+  @protected
+  StripOptions dco_decode_strip_options(dynamic raw);
+
+  @protected
+  int dco_decode_u_32(dynamic raw);
+
+  @protected
+  BigInt dco_decode_u_64(dynamic raw);
+
+  @protected
+  int dco_decode_u_8(dynamic raw);
+
+  @protected
+  void dco_decode_unit(dynamic raw);
+
+  @protected
+  XmpMetadata dco_decode_xmp_metadata(dynamic raw);
+
+  @protected
+  XmpProperty dco_decode_xmp_property(dynamic raw);
+
+  @protected
+  AnyhowException sse_decode_AnyhowException(SseDeserializer deserializer);
+
+  @protected
+  Map<String, BigInt> sse_decode_Map_String_u_64_None(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  RustStreamSink<ScanProgress> sse_decode_StreamSink_scan_progress_Sse(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  String sse_decode_String(SseDeserializer deserializer);
+
+  @protected
+  A1111Parameters sse_decode_a1111_parameters(SseDeserializer deserializer);
+
+  @protected
+  bool sse_decode_bool(SseDeserializer deserializer);
+
+  @protected
+  A1111Parameters sse_decode_box_autoadd_a1111_parameters(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  bool sse_decode_box_autoadd_bool(SseDeserializer deserializer);
+
+  @protected
+  ComfyUiMetadata sse_decode_box_autoadd_comfy_ui_metadata(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  ComfyUiSummary sse_decode_box_autoadd_comfy_ui_summary(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  double sse_decode_box_autoadd_f_64(SseDeserializer deserializer);
+
+  @protected
+  FolderScanResult sse_decode_box_autoadd_folder_scan_result(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  InvokeAiMetadata sse_decode_box_autoadd_invoke_ai_metadata(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  MetadataErrorKind sse_decode_box_autoadd_metadata_error_kind(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  ModelFile sse_decode_box_autoadd_model_file(SseDeserializer deserializer);
+
+  @protected
+  NaiMetadata sse_decode_box_autoadd_nai_metadata(SseDeserializer deserializer);
+
+  @protected
+  NaiV4Prompt sse_decode_box_autoadd_nai_v4_prompt(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  StripOptions sse_decode_box_autoadd_strip_options(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  int sse_decode_box_autoadd_u_32(SseDeserializer deserializer);
+
+  @protected
+  BigInt sse_decode_box_autoadd_u_64(SseDeserializer deserializer);
+
+  @protected
+  XmpMetadata sse_decode_box_autoadd_xmp_metadata(SseDeserializer deserializer);
+
+  @protected
+  C2paAction sse_decode_c2pa_action(SseDeserializer deserializer);
+
+  @protected
+  C2paIngredient sse_decode_c2pa_ingredient(SseDeserializer deserializer);
+
+  @protected
+  C2paManifest sse_decode_c2pa_manifest(SseDeserializer deserializer);
+
+  @protected
+  ComfyUiLora sse_decode_comfy_ui_lora(SseDeserializer deserializer);
+
+  @protected
+  ComfyUiMetadata sse_decode_comfy_ui_metadata(SseDeserializer deserializer);
+
+  @protected
+  ComfyUiSummary sse_decode_comfy_ui_summary(SseDeserializer deserializer);
+
+  @protected
+  double sse_decode_f_64(SseDeserializer deserializer);
+
+  @protected
+  FolderScanResult sse_decode_folder_scan_result(SseDeserializer deserializer);
+
+  @protected
+  Generator sse_decode_generator(SseDeserializer deserializer);
+
+  @protected
+  int sse_decode_i_32(SseDeserializer deserializer);
+
+  @protected
+  ImageInfo sse_decode_image_info(SseDeserializer deserializer);
+
+  @protected
+  ImageScanResult sse_decode_image_scan_result(SseDeserializer deserializer);
+
+  @protected
+  InvokeAiControlLayer sse_decode_invoke_ai_control_layer(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  InvokeAiLora sse_decode_invoke_ai_lora(SseDeserializer deserializer);
+
+  @protected
+  InvokeAiMetadata sse_decode_invoke_ai_metadata(SseDeserializer deserializer);
+
+  @protected
+  InvokeAiRegionalPrompt sse_decode_invoke_ai_regional_prompt(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  List<String> sse_decode_list_String(SseDeserializer deserializer);
+
+  @protected
+  List<C2paAction> sse_decode_list_c2pa_action(SseDeserializer deserializer);
+
+  @protected
+  List<C2paIngredient> sse_decode_list_c2pa_ingredient(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  List<C2paManifest> sse_decode_list_c2pa_manifest(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  List<ComfyUiLora> sse_decode_list_comfy_ui_lora(SseDeserializer deserializer);
+
+  @protected
+  List<ImageScanResult> sse_decode_list_image_scan_result(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  List<InvokeAiControlLayer> sse_decode_list_invoke_ai_control_layer(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  List<InvokeAiLora> sse_decode_list_invoke_ai_lora(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  List<InvokeAiRegionalPrompt> sse_decode_list_invoke_ai_regional_prompt(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  List<ModelFile> sse_decode_list_model_file(SseDeserializer deserializer);
+
+  @protected
+  List<NaiCharCaption> sse_decode_list_nai_char_caption(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  List<NaiCoordinate> sse_decode_list_nai_coordinate(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  List<NetworkReference> sse_decode_list_network_reference(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  List<PngTextChunk> sse_decode_list_png_text_chunk(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  List<double> sse_decode_list_prim_f_64_strict(SseDeserializer deserializer);
+
+  @protected
+  List<int> sse_decode_list_prim_u_8_loose(SseDeserializer deserializer);
+
+  @protected
+  Uint8List sse_decode_list_prim_u_8_strict(SseDeserializer deserializer);
+
+  @protected
+  List<PromptTag> sse_decode_list_prompt_tag(SseDeserializer deserializer);
+
+  @protected
+  List<(String, String)> sse_decode_list_record_string_string(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  List<(String, BigInt)> sse_decode_list_record_string_u_64(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  List<XmpProperty> sse_decode_list_xmp_property(SseDeserializer deserializer);
+
+  @protected
+  MetadataErrorKind sse_decode_metadata_error_kind(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  ModelFile sse_decode_model_file(SseDeserializer deserializer);
+
+  @protected
+  NaiCharCaption sse_decode_nai_char_caption(SseDeserializer deserializer);
+
+  @protected
+  NaiCoordinate sse_decode_nai_coordinate(SseDeserializer deserializer);
+
+  @protected
+  NaiMetadata sse_decode_nai_metadata(SseDeserializer deserializer);
+
+  @protected
+  NaiSignatureStatus sse_decode_nai_signature_status(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  NaiV4Prompt sse_decode_nai_v4_prompt(SseDeserializer deserializer);
+
+  @protected
+  NetworkKind sse_decode_network_kind(SseDeserializer deserializer);
+
+  @protected
+  NetworkReference sse_decode_network_reference(SseDeserializer deserializer);
+
+  @protected
+  String? sse_decode_opt_String(SseDeserializer deserializer);
+
+  @protected
+  A1111Parameters? sse_decode_opt_box_autoadd_a1111_parameters(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  bool? sse_decode_opt_box_autoadd_bool(SseDeserializer deserializer);
+
+  @protected
+  ComfyUiMetadata? sse_decode_opt_box_autoadd_comfy_ui_metadata(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  ComfyUiSummary? sse_decode_opt_box_autoadd_comfy_ui_summary(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  double? sse_decode_opt_box_autoadd_f_64(SseDeserializer deserializer);
+
+  @protected
+  FolderScanResult? sse_decode_opt_box_autoadd_folder_scan_result(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  InvokeAiMetadata? sse_decode_opt_box_autoadd_invoke_ai_metadata(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  MetadataErrorKind? sse_decode_opt_box_autoadd_metadata_error_kind(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  ModelFile? sse_decode_opt_box_autoadd_model_file(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  NaiMetadata? sse_decode_opt_box_autoadd_nai_metadata(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  NaiV4Prompt? sse_decode_opt_box_autoadd_nai_v4_prompt(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  int? sse_decode_opt_box_autoadd_u_32(SseDeserializer deserializer);
+
+  @protected
+  BigInt? sse_decode_opt_box_autoadd_u_64(SseDeserializer deserializer);
+
+  @protected
+  XmpMetadata? sse_decode_opt_box_autoadd_xmp_metadata(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  List<ImageScanResult>? sse_decode_opt_list_image_scan_result(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  PngTextChunk sse_decode_png_text_chunk(SseDeserializer deserializer);
+
+  @protected
+  PngTextChunkType sse_decode_png_text_chunk_type(SseDeserializer deserializer);
+
+  @protected
+  PromptDialect sse_decode_prompt_dialect(SseDeserializer deserializer);
+
+  @protected
+  PromptTag sse_decode_prompt_tag(SseDeserializer deserializer);
+
+  @protected
+  PromptTagKind sse_decode_prompt_tag_kind(SseDeserializer deserializer);
+
+  @protected
+  (String, String) sse_decode_record_string_string(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  (String, BigInt) sse_decode_record_string_u_64(SseDeserializer deserializer);
+
+  @protected
+  ScanProgress sse_decode_scan_progress(SseDeserializer deserializer);
 
   @protected
-  AnyhowException dco_decode_AnyhowException(dynamic raw);
+  StealthChannel sse_decode_stealth_channel(SseDeserializer deserializer);
 
   @protected
-  DataReader
-  dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDataReader(
-    dynamic raw,
-  );
+  StripOptions sse_decode_strip_options(SseDeserializer deserializer);
 
   @protected
-  DataReader
-  dco_decode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDataReader(
-    dynamic raw,
-  );
+  int sse_decode_u_32(SseDeserializer deserializer);
 
   @protected
-  Map<String, BigInt> dco_decode_Map_String_u_64_None(dynamic raw);
+  BigInt sse_decode_u_64(SseDeserializer deserializer);
 
   @protected
-  DataReader
-  dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDataReader(
-    dynamic raw,
-  );
+  int sse_decode_u_8(SseDeserializer deserializer);
 
   @protected
-  RustStreamSink<ScanProgress> dco_decode_StreamSink_scan_progress_Sse(
-    dynamic raw,
-  );
+  void sse_decode_unit(SseDeserializer deserializer);
 
   @protected
-  String dco_decode_String(dynamic raw);
+  XmpMetadata sse_decode_xmp_metadata(SseDeserializer deserializer);
 
   @protected
-  double dco_decode_box_autoadd_f_64(dynamic raw);
+  XmpProperty sse_decode_xmp_property(SseDeserializer deserializer);
 
   @protected
-  FolderScanResult dco_decode_box_autoadd_folder_scan_result(dynamic raw);
+  void sse_encode_AnyhowException(
+    AnyhowException self,
+    SseSerializer serializer,
+  );
 
   @protected
-  double dco_decode_f_64(dynamic raw);
+  void sse_encode_Map_String_u_64_None(
+    Map<String, BigInt> self,
+    SseSerializer serializer,
+  );
 
   @protected
-  FolderScanResult dco_decode_folder_scan_result(dynamic raw);
+  void sse_encode_StreamSink_scan_progress_Sse(
+    RustStreamSink<ScanProgress> self,
+    SseSerializer serializer,
+  );
 
   @protected
-  int dco_decode_i_32(dynamic raw);
+  void sse_encode_String(String self, SseSerializer serializer);
 
   @protected
-  ImageInfo dco_decode_image_info(dynamic raw);
+  void sse_encode_a1111_parameters(
+    A1111Parameters self,
+    SseSerializer serializer,
+  );
 
   @protected
-  ImageScanResult dco_decode_image_scan_result(dynamic raw);
+  void sse_encode_bool(bool self, SseSerializer serializer);
 
   @protected
-  List<ImageScanResult> dco_decode_list_image_scan_result(dynamic raw);
+  void sse_encode_box_autoadd_a1111_parameters(
+    A1111Parameters self,
+    SseSerializer serializer,
+  );
 
   @protected
-  List<int> dco_decode_list_prim_u_8_loose(dynamic raw);
+  void sse_encode_box_autoadd_bool(bool self, SseSerializer serializer);
 
   @protected
-  Uint8List dco_decode_list_prim_u_8_strict(dynamic raw);
+  void sse_encode_box_autoadd_comfy_ui_metadata(
+    ComfyUiMetadata self,
+    SseSerializer serializer,
+  );
 
   @protected
-  List<(String, BigInt)> dco_decode_list_record_string_u_64(dynamic raw);
+  void sse_encode_box_autoadd_comfy_ui_summary(
+    ComfyUiSummary self,
+    SseSerializer serializer,
+  );
 
   @protected
-  String? dco_decode_opt_String(dynamic raw);
+  void sse_encode_box_autoadd_f_64(double self, SseSerializer serializer);
 
   @protected
-  double? dco_decode_opt_box_autoadd_f_64(dynamic raw);
+  void sse_encode_box_autoadd_folder_scan_result(
+    FolderScanResult self,
+    SseSerializer serializer,
+  );
 
   @protected
-  FolderScanResult? dco_decode_opt_box_autoadd_folder_scan_result(dynamic raw);
+  void sse_encode_box_autoadd_invoke_ai_metadata(
+    InvokeAiMetadata self,
+    SseSerializer serializer,
+  );
 
   @protected
-  List<ImageScanResult>? dco_decode_opt_list_image_scan_result(dynamic raw);
+  void sse_encode_box_autoadd_metadata_error_kind(
+    MetadataErrorKind self,
+    SseSerializer serializer,
+  );
 
   @protected
-  (String, BigInt) dco_decode_record_string_u_64(dynamic raw);
+  void sse_encode_box_autoadd_model_file(
+    ModelFile self,
+    SseSerializer serializer,
+  );
 
   @protected
-  ScanProgress dco_decode_scan_progress(dynamic raw);
+  void sse_encode_box_autoadd_nai_metadata(
+    NaiMetadata self,
+    SseSerializer serializer,
+  );
 
   @protected
-  int dco_decode_u_32(dynamic raw);
+  void sse_encode_box_autoadd_nai_v4_prompt(
+    NaiV4Prompt self,
+    SseSerializer serializer,
+  );
 
   @protected
-  BigInt dco_decode_u_64(dynamic raw);
+  void sse_encode_box_autoadd_strip_options(
+    StripOptions self,
+    SseSerializer serializer,
+  );
 
   @protected
-  int dco_decode_u_8(dynamic raw);
+  void sse_encode_box_autoadd_u_32(int self, SseSerializer serializer);
 
   @protected
-  void dco_decode_unit(dynamic raw);
+  void sse_encode_box_autoadd_u_64(BigInt self, SseSerializer serializer);
 
   @protected
-  BigInt dco_decode_usize(dynamic raw);
+  void sse_encode_box_autoadd_xmp_metadata(
+    XmpMetadata self,
+    SseSerializer serializer,
+  );
 
   @protected
-  AnyhowException sse_decode_AnyhowException(SseDeserializer deserializer);
+  void sse_encode_c2pa_action(C2paAction self, SseSerializer serializer);
 
   @protected
-  DataReader
-  sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDataReader(
-    SseDeserializer deserializer,
+  void sse_encode_c2pa_ingredient(
+    C2paIngredient self,
+    SseSerializer serializer,
   );
 
   @protected
-  DataReader
-  sse_decode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDataReader(
-    SseDeserializer deserializer,
-  );
+  void sse_encode_c2pa_manifest(C2paManifest self, SseSerializer serializer);
 
   @protected
-  Map<String, BigInt> sse_decode_Map_String_u_64_None(
-    SseDeserializer deserializer,
+  void sse_encode_comfy_ui_lora(ComfyUiLora self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_comfy_ui_metadata(
+    ComfyUiMetadata self,
+    SseSerializer serializer,
   );
 
   @protected
-  DataReader
-  sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDataReader(
-    SseDeserializer deserializer,
+  void sse_encode_comfy_ui_summary(
+    ComfyUiSummary self,
+    SseSerializer serializer,
   );
 
   @protected
-  RustStreamSink<ScanProgress> sse_decode_StreamSink_scan_progress_Sse(
-    SseDeserializer deserializer,
+  void sse_encode_f_64(double self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_folder_scan_result(
+    FolderScanResult self,
+    SseSerializer serializer,
   );
 
   @protected
-  String sse_decode_String(SseDeserializer deserializer);
+  void sse_encode_generator(Generator self, SseSerializer serializer);
 
   @protected
-  double sse_decode_box_autoadd_f_64(SseDeserializer deserializer);
+  void sse_encode_i_32(int self, SseSerializer serializer);
 
   @protected
-  FolderScanResult sse_decode_box_autoadd_folder_scan_result(
-    SseDeserializer deserializer,
+  void sse_encode_image_info(ImageInfo self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_image_scan_result(
+    ImageScanResult self,
+    SseSerializer serializer,
   );
 
   @protected
-  double sse_decode_f_64(SseDeserializer deserializer);
+  void sse_encode_invoke_ai_control_layer(
+    InvokeAiControlLayer self,
+    SseSerializer serializer,
+  );
 
   @protected
-  FolderScanResult sse_decode_folder_scan_result(SseDeserializer deserializer);
+  void sse_encode_invoke_ai_lora(InvokeAiLora self, SseSerializer serializer);
 
   @protected
-  int sse_decode_i_32(SseDeserializer deserializer);
+  void sse_encode_invoke_ai_metadata(
+    InvokeAiMetadata self,
+    SseSerializer serializer,
+  );
 
   @protected
-  ImageInfo sse_decode_image_info(SseDeserializer deserializer);
+  void sse_encode_invoke_ai_regional_prompt(
+    InvokeAiRegionalPrompt self,
+    SseSerializer serializer,
+  );
 
   @protected
-  ImageScanResult sse_decode_image_scan_result(SseDeserializer deserializer);
+  void sse_encode_list_String(List<String> self, SseSerializer serializer);
 
   @protected
-  List<ImageScanResult> sse_decode_list_image_scan_result(
-    SseDeserializer deserializer,
+  void sse_encode_list_c2pa_action(
+    List<C2paAction> self,
+    SseSerializer serializer,
   );
 
   @protected
-  List<int> sse_decode_list_prim_u_8_loose(SseDeserializer deserializer);
+  void sse_encode_list_c2pa_ingredient(
+    List<C2paIngredient> self,
+    SseSerializer serializer,
+  );
 
   @protected
-  Uint8List sse_decode_list_prim_u_8_strict(SseDeserializer deserializer);
+  void sse_encode_list_c2pa_manifest(
+    List<C2paManifest> self,
+    SseSerializer serializer,
+  );
 
   @protected
-  List<(String, BigInt)> sse_decode_list_record_string_u_64(
-    SseDeserializer deserializer,
+  void sse_encode_list_comfy_ui_lora(
+    List<ComfyUiLora> self,
+    SseSerializer serializer,
   );
 
   @protected
-  String? sse_decode_opt_String(SseDeserializer deserializer);
+  void sse_encode_list_image_scan_result(
+    List<ImageScanResult> self,
+    SseSerializer serializer,
+  );
 
   @protected
-  double? sse_decode_opt_box_autoadd_f_64(SseDeserializer deserializer);
+  void sse_encode_list_invoke_ai_control_layer(
+    List<InvokeAiControlLayer> self,
+    SseSerializer serializer,
+  );
 
   @protected
-  FolderScanResult? sse_decode_opt_box_autoadd_folder_scan_result(
-    SseDeserializer deserializer,
+  void sse_encode_list_invoke_ai_lora(
+    List<InvokeAiLora> self,
+    SseSerializer serializer,
   );
 
   @protected
-  List<ImageScanResult>? sse_decode_opt_list_image_scan_result(
-    SseDeserializer deserializer,
+  void sse_encode_list_invoke_ai_regional_prompt(
+    List<InvokeAiRegionalPrompt> self,
+    SseSerializer serializer,
   );
 
   @protected
-  (String, BigInt) sse_decode_record_string_u_64(SseDeserializer deserializer);
+  void sse_encode_list_model_file(
+    List<ModelFile> self,
+    SseSerializer serializer,
+  );
 
   @protected
-  ScanProgress sse_decode_scan_progress(SseDeserializer deserializer);
+  void sse_encode_list_nai_char_caption(
+    List<NaiCharCaption> self,
+    SseSerializer serializer,
+  );
 
   @protected
-  int sse_decode_u_32(SseDeserializer deserializer);
+  void sse_encode_list_nai_coordinate(
+    List<NaiCoordinate> self,
+    SseSerializer serializer,
+  );
 
   @protected
-  BigInt sse_decode_u_64(SseDeserializer deserializer);
+  void sse_encode_list_network_reference(
+    List<NetworkReference> self,
+    SseSerializer serializer,
+  );
 
   @protected
-  int sse_decode_u_8(SseDeserializer deserializer);
+  void sse_encode_list_png_text_chunk(
+    List<PngTextChunk> self,
+    SseSerializer serializer,
+  );
 
   @protected
-  void sse_decode_unit(SseDeserializer deserializer);
+  void sse_encode_list_prim_f_64_strict(
+    List<double> self,
+    SseSerializer serializer,
+  );
 
   @protected
-  BigInt sse_decode_usize(SseDeserializer deserializer);
+  void sse_encode_list_prim_u_8_loose(List<int> self, SseSerializer serializer);
 
   @protected
-  bool sse_decode_bool(SseDeserializer deserializer);
+  void sse_encode_list_prim_u_8_strict(
+    Uint8List self,
+    SseSerializer serializer,
+  );
 
   @protected
-  void sse_encode_AnyhowException(
-    AnyhowException self,
+  void sse_encode_list_prompt_tag(
+    List<PromptTag> self,
     SseSerializer serializer,
   );
 
   @protected
-  void
-  sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDataReader(
-    DataReader self,
+  void sse_encode_list_record_string_string(
+    List<(String, String)> self,
     SseSerializer serializer,
   );
 
   @protected
-  void
-  sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDataReader(
-    DataReader self,
+  void sse_encode_list_record_string_u_64(
+    List<(String, BigInt)> self,
     SseSerializer serializer,
   );
 
   @protected
-  void sse_encode_Map_String_u_64_None(
-    Map<String, BigInt> self,
+  void sse_encode_list_xmp_property(
+    List<XmpProperty> self,
     SseSerializer serializer,
   );
 
   @protected
-  void
-  sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDataReader(
-    DataReader self,
+  void sse_encode_metadata_error_kind(
+    MetadataErrorKind self,
     SseSerializer serializer,
   );
 
   @protected
-  void sse_encode_StreamSink_scan_progress_Sse(
-    RustStreamSink<ScanProgress> self,
+  void sse_encode_model_file(ModelFile self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_nai_char_caption(
+    NaiCharCaption self,
     SseSerializer serializer,
   );
 
   @protected
-  void sse_encode_String(String self, SseSerializer serializer);
+  void sse_encode_nai_coordinate(NaiCoordinate self, SseSerializer serializer);
 
   @protected
-  void sse_encode_box_autoadd_f_64(double self, SseSerializer serializer);
+  void sse_encode_nai_metadata(NaiMetadata self, SseSerializer serializer);
 
   @protected
-  void sse_encode_box_autoadd_folder_scan_result(
-    FolderScanResult self,
+  void sse_encode_nai_signature_status(
+    NaiSignatureStatus self,
     SseSerializer serializer,
   );
 
   @protected
-  void sse_encode_f_64(double self, SseSerializer serializer);
+  void sse_encode_nai_v4_prompt(NaiV4Prompt self, SseSerializer serializer);
 
   @protected
-  void sse_encode_folder_scan_result(
-    FolderScanResult self,
+  void sse_encode_network_kind(NetworkKind self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_network_reference(
+    NetworkReference self,
     SseSerializer serializer,
   );
 
   @protected
-  void sse_encode_i_32(int self, SseSerializer serializer);
+  void sse_encode_opt_String(String? self, SseSerializer serializer);
 
   @protected
-  void sse_encode_image_info(ImageInfo self, SseSerializer serializer);
+  void sse_encode_opt_box_autoadd_a1111_parameters(
+    A1111Parameters? self,
+    SseSerializer serializer,
+  );
 
   @protected
-  void sse_encode_image_scan_result(
-    ImageScanResult self,
+  void sse_encode_opt_box_autoadd_bool(bool? self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_opt_box_autoadd_comfy_ui_metadata(
+    ComfyUiMetadata? self,
     SseSerializer serializer,
   );
 
   @protected
-  void sse_encode_list_image_scan_result(
-    List<ImageScanResult> self,
+  void sse_encode_opt_box_autoadd_comfy_ui_summary(
+    ComfyUiSummary? self,
     SseSerializer serializer,
   );
 
   @protected
-  void sse_encode_list_prim_u_8_loose(List<int> self, SseSerializer serializer);
+  void sse_encode_opt_box_autoadd_f_64(double? self, SseSerializer serializer);
 
   @protected
-  void sse_encode_list_prim_u_8_strict(
-    Uint8List self,
+  void sse_encode_opt_box_autoadd_folder_scan_result(
+    FolderScanResult? self,
     SseSerializer serializer,
   );
 
   @protected
-  void sse_encode_list_record_string_u_64(
-    List<(String, BigInt)> self,
+  void sse_encode_opt_box_autoadd_invoke_ai_metadata(
+    InvokeAiMetadata? self,
     SseSerializer serializer,
   );
 
   @protected
-  void sse_encode_opt_String(String? self, SseSerializer serializer);
+  void sse_encode_opt_box_autoadd_metadata_error_kind(
+    MetadataErrorKind? self,
+    SseSerializer serializer,
+  );
 
   @protected
-  void sse_encode_opt_box_autoadd_f_64(double? self, SseSerializer serializer);
+  void sse_encode_opt_box_autoadd_model_file(
+    ModelFile? self,
+    SseSerializer serializer,
+  );
 
   @protected
-  void sse_encode_opt_box_autoadd_folder_scan_result(
-    FolderScanResult? self,
+  void sse_encode_opt_box_autoadd_nai_metadata(
+    NaiMetadata? self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_opt_box_autoadd_nai_v4_prompt(
+    NaiV4Prompt? self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_opt_box_autoadd_u_32(int? self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_opt_box_autoadd_u_64(BigInt? self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_opt_box_autoadd_xmp_metadata(
+    XmpMetadata? self,
     SseSerializer serializer,
   );
 
@@ -348,6 +1135,30 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     SseSerializer serializer,
   );
 
+  @protected
+  void sse_encode_png_text_chunk(PngTextChunk self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_png_text_chunk_type(
+    PngTextChunkType self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_prompt_dialect(PromptDialect self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_prompt_tag(PromptTag self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_prompt_tag_kind(PromptTagKind self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_record_string_string(
+    (String, String) self,
+    SseSerializer serializer,
+  );
+
   @protected
   void sse_encode_record_string_u_64(
     (String, BigInt) self,
@@ -357,6 +1168,15 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   void sse_encode_scan_progress(ScanProgress self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_stealth_channel(
+    StealthChannel self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_strip_options(StripOptions self, SseSerializer serializer);
+
   @protected
   void sse_encode_u_32(int self, SseSerializer serializer);
 
@@ -370,32 +1190,16 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   void sse_encode_unit(void self, SseSerializer serializer);
 
   @protected
-  void sse_encode_usize(BigInt self, SseSerializer serializer);
+  void sse_encode_xmp_metadata(XmpMetadata self, SseSerializer serializer);
 
   @protected
-  void sse_encode_bool(bool self, SseSerializer serializer);
+  void sse_encode_xmp_property(XmpProperty self, SseSerializer serializer);
 }
 
 // Section: wire_class
 
 class RustLibWire implements BaseWire {
   RustLibWire.fromExternalLibrary(ExternalLibrary lib);
-
-  void
-  rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDataReader(
-    int ptr,
-  ) => wasmModule
-      .rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDataReader(
-        ptr,
-      );
-
-  void
-  rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDataReader(
-    int ptr,
-  ) => wasmModule
-      .rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDataReader(
-        ptr,
-      );
 }
 
 @JS('wasm_bindgen')
@@ -404,13 +1208,4 @@ external RustLibWasmModule get wasmModule;
 @JS()
 @anonymous
 extension type RustLibWasmModule._(JSObject _) implements JSObject {
-  external void
-  rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDataReader(
-    int ptr,
-  );
-
-  external void
-  rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDataReader(
-    int ptr,
-  );
 }
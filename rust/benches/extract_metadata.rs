@@ -0,0 +1,115 @@
+//! 对比单次解码与完整元数据提取的耗时
+//!
+//! PNG 的 `extract_metadata` 应当只比一次 `image::load_from_memory` 多出少量开销，
+//! 如果接近两倍说明像素又被重复解码了；JPEG 只读取文件头，应当远快于解码。
+//!
+//! 运行: `cargo bench --bench extract_metadata`
+
+use std::hint::black_box;
+use std::io::Write;
+
+use criterion::{criterion_group, criterion_main, Criterion};
+use flate2::write::GzEncoder;
+use flate2::Compression;
+use rust_lib_metadata_gallery::api::metadata::extract_metadata;
+
+const WIDTH: u32 = 832;
+const HEIGHT: u32 = 1216;
+
+const NAI_JSON: &str = r#"{"Title":"NovelAI generated image","Software":"NovelAI","Comment":"{\"prompt\":\"1girl, solo\",\"steps\":28,\"seed\":1234,\"sampler\":\"k_euler_ancestral\",\"scale\":5.0,\"uc\":\"lowres\"}"}"#;
+
+const A1111_PARAMETERS: &str = "1girl, solo\nNegative prompt: lowres\nSteps: 28, Sampler: Euler a, CFG scale: 7, Seed: 1234, Size: 832x1216";
+
+// 生成带噪声的像素，避免 PNG 压缩得过小而失去代表性
+fn noise_pixels(channels: usize) -> Vec<u8> {
+    let mut state = 0x2545_f491_u32;
+    (0..WIDTH as usize * HEIGHT as usize * channels)
+        .map(|_| {
+            state ^= state << 13;
+            state ^= state >> 17;
+            state ^= state << 5;
+            state as u8
+        })
+        .collect()
+}
+
+fn encode_png(pixels: &[u8], color: png::ColorType, text: Option<(&str, &str)>) -> Vec<u8> {
+    let mut output = vec![];
+    let mut encoder = png::Encoder::new(&mut output, WIDTH, HEIGHT);
+    encoder.set_color(color);
+    if let Some((keyword, text)) = text {
+        encoder
+            .add_text_chunk(keyword.to_string(), text.to_string())
+            .unwrap();
+    }
+    let mut writer = encoder.write_header().unwrap();
+    writer.write_image_data(pixels).unwrap();
+    writer.finish().unwrap();
+    output
+}
+
+// 按列优先顺序把 stealth_pngcomp 数据写入 alpha 最低位
+fn stealth_png() -> Vec<u8> {
+    let mut gz = GzEncoder::new(vec![], Compression::default());
+    gz.write_all(NAI_JSON.as_bytes()).unwrap();
+    let payload = gz.finish().unwrap();
+
+    let mut bytes = b"stealth_pngcomp".to_vec();
+    bytes.extend_from_slice(&((payload.len() * 8) as i32).to_be_bytes());
+    bytes.extend_from_slice(&payload);
+    let bits = bytes
+        .iter()
+        .flat_map(|byte| (0..8).rev().map(move |i| (byte >> i) & 1));
+
+    let mut pixels = noise_pixels(4);
+    let positions = (0..WIDTH).flat_map(|x| (0..HEIGHT).map(move |y| (x, y)));
+    for ((x, y), bit) in positions.zip(bits) {
+        let alpha = ((y * WIDTH + x) * 4 + 3) as usize;
+        pixels[alpha] = (pixels[alpha] & !1) | bit;
+    }
+    encode_png(&pixels, png::ColorType::Rgba, None)
+}
+
+fn parameters_png() -> Vec<u8> {
+    encode_png(
+        &noise_pixels(3),
+        png::ColorType::Rgb,
+        Some(("parameters", A1111_PARAMETERS)),
+    )
+}
+
+fn photo_jpeg() -> Vec<u8> {
+    let mut output = vec![];
+    image::codecs::jpeg::JpegEncoder::new_with_quality(&mut output, 90)
+        .encode(
+            &noise_pixels(3),
+            WIDTH,
+            HEIGHT,
+            image::ExtendedColorType::Rgb8,
+        )
+        .unwrap();
+    output
+}
+
+fn bench_extract_metadata(c: &mut Criterion) {
+    let images = [
+        ("stealth_png", stealth_png()),
+        ("parameters_png", parameters_png()),
+        ("photo_jpeg", photo_jpeg()),
+    ];
+
+    let mut group = c.benchmark_group("extract_metadata");
+    for (name, bytes) in &images {
+        extract_metadata(bytes).unwrap();
+        group.bench_function(format!("decode_only/{name}"), |b| {
+            b.iter(|| image::load_from_memory(black_box(bytes)).unwrap())
+        });
+        group.bench_function(format!("extract/{name}"), |b| {
+            b.iter(|| extract_metadata(black_box(bytes)).unwrap())
+        });
+    }
+    group.finish();
+}
+
+criterion_group!(benches, bench_extract_metadata);
+criterion_main!(benches);
@@ -0,0 +1,243 @@
+/// AUTOMATIC1111 / Forge 的 `parameters` 文本解析结果
+#[derive(Debug, Clone, PartialEq)]
+pub struct A1111Parameters {
+    pub prompt: String,
+    pub negative_prompt: Option<String>,
+    pub steps: Option<u32>,
+    pub sampler: Option<String>,
+    pub schedule_type: Option<String>,
+    pub cfg_scale: Option<f64>,
+    pub seed: Option<u64>,
+    pub width: Option<u32>,
+    pub height: Option<u32>,
+    pub model: Option<String>,
+    pub model_hash: Option<String>,
+    pub denoising_strength: Option<f64>,
+    /// 参数行及其后附加行的全部键值对，按原始顺序保存，引号已去除
+    pub settings: Vec<(String, String)>,
+}
+
+const NEGATIVE_PROMPT_PREFIX: &str = "Negative prompt:";
+
+/// 解析 `prompt\nNegative prompt: ...\nSteps: 28, Sampler: ...` 格式的文本
+///
+/// 参数行是最后一个包含至少三个键值对的行。Dynamic Prompts 等扩展会在参数行之后追加
+/// `Template: ...` 这样的行，这些行整行作为一个键值对加入 `settings`。
+/// 没有可识别的参数行时返回 `None`，避免把普通描述文本误判为 A1111 参数
+pub(crate) fn parse_a1111_parameters(text: &str) -> Option<A1111Parameters> {
+    let lines: Vec<&str> = text.trim().lines().collect();
+    let (index, mut settings) = lines
+        .iter()
+        .enumerate()
+        .rev()
+        .find_map(|(index, line)| Some((index, parse_settings_line(line)?)))?;
+    settings.extend(lines[index + 1..].iter().filter_map(|line| {
+        let (key, value) = line.split_once(':')?;
+        (!key.is_empty() && key.chars().all(is_key_char))
+            .then(|| (key.trim().to_string(), value.trim().to_string()))
+    }));
+
+    // 正向提示词在前，`Negative prompt:` 之后的行都属于反向提示词
+    let mut prompt_lines = vec![];
+    let mut negative_lines: Option<Vec<&str>> = None;
+    for &line in &lines[..index] {
+        match negative_lines.as_mut() {
+            Some(lines) => lines.push(line),
+            None => match line.strip_prefix(NEGATIVE_PROMPT_PREFIX) {
+                Some(rest) => negative_lines = Some(vec![rest.trim_start()]),
+                None => prompt_lines.push(line),
+            },
+        }
+    }
+
+    let get = |key: &str| {
+        settings
+            .iter()
+            .find(|(name, _)| name == key)
+            .map(|(_, value)| value.as_str())
+    };
+    let (width, height) = get("Size")
+        .and_then(|size| size.split_once('x'))
+        .map(|(w, h)| (w.trim().parse().ok(), h.trim().parse().ok()))
+        .unwrap_or((None, None));
+
+    Some(A1111Parameters {
+        prompt: prompt_lines.join("\n").trim().to_string(),
+        negative_prompt: negative_lines.map(|lines| lines.join("\n").trim().to_string()),
+        steps: get("Steps").and_then(|value| value.parse().ok()),
+        sampler: get("Sampler").map(str::to_string),
+        schedule_type: get("Schedule type").map(str::to_string),
+        cfg_scale: get("CFG scale").and_then(|value| value.parse().ok()),
+        seed: get("Seed").and_then(|value| value.parse().ok()),
+        width,
+        height,
+        model: get("Model").map(str::to_string),
+        model_hash: get("Model hash").map(str::to_string),
+        denoising_strength: get("Denoising strength").and_then(|value| value.parse().ok()),
+        settings,
+    })
+}
+
+/// 解析 `Key: value, Key: "quoted, value", ...`，至少需要三个键值对才视为参数行
+fn parse_settings_line(line: &str) -> Option<Vec<(String, String)>> {
+    let chars: Vec<char> = line.chars().collect();
+    let mut pairs = vec![];
+    let mut i = 0;
+
+    while i < chars.len() {
+        while i < chars.len() && chars[i].is_whitespace() {
+            i += 1;
+        }
+        if i == chars.len() {
+            break;
+        }
+
+        // 键: 字母数字、空格、`-` 和 `/`，不符合的片段直接跳到下一个逗号
+        let key_start = i;
+        while i < chars.len() && is_key_char(chars[i]) {
+            i += 1;
+        }
+        if i == chars.len() || i == key_start || chars[i] != ':' {
+            while i < chars.len() && chars[i] != ',' {
+                i += 1;
+            }
+            i += 1;
+            continue;
+        }
+        let key: String = chars[key_start..i]
+            .iter()
+            .collect::<String>()
+            .trim()
+            .to_string();
+        i += 1;
+        while i < chars.len() && chars[i] == ' ' {
+            i += 1;
+        }
+
+        // 值: 引号内的值可以包含逗号和转义字符。引号没有闭合或结束引号之后还有其他内容时，
+        // 与 A1111 的正则表达式一样按普通的值读取到下一个逗号
+        let quoted = (chars.get(i) == Some(&'"'))
+            .then(|| read_quoted(&chars, i))
+            .flatten()
+            .filter(|(_, next)| {
+                chars[*next..]
+                    .iter()
+                    .take_while(|c| **c != ',')
+                    .all(|c| c.is_whitespace())
+            });
+        let value = if let Some((value, next)) = quoted {
+            i = next;
+            while i < chars.len() && chars[i] != ',' {
+                i += 1;
+            }
+            value
+        } else {
+            let value_start = i;
+            while i < chars.len() && chars[i] != ',' {
+                i += 1;
+            }
+            chars[value_start..i]
+                .iter()
+                .collect::<String>()
+                .trim()
+                .to_string()
+        };
+        pairs.push((key, value));
+        // 跳过分隔符
+        i += 1;
+    }
+
+    if pairs.len() < 3 {
+        return None;
+    }
+    Some(pairs)
+}
+
+fn is_key_char(c: char) -> bool {
+    c.is_alphanumeric() || c == '_' || c == ' ' || c == '-' || c == '/'
+}
+
+// 返回去除引号和转义后的值，以及结束引号之后的位置
+fn read_quoted(chars: &[char], start: usize) -> Option<(String, usize)> {
+    let mut value = String::new();
+    let mut i = start + 1;
+    while i < chars.len() {
+        match chars[i] {
+            '\\' if i + 1 < chars.len() => {
+                value.push(match chars[i + 1] {
+                    'n' => '\n',
+                    't' => '\t',
+                    other => other,
+                });
+                i += 2;
+            }
+            '"' => return Some((value, i + 1)),
+            c => {
+                value.push(c);
+                i += 1;
+            }
+        }
+    }
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SETTINGS: &str = "Steps: 28, Sampler: Euler a, CFG scale: 7";
+
+    #[test]
+    fn quoted_values_keep_commas() {
+        let text = format!("1girl\n{SETTINGS}, Lora hashes: \"a: 1, b: 2\", Seed: 5");
+        let parameters = parse_a1111_parameters(&text).unwrap();
+        assert!(parameters
+            .settings
+            .contains(&("Lora hashes".to_string(), "a: 1, b: 2".to_string())));
+        assert_eq!(parameters.seed, Some(5));
+    }
+
+    #[test]
+    fn text_after_closing_quote_is_kept_as_plain_value() {
+        let text = format!("1girl\n{SETTINGS}, Note: \"a\" b, Seed: 5");
+        let parameters = parse_a1111_parameters(&text).unwrap();
+        assert!(parameters
+            .settings
+            .contains(&("Note".to_string(), "\"a\" b".to_string())));
+        assert_eq!(parameters.seed, Some(5));
+    }
+
+    #[test]
+    fn lines_after_settings_are_kept_as_extra_settings() {
+        let text = format!(
+            "a {{red|blue}} cat\nNegative prompt: lowres\n{SETTINGS}, Seed: 5\n\
+             Template: a {{red|blue}} cat, masterpiece\nNegative Template: lowres"
+        );
+        let parameters = parse_a1111_parameters(&text).unwrap();
+        assert_eq!(parameters.prompt, "a {red|blue} cat");
+        assert_eq!(parameters.negative_prompt.as_deref(), Some("lowres"));
+        assert_eq!(parameters.seed, Some(5));
+        assert!(parameters.settings.contains(&(
+            "Template".to_string(),
+            "a {red|blue} cat, masterpiece".to_string()
+        )));
+        assert!(parameters
+            .settings
+            .contains(&("Negative Template".to_string(), "lowres".to_string())));
+    }
+
+    #[test]
+    fn text_without_settings_line_is_not_a1111() {
+        assert_eq!(parse_a1111_parameters("a cat\nsitting, on a mat"), None);
+    }
+
+    #[test]
+    fn unterminated_quote_is_kept_as_plain_value() {
+        let text = format!("1girl\n{SETTINGS}, Note: \"a, Seed: 5");
+        let parameters = parse_a1111_parameters(&text).unwrap();
+        assert!(parameters
+            .settings
+            .contains(&("Note".to_string(), "\"a".to_string())));
+        assert_eq!(parameters.seed, Some(5));
+    }
+}
@@ -0,0 +1,619 @@
+use ciborium::Value as CborValue;
+use serde_json::Value;
+
+use crate::api::metadata::{png_chunks, webp_chunks};
+use crate::api::writer::{jpeg_segments, JpegSegment};
+
+/// 清单存储中的一个 C2PA 清单 (内容凭据)
+#[derive(Debug, Clone, PartialEq)]
+pub struct C2paManifest {
+    /// 清单的 JUMBF 标签，例如 `urn:uuid:...`
+    pub label: String,
+    /// 写入清单的工具，优先使用 `claim_generator_info` 中的名称和版本，例如 `DALL·E`
+    pub claim_generator: Option<String>,
+    /// `dc:title`
+    pub title: Option<String>,
+    /// `dc:format`
+    pub format: Option<String>,
+    pub actions: Vec<C2paAction>,
+    pub ingredients: Vec<C2paIngredient>,
+    /// 全部断言的标签，例如 `c2pa.actions.v2`、`c2pa.hash.data`
+    pub assertion_labels: Vec<String>,
+}
+
+/// `c2pa.actions` 断言中的一个操作
+#[derive(Debug, Clone, PartialEq)]
+pub struct C2paAction {
+    /// 例如 `c2pa.created`、`c2pa.edited`
+    pub action: String,
+    pub software_agent: Option<String>,
+    /// IPTC 数字来源类型，AI 生成的图片为 `http://cv.iptc.org/newscodes/digitalsourcetype/trainedAlgorithmicMedia`
+    pub digital_source_type: Option<String>,
+    pub when: Option<String>,
+}
+
+/// `c2pa.ingredient` 断言，记录编辑时使用的原始素材
+#[derive(Debug, Clone, PartialEq)]
+pub struct C2paIngredient {
+    pub title: Option<String>,
+    pub format: Option<String>,
+    /// `parentOf` / `componentOf` / `inputTo`
+    pub relationship: Option<String>,
+}
+
+/// JPEG APP11，C2PA 的 JUMBF 数据保存在这里
+pub(crate) const JPEG_APP11: u8 = 0xEB;
+
+const MANIFEST_STORE_LABEL: &str = "c2pa";
+const ASSERTION_STORE_LABEL: &str = "c2pa.assertions";
+// 断言中的 CBOR 嵌套层数上限，正常的清单不超过十层
+const MAX_CBOR_DEPTH: usize = 64;
+
+/// 读取 PNG `caBX` 块、JPEG APP11 段或 WebP `C2PA` 块中的清单存储
+///
+/// 只解析清单内容，不验证签名和哈希。按存储中的顺序返回，最后一个是当前图片的清单
+pub(crate) fn read_c2pa_manifests(input_bytes: &[u8]) -> Vec<C2paManifest> {
+    let jumbf = match infer::get(input_bytes).map(|kind| kind.mime_type()) {
+        Some("image/png") => png_chunks(input_bytes)
+            .find(|(chunk_type, _)| chunk_type == b"caBX")
+            .map(|(_, data)| data.to_vec()),
+        Some("image/jpeg") => jpeg_jumbf(input_bytes),
+        Some("image/webp") => webp_chunks(input_bytes)
+            .find(|(fourcc, _)| fourcc == b"C2PA")
+            .map(|(_, data)| data.to_vec()),
+        _ => None,
+    };
+    let Some(store) = jumbf.as_deref().and_then(manifest_store) else {
+        return vec![];
+    };
+    child_superboxes(&store)
+        .iter()
+        .map(parse_manifest)
+        .collect()
+}
+
+/// 是否有 PNG `caBX` 块、JPEG APP11 段或 WebP `C2PA` 块，不解析清单内容
+pub(crate) fn has_c2pa_manifest(input_bytes: &[u8]) -> bool {
+    match infer::get(input_bytes).map(|kind| kind.mime_type()) {
+        Some("image/png") => png_chunks(input_bytes).any(|(chunk_type, _)| &chunk_type == b"caBX"),
+        Some("image/jpeg") => jpeg_segments(input_bytes)
+            .is_ok_and(|segments| !jpeg_c2pa_instances(&segments).is_empty()),
+        Some("image/webp") => webp_chunks(input_bytes).any(|(fourcc, _)| &fourcc == b"C2PA"),
+        _ => false,
+    }
+}
+
+/// 保存 C2PA 清单存储的 JUMBF 实例编号，任一分段中出现 `c2pa` 标签的实例都算在内，
+/// 因此清单无法解析时也能找到
+pub(crate) fn jpeg_c2pa_instances(segments: &[JpegSegment]) -> Vec<u16> {
+    let mut instances: Vec<u16> = segments
+        .iter()
+        .filter(|(marker, data, _)| {
+            *marker == JPEG_APP11 && data.windows(4).any(|window| window == b"c2pa")
+        })
+        .filter_map(|(_, data, _)| jumbf_instance(data))
+        .collect();
+    instances.sort_unstable();
+    instances.dedup();
+    instances
+}
+
+/// APP11 段中 JUMBF 的盒子实例编号 En，不是 JUMBF 的段返回 `None`
+pub(crate) fn jumbf_instance(data: &[u8]) -> Option<u16> {
+    let rest = data.strip_prefix(b"JP")?;
+    Some(u16::from_be_bytes(rest.get(0..2)?.try_into().ok()?))
+}
+
+/// 当前清单的文本摘要，图片没有其他元数据时用于搜索
+pub(crate) fn describe_c2pa_manifests(manifests: &[C2paManifest]) -> Option<String> {
+    let manifest = manifests.last()?;
+    let mut lines = vec![];
+    if let Some(claim_generator) = &manifest.claim_generator {
+        lines.push(format!("Claim generator: {claim_generator}"));
+    }
+    if let Some(title) = &manifest.title {
+        lines.push(format!("Title: {title}"));
+    }
+    for action in &manifest.actions {
+        let details: Vec<&str> = [&action.software_agent, &action.digital_source_type]
+            .into_iter()
+            .flatten()
+            .map(String::as_str)
+            .collect();
+        if details.is_empty() {
+            lines.push(format!("Action: {}", action.action));
+        } else {
+            lines.push(format!(
+                "Action: {} ({})",
+                action.action,
+                details.join(", ")
+            ));
+        }
+    }
+    for ingredient in &manifest.ingredients {
+        let title = ingredient.title.as_deref().unwrap_or_default();
+        match &ingredient.relationship {
+            Some(relationship) => lines.push(format!("Ingredient: {title} ({relationship})")),
+            None => lines.push(format!("Ingredient: {title}")),
+        }
+    }
+    if !manifest.assertion_labels.is_empty() {
+        lines.push(format!(
+            "Assertions: {}",
+            manifest.assertion_labels.join(", ")
+        ));
+    }
+    Some(lines.join("\n"))
+}
+
+// JPEG 中的 JUMBF 按 64 KiB 分段保存在多个 APP11 段中:
+// `JP` + 盒子实例编号 En (u16) + 分段序号 Z (u32) + 数据，同一实例的分段按序号拼接
+fn jpeg_jumbf(input_bytes: &[u8]) -> Option<Vec<u8>> {
+    let segments = jpeg_segments(input_bytes).ok()?;
+    let mut packets: Vec<(u16, u32, &[u8])> = segments
+        .iter()
+        .filter(|(marker, _, _)| *marker == JPEG_APP11)
+        .filter_map(|(_, data, _)| {
+            let instance = jumbf_instance(data)?;
+            let rest = &data[2..];
+            let sequence = u32::from_be_bytes(rest.get(2..6)?.try_into().ok()?);
+            Some((instance, sequence, rest.get(6..)?))
+        })
+        .collect();
+    packets.sort_by_key(|(instance, sequence, _)| (*instance, *sequence));
+
+    // 同一文件中可能还有其他用途的 JUMBF，只使用标签为 `c2pa` 的实例
+    packets.chunk_by(|a, b| a.0 == b.0).find_map(|packets| {
+        let mut jumbf = packets[0].2.to_vec();
+        // 后续分段重复了盒子头 (LBox + TBox，LBox 为 1 时还有 8 字节的 XLBox)
+        for (_, _, payload) in &packets[1..] {
+            let header_length = match payload.get(0..4) {
+                Some([0, 0, 0, 1]) => 16,
+                _ => 8,
+            };
+            jumbf.extend_from_slice(payload.get(header_length..)?);
+        }
+        manifest_store(&jumbf).is_some().then_some(jumbf)
+    })
+}
+
+// JUMBF 超级盒 (`jumb`)，描述盒 (`jumd`) 中的标签和其余的子盒子
+struct Superbox<'a> {
+    label: Option<String>,
+    boxes: Vec<([u8; 4], &'a [u8])>,
+}
+
+fn manifest_store(jumbf: &[u8]) -> Option<Superbox<'_>> {
+    read_boxes(jumbf)
+        .into_iter()
+        .find(|(box_type, _)| box_type == b"jumb")
+        .and_then(|(_, content)| read_superbox(content))
+        .filter(|store| store.label.as_deref() == Some(MANIFEST_STORE_LABEL))
+}
+
+// ISO BMFF 风格的盒子: LBox (u32) + TBox，LBox 为 1 时后跟 u64 的 XLBox，为 0 时延伸到末尾
+fn read_boxes(data: &[u8]) -> Vec<([u8; 4], &[u8])> {
+    let mut boxes = vec![];
+    let mut offset = 0;
+    while let Some(header) = data.get(offset..offset + 8) {
+        let length = u32::from_be_bytes([header[0], header[1], header[2], header[3]]) as u64;
+        let box_type = [header[4], header[5], header[6], header[7]];
+        let (header_length, box_length) = match length {
+            0 => (8, (data.len() - offset) as u64),
+            1 => match data.get(offset + 8..offset + 16) {
+                Some(extended) => (
+                    16,
+                    u64::from_be_bytes(extended.try_into().unwrap_or_default()),
+                ),
+                None => break,
+            },
+            length => (8, length),
+        };
+        let end = usize::try_from(box_length)
+            .ok()
+            .filter(|length| *length >= header_length)
+            .and_then(|length| offset.checked_add(length))
+            .filter(|end| *end <= data.len());
+        let Some(end) = end else {
+            break;
+        };
+        boxes.push((box_type, &data[offset + header_length..end]));
+        offset = end;
+    }
+    boxes
+}
+
+fn read_superbox(content: &[u8]) -> Option<Superbox<'_>> {
+    let mut boxes = read_boxes(content);
+    if boxes.first()?.0 != *b"jumd" {
+        return None;
+    }
+    let (_, description) = boxes.remove(0);
+    // 16 字节的类型 UUID 和 1 字节的开关，开关的第 2 位表示后面有以 NUL 结尾的标签
+    let toggles = *description.get(16)?;
+    let label = description
+        .get(17..)
+        .filter(|_| toggles & 0x02 != 0)
+        .map(|rest| {
+            let end = rest
+                .iter()
+                .position(|byte| *byte == 0)
+                .unwrap_or(rest.len());
+            String::from_utf8_lossy(&rest[..end]).to_string()
+        });
+    Some(Superbox { label, boxes })
+}
+
+fn child_superboxes<'a>(superbox: &Superbox<'a>) -> Vec<Superbox<'a>> {
+    superbox
+        .boxes
+        .iter()
+        .filter(|(box_type, _)| box_type == b"jumb")
+        .filter_map(|(_, content)| read_superbox(content))
+        .collect()
+}
+
+// 断言和声明的内容保存在 `cbor` 或 `json` 盒子中，二进制数据 (缩略图等) 忽略
+fn content_value(superbox: &Superbox) -> Option<Value> {
+    superbox
+        .boxes
+        .iter()
+        .find_map(|(box_type, data)| match box_type {
+            b"cbor" => ciborium::de::from_reader_with_recursion_limit::<CborValue, _>(
+                *data,
+                MAX_CBOR_DEPTH,
+            )
+            .ok()
+            .map(cbor_to_json),
+            b"json" => serde_json::from_slice(data).ok(),
+            _ => None,
+        })
+}
+
+fn parse_manifest(manifest: &Superbox) -> C2paManifest {
+    let mut result = C2paManifest {
+        label: manifest.label.clone().unwrap_or_default(),
+        claim_generator: None,
+        title: None,
+        format: None,
+        actions: vec![],
+        ingredients: vec![],
+        assertion_labels: vec![],
+    };
+    for child in child_superboxes(manifest) {
+        match child.label.as_deref() {
+            Some("c2pa.claim" | "c2pa.claim.v2") => {
+                let Some(claim) = content_value(&child) else {
+                    continue;
+                };
+                result.claim_generator = claim
+                    .get("claim_generator_info")
+                    .and_then(software_name)
+                    .or_else(|| text(&claim, "claim_generator"));
+                result.title = text(&claim, "dc:title");
+                result.format = text(&claim, "dc:format");
+            }
+            Some(ASSERTION_STORE_LABEL) => {
+                for assertion in child_superboxes(&child) {
+                    let label = assertion.label.clone().unwrap_or_default();
+                    read_assertion(&label, &assertion, &mut result);
+                    result.assertion_labels.push(label);
+                }
+            }
+            _ => {}
+        }
+    }
+    result
+}
+
+fn read_assertion(label: &str, assertion: &Superbox, manifest: &mut C2paManifest) {
+    // 同类型的多个断言以 `__1`、`__2` 区分
+    let base_label = label.split_once("__").map_or(label, |(base, _)| base);
+    let is_actions = matches!(base_label, "c2pa.actions" | "c2pa.actions.v2");
+    let is_ingredient = base_label.starts_with("c2pa.ingredient");
+    if !is_actions && !is_ingredient {
+        return;
+    }
+    let Some(value) = content_value(assertion) else {
+        return;
+    };
+
+    if is_ingredient {
+        manifest.ingredients.push(C2paIngredient {
+            title: text(&value, "dc:title"),
+            format: text(&value, "dc:format"),
+            relationship: text(&value, "relationship"),
+        });
+        return;
+    }
+    let actions = value.get("actions").and_then(Value::as_array);
+    for action in actions.into_iter().flatten() {
+        let Some(name) = text(action, "action") else {
+            continue;
+        };
+        manifest.actions.push(C2paAction {
+            action: name,
+            software_agent: action.get("softwareAgent").and_then(software_name),
+            digital_source_type: text(action, "digitalSourceType"),
+            when: text(action, "when"),
+        });
+    }
+}
+
+fn text(value: &Value, key: &str) -> Option<String> {
+    value.get(key)?.as_str().map(str::to_string)
+}
+
+// 字符串，或 `{"name": ..., "version": ...}` (v1 的 `claim_generator_info` 为数组)
+fn software_name(value: &Value) -> Option<String> {
+    let info = match value {
+        Value::String(name) => return Some(name.clone()),
+        Value::Array(items) => items.first()?,
+        other => other,
+    };
+    let name = info.get("name")?.as_str()?;
+    match info.get("version").and_then(Value::as_str) {
+        Some(version) => Some(format!("{name} {version}")),
+        None => Some(name.to_string()),
+    }
+}
+
+fn cbor_to_json(value: CborValue) -> Value {
+    match value {
+        CborValue::Integer(integer) => {
+            let integer = i128::from(integer);
+            i64::try_from(integer)
+                .map(Value::from)
+                .or_else(|_| u64::try_from(integer).map(Value::from))
+                .unwrap_or(Value::Null)
+        }
+        CborValue::Float(float) => {
+            serde_json::Number::from_f64(float).map_or(Value::Null, Value::Number)
+        }
+        CborValue::Text(text) => Value::String(text),
+        CborValue::Bool(boolean) => Value::Bool(boolean),
+        CborValue::Tag(_, inner) => cbor_to_json(*inner),
+        CborValue::Array(items) => Value::Array(items.into_iter().map(cbor_to_json).collect()),
+        CborValue::Map(entries) => Value::Object(
+            entries
+                .into_iter()
+                .filter_map(|(key, value)| Some((key.into_text().ok()?, cbor_to_json(value))))
+                .collect(),
+        ),
+        // 哈希等二进制数据不需要显示
+        _ => Value::Null,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use image::ImageFormat;
+    use serde_json::json;
+
+    use super::*;
+    use crate::test_fixtures::{
+        insert_jpeg_segment, insert_png_chunk, jumbf_box, source_image, superbox,
+    };
+
+    fn cbor_box(value: &CborValue) -> Vec<u8> {
+        let mut data = vec![];
+        ciborium::ser::into_writer(value, &mut data).unwrap();
+        jumbf_box(b"cbor", &data)
+    }
+
+    fn cbor(value: Value) -> CborValue {
+        CborValue::serialized(&value).unwrap()
+    }
+
+    fn store_fixture() -> Vec<u8> {
+        let claim = superbox(
+            "c2pa.claim",
+            &[cbor_box(&cbor(json!({
+                "claim_generator": "fallback/1.0",
+                "claim_generator_info": [{"name": "DALL·E", "version": "3"}],
+                "dc:title": "image.png",
+                "dc:format": "image/png",
+            })))],
+        );
+        // v1 的操作写在 JSON 盒子中
+        let actions_v1 = superbox(
+            "c2pa.actions",
+            &[jumbf_box(
+                b"json",
+                br#"{"actions": [{"action": "c2pa.opened"}, {"softwareAgent": "x"}]}"#,
+            )],
+        );
+        let actions_v2 = superbox(
+            "c2pa.actions.v2__1",
+            &[cbor_box(&cbor(json!({"actions": [{
+                "action": "c2pa.created",
+                "softwareAgent": {"name": "GPT-4o"},
+                "digitalSourceType": "http://cv.iptc.org/newscodes/digitalsourcetype/trainedAlgorithmicMedia",
+                "when": "2024-01-01T00:00:00Z",
+            }]})))],
+        );
+        let ingredient = superbox(
+            "c2pa.ingredient.v3",
+            &[cbor_box(&cbor(json!({
+                "dc:title": "source.jpg",
+                "dc:format": "image/jpeg",
+                "relationship": "parentOf",
+            })))],
+        );
+        let hash = superbox(
+            "c2pa.hash.data",
+            &[cbor_box(&CborValue::Map(vec![(
+                CborValue::Text("hash".into()),
+                CborValue::Bytes(vec![1, 2, 3]),
+            )]))],
+        );
+        let assertions = superbox(
+            ASSERTION_STORE_LABEL,
+            &[actions_v1, actions_v2, ingredient, hash],
+        );
+        let ingredient_manifest = superbox("urn:uuid:ingredient", &[]);
+        superbox(
+            MANIFEST_STORE_LABEL,
+            &[
+                ingredient_manifest,
+                superbox("urn:uuid:current", &[claim, assertions]),
+            ],
+        )
+    }
+
+    // `JP` + 实例编号 + 分段序号 + 数据
+    fn app11(instance: u16, sequence: u32, payload: &[u8]) -> Vec<u8> {
+        let mut segment = b"JP".to_vec();
+        segment.extend_from_slice(&instance.to_be_bytes());
+        segment.extend_from_slice(&sequence.to_be_bytes());
+        segment.extend_from_slice(payload);
+        segment
+    }
+
+    #[test]
+    fn manifest_store_is_parsed() {
+        let png = source_image(8, 8, ImageFormat::Png, false);
+        let manifests = read_c2pa_manifests(&insert_png_chunk(&png, b"caBX", &store_fixture()));
+        assert_eq!(manifests.len(), 2);
+        assert_eq!(manifests[0].label, "urn:uuid:ingredient");
+
+        let manifest = &manifests[1];
+        assert_eq!(manifest.claim_generator.as_deref(), Some("DALL·E 3"));
+        assert_eq!(manifest.title.as_deref(), Some("image.png"));
+        assert_eq!(manifest.format.as_deref(), Some("image/png"));
+        assert_eq!(
+            manifest.assertion_labels,
+            [
+                "c2pa.actions",
+                "c2pa.actions.v2__1",
+                "c2pa.ingredient.v3",
+                "c2pa.hash.data"
+            ]
+        );
+        // 没有 action 字段的操作跳过
+        assert_eq!(
+            manifest.actions,
+            [
+                C2paAction {
+                    action: "c2pa.opened".to_string(),
+                    software_agent: None,
+                    digital_source_type: None,
+                    when: None,
+                },
+                C2paAction {
+                    action: "c2pa.created".to_string(),
+                    software_agent: Some("GPT-4o".to_string()),
+                    digital_source_type: Some(
+                        "http://cv.iptc.org/newscodes/digitalsourcetype/trainedAlgorithmicMedia"
+                            .to_string()
+                    ),
+                    when: Some("2024-01-01T00:00:00Z".to_string()),
+                },
+            ]
+        );
+        assert_eq!(
+            manifest.ingredients,
+            [C2paIngredient {
+                title: Some("source.jpg".to_string()),
+                format: Some("image/jpeg".to_string()),
+                relationship: Some("parentOf".to_string()),
+            }]
+        );
+    }
+
+    #[test]
+    fn jpeg_segments_are_reassembled_in_order() {
+        let store = store_fixture();
+        let (first, second) = store.split_at(store.len() / 2);
+        // 后续分段重复 LBox + TBox
+        let second = [&store[..8], second].concat();
+        let jpeg = source_image(8, 8, ImageFormat::Jpeg, false);
+        // 在 SOI 之后依次插入，文件中第二段在前
+        let jpeg = insert_jpeg_segment(&jpeg, JPEG_APP11, &app11(7, 1, first));
+        let jpeg = insert_jpeg_segment(&jpeg, JPEG_APP11, &app11(7, 2, &second));
+        // 同一文件中其他用途的 JUMBF 不影响结果
+        let other = superbox("other", &[]);
+        let jpeg = insert_jpeg_segment(&jpeg, JPEG_APP11, &app11(1, 1, &other));
+
+        let manifests = read_c2pa_manifests(&jpeg);
+        assert_eq!(manifests.len(), 2);
+        assert_eq!(manifests[1].claim_generator.as_deref(), Some("DALL·E 3"));
+        assert!(has_c2pa_manifest(&jpeg));
+        assert_eq!(jpeg_c2pa_instances(&jpeg_segments(&jpeg).unwrap()), [7]);
+    }
+
+    #[test]
+    fn extended_and_open_ended_box_lengths_are_read() {
+        // LBox 为 1 时使用 8 字节的 XLBox，为 0 时延伸到末尾
+        let mut data = 1u32.to_be_bytes().to_vec();
+        data.extend_from_slice(b"json");
+        data.extend_from_slice(&18u64.to_be_bytes());
+        data.extend_from_slice(b"{}");
+        data.extend_from_slice(&0u32.to_be_bytes());
+        data.extend_from_slice(b"cbor");
+        data.extend_from_slice(&[0xa0, 0xa0]);
+
+        let boxes = read_boxes(&data);
+        assert_eq!(
+            boxes,
+            [
+                (*b"json", b"{}".as_slice()),
+                (*b"cbor", [0xa0, 0xa0].as_slice())
+            ]
+        );
+    }
+
+    #[test]
+    fn truncated_boxes_are_ignored() {
+        let store = store_fixture();
+        for length in 0..store.len() {
+            assert!(manifest_store(&store[..length]).is_none());
+        }
+        // 声明的长度小于盒子头
+        let mut data = 4u32.to_be_bytes().to_vec();
+        data.extend_from_slice(b"jumb");
+        assert!(read_boxes(&data).is_empty());
+
+        let png = source_image(8, 8, ImageFormat::Png, false);
+        let png = insert_png_chunk(&png, b"caBX", &store[..store.len() - 1]);
+        assert!(read_c2pa_manifests(&png).is_empty());
+        // 无法解析的清单仍然能按块的类型识别
+        assert!(has_c2pa_manifest(&png));
+    }
+
+    #[test]
+    fn cbor_values_are_converted_to_json() {
+        let value = CborValue::Map(vec![
+            (
+                CborValue::Text("big".into()),
+                CborValue::Integer(u64::MAX.into()),
+            ),
+            (
+                CborValue::Text("negative".into()),
+                CborValue::Integer((-5).into()),
+            ),
+            (CborValue::Text("nan".into()), CborValue::Float(f64::NAN)),
+            (
+                CborValue::Text("tagged".into()),
+                CborValue::Tag(0, Box::new(CborValue::Text("2024".into()))),
+            ),
+            (CborValue::Text("bytes".into()), CborValue::Bytes(vec![1])),
+            (CborValue::Integer(1.into()), CborValue::Bool(true)),
+            (
+                CborValue::Text("list".into()),
+                CborValue::Array(vec![CborValue::Bool(false), CborValue::Null]),
+            ),
+        ]);
+        assert_eq!(
+            cbor_to_json(value),
+            json!({
+                "big": u64::MAX,
+                "negative": -5,
+                "nan": null,
+                "tagged": "2024",
+                "bytes": null,
+                "list": [false, null],
+            })
+        );
+    }
+}
@@ -0,0 +1,379 @@
+use std::collections::HashSet;
+
+use serde_json::{Map, Value};
+
+use crate::api::metadata::PngTextChunk;
+use crate::fields::text_chunk;
+
+/// ComfyUI 图片中的两个文本块，以及从 API 图中解析出的摘要
+#[derive(Debug, Clone, PartialEq)]
+pub struct ComfyUiMetadata {
+    /// `prompt` 文本块 (API 格式的节点图)
+    pub prompt_graph: Option<String>,
+    /// `workflow` 文本块 (UI 格式的节点图)
+    pub workflow: Option<String>,
+    pub summary: Option<ComfyUiSummary>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct ComfyUiSummary {
+    pub positive_prompt: Option<String>,
+    pub negative_prompt: Option<String>,
+    pub seed: Option<u64>,
+    pub steps: Option<u32>,
+    pub cfg: Option<f64>,
+    pub sampler_name: Option<String>,
+    pub scheduler: Option<String>,
+    pub denoise: Option<f64>,
+    pub checkpoint: Option<String>,
+    pub loras: Vec<ComfyUiLora>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct ComfyUiLora {
+    pub name: String,
+    pub strength_model: Option<f64>,
+    pub strength_clip: Option<f64>,
+}
+
+// 防止循环引用或异常深的节点链
+const MAX_LINK_DEPTH: usize = 32;
+
+/// 根据 PNG 文本块的键名提取 ComfyUI 数据，`prompt` 不是 JSON 对象且没有 `workflow` 时返回 `None`
+///
+/// Easy Diffusion 等工具也会写入纯文本的 `prompt` 文本块，不能只凭键名判断
+pub(crate) fn extract_comfyui_metadata(chunks: &[PngTextChunk]) -> Option<ComfyUiMetadata> {
+    let prompt_graph = text_chunk(chunks, "prompt").and_then(|text| {
+        let graph = serde_json::from_str::<Value>(text).ok()?;
+        graph.is_object().then_some((text, graph))
+    });
+    let workflow = text_chunk(chunks, "workflow");
+    if prompt_graph.is_none() && workflow.is_none() {
+        return None;
+    }
+
+    let summary = prompt_graph
+        .as_ref()
+        .and_then(|(_, graph)| summarize_prompt_graph(graph.as_object()?));
+
+    Some(ComfyUiMetadata {
+        prompt_graph: prompt_graph.map(|(text, _)| text.to_string()),
+        workflow: workflow.map(str::to_string),
+        summary,
+    })
+}
+
+/// 从采样器节点出发，沿着连线找到提示词、模型和 LoRA
+fn summarize_prompt_graph(graph: &Map<String, Value>) -> Option<ComfyUiSummary> {
+    let (_, sampler) = graph
+        .iter()
+        .filter(|(_, node)| input(node, "positive").is_some() && input(node, "negative").is_some())
+        .min_by_key(|(id, node)| {
+            // 优先选择 KSampler 系列节点，其次按节点编号
+            let is_ksampler = class_type(node).is_some_and(|class| class.contains("KSampler"));
+            (!is_ksampler, id.parse::<u64>().unwrap_or(u64::MAX))
+        })?;
+
+    let number = |keys: &[&str]| {
+        keys.iter()
+            .find_map(|key| resolve_value(graph, input(sampler, key)?, key, 0))
+            .and_then(|value| value.as_f64())
+    };
+    let string = |key: &str| {
+        resolve_value(graph, input(sampler, key)?, key, 0)
+            .and_then(|value| value.as_str().map(str::to_string))
+    };
+
+    let mut loras = vec![];
+    let checkpoint =
+        input(sampler, "model").and_then(|link| walk_model_chain(graph, link, &mut loras));
+    // 不在模型链上的 LoRA 加载器 (例如只作用于 CLIP) 也一并收集
+    for node in sorted_nodes(graph) {
+        if let Some(lora) = parse_lora(node) {
+            if !loras.contains(&lora) {
+                loras.push(lora);
+            }
+        }
+    }
+
+    Some(ComfyUiSummary {
+        positive_prompt: input(sampler, "positive")
+            .and_then(|link| resolve_text(graph, link, &mut HashSet::new(), 0)),
+        negative_prompt: input(sampler, "negative")
+            .and_then(|link| resolve_text(graph, link, &mut HashSet::new(), 0)),
+        seed: number(&["seed", "noise_seed"])
+            .filter(|seed| *seed >= 0.0)
+            .map(|seed| seed as u64),
+        steps: number(&["steps"]).map(|steps| steps as u32),
+        cfg: number(&["cfg"]),
+        sampler_name: string("sampler_name"),
+        scheduler: string("scheduler"),
+        denoise: number(&["denoise"]),
+        checkpoint: checkpoint.or_else(|| {
+            sorted_nodes(graph)
+                .into_iter()
+                .find_map(|node| node_string_input(node, "ckpt_name"))
+        }),
+        loras,
+    })
+}
+
+// 跟随 `model` 输入，记录经过的 LoRA 加载器，返回最终的模型文件名
+fn walk_model_chain(
+    graph: &Map<String, Value>,
+    link: &Value,
+    loras: &mut Vec<ComfyUiLora>,
+) -> Option<String> {
+    let mut current = linked_node(graph, link)?;
+    for _ in 0..MAX_LINK_DEPTH {
+        if let Some(name) = node_string_input(current, "ckpt_name")
+            .or_else(|| node_string_input(current, "unet_name"))
+        {
+            return Some(name);
+        }
+        if let Some(lora) = parse_lora(current) {
+            loras.push(lora);
+        }
+        current = linked_node(graph, input(current, "model")?)?;
+    }
+    None
+}
+
+fn parse_lora(node: &Value) -> Option<ComfyUiLora> {
+    Some(ComfyUiLora {
+        name: node_string_input(node, "lora_name")?,
+        strength_model: input(node, "strength_model").and_then(Value::as_f64),
+        strength_clip: input(node, "strength_clip").and_then(Value::as_f64),
+    })
+}
+
+// 递归查找提示词文本，兼容 Conditioning 合并节点和字符串原语节点
+fn resolve_text(
+    graph: &Map<String, Value>,
+    value: &Value,
+    visited: &mut HashSet<String>,
+    depth: usize,
+) -> Option<String> {
+    if let Some(text) = value.as_str() {
+        return Some(text.to_string());
+    }
+    if depth >= MAX_LINK_DEPTH {
+        return None;
+    }
+    let id = link_target(value)?;
+    if !visited.insert(id.to_string()) {
+        return None;
+    }
+    let inputs = graph.get(id)?.get("inputs")?.as_object()?;
+
+    // 文本编码节点: CLIPTextEncode 使用 `text`，SDXL 编码器使用 `text_g` / `text_l`
+    for key in ["text", "text_g", "text_l", "string", "value", "prompt"] {
+        if let Some(input) = inputs.get(key) {
+            if let Some(text) = resolve_text(graph, input, visited, depth + 1) {
+                return Some(text);
+            }
+        }
+    }
+
+    let texts: Vec<String> = inputs
+        .iter()
+        .filter(|(_, input)| link_target(input).is_some())
+        .filter_map(|(_, input)| resolve_text(graph, input, visited, depth + 1))
+        .collect();
+    if texts.is_empty() {
+        None
+    } else {
+        Some(texts.join("\n"))
+    }
+}
+
+// 数值和字符串参数可能连接到原语节点，跟随连线取出实际的值
+fn resolve_value<'a>(
+    graph: &'a Map<String, Value>,
+    value: &'a Value,
+    key: &str,
+    depth: usize,
+) -> Option<&'a Value> {
+    let Some(id) = link_target(value) else {
+        return Some(value);
+    };
+    if depth >= MAX_LINK_DEPTH {
+        return None;
+    }
+    let inputs = graph.get(id)?.get("inputs")?.as_object()?;
+    // 优先使用同名输入，其次是原语节点常见的输入名，最后取第一个标量
+    let value = [key, "value", "int", "float", "number", "string", "seed"]
+        .iter()
+        .find_map(|name| inputs.get(*name))
+        .or_else(|| {
+            inputs
+                .values()
+                .find(|value| !value.is_object() && !value.is_array())
+        })?;
+    resolve_value(graph, value, key, depth + 1)
+}
+
+// 连线的格式为 `["节点编号", 输出序号]`
+fn link_target(value: &Value) -> Option<&str> {
+    match value.as_array()?.as_slice() {
+        [Value::String(id), Value::Number(_)] => Some(id),
+        _ => None,
+    }
+}
+
+fn linked_node<'a>(graph: &'a Map<String, Value>, link: &Value) -> Option<&'a Value> {
+    graph.get(link_target(link)?)
+}
+
+fn input<'a>(node: &'a Value, key: &str) -> Option<&'a Value> {
+    node.get("inputs")?.get(key)
+}
+
+fn node_string_input(node: &Value, key: &str) -> Option<String> {
+    input(node, key).and_then(Value::as_str).map(str::to_string)
+}
+
+fn class_type(node: &Value) -> Option<&str> {
+    node.get("class_type").and_then(Value::as_str)
+}
+
+// 节点编号通常是数字字符串，按数值排序以保持结果稳定
+fn sorted_nodes(graph: &Map<String, Value>) -> Vec<&Value> {
+    let mut nodes: Vec<(&String, &Value)> = graph.iter().collect();
+    nodes.sort_by_key(|(id, _)| (id.parse::<u64>().unwrap_or(u64::MAX), id.to_string()));
+    nodes.into_iter().map(|(_, node)| node).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+    use crate::test_fixtures::make_text_chunk;
+
+    fn summarize(graph: Value) -> ComfyUiSummary {
+        let chunks = [make_text_chunk("prompt", &graph.to_string())];
+        extract_comfyui_metadata(&chunks).unwrap().summary.unwrap()
+    }
+
+    fn lora(id: &str, name: &str, model: &str) -> Value {
+        json!({
+            "class_type": "LoraLoader",
+            "inputs": {
+                "lora_name": name,
+                "strength_model": 0.8,
+                "strength_clip": 0.5,
+                "model": [model, 0],
+                "clip": [id, 1],
+            }
+        })
+    }
+
+    fn graph() -> Value {
+        json!({
+            "1": {"class_type": "CheckpointLoaderSimple", "inputs": {"ckpt_name": "model.safetensors"}},
+            "2": lora("2", "first.safetensors", "1"),
+            "3": lora("3", "second.safetensors", "2"),
+            "4": {"class_type": "CLIPTextEncode", "inputs": {"text": "1girl", "clip": ["3", 1]}},
+            "5": {"class_type": "CLIPTextEncode", "inputs": {"text": ["8", 0], "clip": ["3", 1]}},
+            "6": {"class_type": "PrimitiveNode", "inputs": {"value": 1234}},
+            "7": {
+                "class_type": "KSampler",
+                "inputs": {
+                    "model": ["3", 0],
+                    "positive": ["4", 0],
+                    "negative": ["5", 0],
+                    "seed": ["6", 0],
+                    "steps": 28,
+                    "cfg": 7.0,
+                    "sampler_name": "euler",
+                    "scheduler": "normal",
+                    "denoise": 1.0,
+                }
+            },
+            "8": {"class_type": "String Literal", "inputs": {"string": "lowres"}},
+        })
+    }
+
+    #[test]
+    fn sampler_inputs_are_resolved_through_links() {
+        let summary = summarize(graph());
+        assert_eq!(summary.positive_prompt.as_deref(), Some("1girl"));
+        assert_eq!(summary.negative_prompt.as_deref(), Some("lowres"));
+        assert_eq!(summary.seed, Some(1234));
+        assert_eq!(summary.steps, Some(28));
+        assert_eq!(summary.cfg, Some(7.0));
+        assert_eq!(summary.sampler_name.as_deref(), Some("euler"));
+        assert_eq!(summary.checkpoint.as_deref(), Some("model.safetensors"));
+    }
+
+    #[test]
+    fn lora_chain_is_collected_from_the_sampler() {
+        let mut graph = graph();
+        // 只作用于 CLIP 的加载器不在模型链上，也要收集
+        graph["9"] = lora("9", "clip_only.safetensors", "1");
+        let names: Vec<_> = summarize(graph)
+            .loras
+            .into_iter()
+            .map(|lora| (lora.name, lora.strength_model, lora.strength_clip))
+            .collect();
+        assert_eq!(
+            names,
+            [
+                ("second.safetensors".to_string(), Some(0.8), Some(0.5)),
+                ("first.safetensors".to_string(), Some(0.8), Some(0.5)),
+                ("clip_only.safetensors".to_string(), Some(0.8), Some(0.5)),
+            ]
+        );
+    }
+
+    #[test]
+    fn ksampler_is_preferred_over_other_samplers() {
+        let mut graph = graph();
+        // 编号更小但不是 KSampler 的节点 (例如 ControlNet 应用节点) 也有 positive / negative
+        graph["0"] = json!({
+            "class_type": "ControlNetApplyAdvanced",
+            "inputs": {"positive": ["5", 0], "negative": ["4", 0], "steps": 5}
+        });
+        let summary = summarize(graph);
+        assert_eq!(summary.positive_prompt.as_deref(), Some("1girl"));
+        assert_eq!(summary.steps, Some(28));
+    }
+
+    #[test]
+    fn link_cycles_stop_at_the_depth_limit() {
+        let mut graph = graph();
+        // 模型链和数值输入互相引用，不能无限循环
+        graph["2"]["inputs"]["model"] = json!(["3", 0]);
+        graph["6"]["inputs"] = json!({"seed": ["10", 0]});
+        graph["10"] = json!({"class_type": "PrimitiveNode", "inputs": {"seed": ["6", 0]}});
+        graph["4"]["inputs"]["text"] = json!(["11", 0]);
+        graph["11"] = json!({"class_type": "ConditioningCombine", "inputs": {"a": ["4", 0]}});
+
+        let summary = summarize(graph);
+        assert_eq!(summary.seed, None);
+        assert_eq!(summary.positive_prompt, None);
+        // 模型链断开后使用图中第一个检查点
+        assert_eq!(summary.checkpoint.as_deref(), Some("model.safetensors"));
+        assert!(summary.loras.len() >= 2);
+    }
+
+    #[test]
+    fn plain_text_prompt_chunk_is_not_comfyui() {
+        // Easy Diffusion 的文本块
+        let chunks = [
+            make_text_chunk("prompt", "a cat, best quality"),
+            make_text_chunk("negative_prompt", "lowres"),
+            make_text_chunk("seed", "1234"),
+        ];
+        assert_eq!(extract_comfyui_metadata(&chunks), None);
+
+        let chunks = [
+            make_text_chunk("prompt", "a cat"),
+            make_text_chunk("workflow", "{}"),
+        ];
+        let metadata = extract_comfyui_metadata(&chunks).unwrap();
+        assert_eq!(metadata.prompt_graph, None);
+        assert_eq!(metadata.workflow.as_deref(), Some("{}"));
+    }
+}
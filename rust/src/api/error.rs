@@ -0,0 +1,65 @@
+use flutter_rust_bridge::frb;
+use thiserror::Error;
+
+/// 元数据提取和扫描过程中的错误
+#[frb(ignore)]
+#[derive(Debug, Error)]
+pub enum MetadataError {
+    #[error("无法识别的文件类型 (Unsupported format)")]
+    UnsupportedFormat,
+    #[error("图片解码失败 (Decode failure): {0}")]
+    Decode(String),
+    #[error("图片尺寸无效 (Invalid dimensions): {0}x{1}")]
+    InvalidDimensions(u32, u32),
+    #[error("隐写数据被截断 (Truncated stealth payload)")]
+    TruncatedStealthPayload,
+    #[error("隐写数据过大 (Stealth payload is too large): {0} bytes")]
+    OversizedStealthPayload(u64),
+    #[error("隐写数据无效 (Invalid stealth payload): {0}")]
+    InvalidStealthPayload(String),
+    #[error("读取文件失败 (I/O error): {0}")]
+    Io(#[from] std::io::Error),
+}
+
+/// 错误类别，供界面显示和筛选损坏的文件
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MetadataErrorKind {
+    UnsupportedFormat,
+    DecodeFailed,
+    InvalidDimensions,
+    TruncatedStealthPayload,
+    OversizedStealthPayload,
+    InvalidStealthPayload,
+    NotFound,
+    PermissionDenied,
+    Io,
+}
+
+impl MetadataError {
+    #[frb(ignore)]
+    pub fn kind(&self) -> MetadataErrorKind {
+        match self {
+            MetadataError::UnsupportedFormat => MetadataErrorKind::UnsupportedFormat,
+            MetadataError::Decode(_) => MetadataErrorKind::DecodeFailed,
+            MetadataError::InvalidDimensions(..) => MetadataErrorKind::InvalidDimensions,
+            MetadataError::TruncatedStealthPayload => MetadataErrorKind::TruncatedStealthPayload,
+            MetadataError::OversizedStealthPayload(_) => MetadataErrorKind::OversizedStealthPayload,
+            MetadataError::InvalidStealthPayload(_) => MetadataErrorKind::InvalidStealthPayload,
+            MetadataError::Io(error) => match error.kind() {
+                std::io::ErrorKind::NotFound => MetadataErrorKind::NotFound,
+                std::io::ErrorKind::PermissionDenied => MetadataErrorKind::PermissionDenied,
+                _ => MetadataErrorKind::Io,
+            },
+        }
+    }
+}
+
+// 图片数据已经在内存中，解码时的 I/O 错误只可能是文件被截断，因此都归为解码失败
+impl From<image::ImageError> for MetadataError {
+    fn from(error: image::ImageError) -> Self {
+        match error {
+            image::ImageError::Unsupported(_) => MetadataError::UnsupportedFormat,
+            error => MetadataError::Decode(error.to_string()),
+        }
+    }
+}
@@ -0,0 +1,173 @@
+use serde_json::{Map, Value};
+
+use crate::api::a1111::A1111Parameters;
+use crate::api::metadata::PngTextChunk;
+use crate::fields::{get_parsed, get_text, get_u64, settings, text_chunk};
+
+/// 识别 Fooocus 写入的参数，转换为与 A1111 相同的字段
+///
+/// Fooocus 按 `fooocus_scheme` 选择格式: `fooocus` 为 `parameters` 中的 JSON，
+/// `a1111` 为 A1111 格式的文本 (最后一行带有 `Version: Fooocus v2.x`)。
+/// JPEG / WebP 中没有 `fooocus_scheme`，只能根据 JSON 中的版本号判断
+pub(crate) fn parse_fooocus_parameters(
+    chunks: &[PngTextChunk],
+    metadata_string: Option<&str>,
+    a1111_parameters: Option<&A1111Parameters>,
+) -> Option<A1111Parameters> {
+    let chunk = |key: &str| text_chunk(chunks, key);
+    let scheme = chunk("fooocus_scheme").map(str::trim);
+
+    let json = chunk("parameters")
+        .or(metadata_string)
+        .and_then(|text| serde_json::from_str::<Value>(text.trim()).ok());
+    if let Some(Value::Object(root)) = json {
+        let version = field_text(&root, &["version", "Version"]);
+        if scheme == Some("fooocus") || version.as_deref().is_some_and(is_fooocus_version) {
+            return Some(parse_fooocus_json(&root));
+        }
+    }
+
+    a1111_parameters
+        .filter(|parameters| {
+            scheme == Some("a1111")
+                || parameters
+                    .settings
+                    .iter()
+                    .any(|(key, value)| key == "Version" && is_fooocus_version(value))
+        })
+        .cloned()
+}
+
+pub(crate) fn is_fooocus_version(version: &str) -> bool {
+    version.trim().to_ascii_lowercase().starts_with("fooocus")
+}
+
+// 新版本使用小写下划线的键名，旧版本的日志格式使用 `Guidance Scale` 这样的键名
+fn parse_fooocus_json(root: &Map<String, Value>) -> A1111Parameters {
+    // 分辨率写作 `(1152, 896)`
+    let resolution: Vec<u32> = field_text(root, &["resolution", "Resolution"])
+        .map(|resolution| {
+            resolution
+                .split(|c: char| !c.is_ascii_digit())
+                .filter_map(|part| part.parse().ok())
+                .collect()
+        })
+        .unwrap_or_default();
+    let (width, height) = match resolution.as_slice() {
+        [width, height] => (Some(*width), Some(*height)),
+        _ => (None, None),
+    };
+
+    A1111Parameters {
+        prompt: field_text(root, &["prompt", "Prompt"]).unwrap_or_default(),
+        negative_prompt: field_text(root, &["negative_prompt", "Negative Prompt"]),
+        steps: field_integer(root, &["steps", "Steps"]).and_then(|steps| u32::try_from(steps).ok()),
+        sampler: field_text(root, &["sampler", "Sampler"]),
+        schedule_type: field_text(root, &["scheduler", "Scheduler"]),
+        cfg_scale: field_number(root, &["guidance_scale", "Guidance Scale"]),
+        seed: field_integer(root, &["seed", "Seed"]),
+        width,
+        height,
+        model: field_text(root, &["base_model", "Base Model"]),
+        model_hash: field_text(root, &["base_model_hash"]),
+        denoising_strength: None,
+        settings: settings(root),
+    }
+}
+
+// 新旧版本的键名不同，使用第一个存在的键
+fn field_text(object: &Map<String, Value>, keys: &[&str]) -> Option<String> {
+    keys.iter().find_map(|key| get_text(object, key))
+}
+
+fn field_number(object: &Map<String, Value>, keys: &[&str]) -> Option<f64> {
+    keys.iter().find_map(|key| get_parsed(object, key))
+}
+
+fn field_integer(object: &Map<String, Value>, keys: &[&str]) -> Option<u64> {
+    keys.iter().find_map(|key| get_u64(object, key))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::api::a1111::parse_a1111_parameters;
+    use crate::test_fixtures::make_text_chunk;
+
+    // Fooocus v2.5 以 `fooocus` 格式导出的 `parameters`
+    const FOOOCUS_JSON: &str = r#"{"adm_guidance": "(1.5, 0.8, 0.3)", "base_model": "juggernautXL_v8Rundiffusion.safetensors", "base_model_hash": "aeb7e9e689", "clip_skip": 2, "guidance_scale": 4, "loras": [["sd_xl_offset_example-lora_1.0.safetensors", 0.1, "4852686128"]], "metadata_scheme": "fooocus", "negative_prompt": "lowres", "performance": "Speed", "prompt": "a cat", "prompt_expansion": "a cat, intricate, highly detailed", "refiner_model": "None", "refiner_switch": 0.8, "resolution": "(1152, 896)", "sampler": "dpmpp_2m_sde_gpu", "scheduler": "karras", "seed": "1234567890123", "sharpness": 2, "steps": 30, "styles": "['Fooocus V2', 'Fooocus Enhance', 'Fooocus Sharp']", "vae": "Default (model)", "version": "Fooocus v2.5.3"}"#;
+
+    #[test]
+    fn fooocus_scheme_json_is_parsed() {
+        let chunks = [
+            make_text_chunk("parameters", FOOOCUS_JSON),
+            make_text_chunk("fooocus_scheme", "fooocus"),
+        ];
+        let parameters = parse_fooocus_parameters(&chunks, None, None).unwrap();
+        assert_eq!(parameters.prompt, "a cat");
+        assert_eq!(parameters.negative_prompt.as_deref(), Some("lowres"));
+        assert_eq!(parameters.steps, Some(30));
+        assert_eq!(parameters.sampler.as_deref(), Some("dpmpp_2m_sde_gpu"));
+        assert_eq!(parameters.schedule_type.as_deref(), Some("karras"));
+        assert_eq!(parameters.cfg_scale, Some(4.0));
+        // 种子写成字符串
+        assert_eq!(parameters.seed, Some(1234567890123));
+        assert_eq!(
+            (parameters.width, parameters.height),
+            (Some(1152), Some(896))
+        );
+        assert_eq!(
+            parameters.model.as_deref(),
+            Some("juggernautXL_v8Rundiffusion.safetensors")
+        );
+        assert_eq!(parameters.model_hash.as_deref(), Some("aeb7e9e689"));
+        assert!(parameters
+            .settings
+            .contains(&("performance".to_string(), "Speed".to_string())));
+    }
+
+    #[test]
+    fn legacy_log_keys_and_string_numbers_are_parsed() {
+        // 没有 `fooocus_scheme` 时 (例如 JPEG) 根据版本号判断
+        let json = r#"{"Prompt": "a cat", "Negative Prompt": "", "Steps": "28.0", "Guidance Scale": "7", "Resolution": "(1024, 1024)", "Seed": 42.0, "Base Model": "sd_xl_base_1.0.safetensors", "Version": "Fooocus v2.1.0"}"#;
+        let parameters = parse_fooocus_parameters(&[], Some(json), None).unwrap();
+        assert_eq!(parameters.prompt, "a cat");
+        assert_eq!(parameters.steps, Some(28));
+        assert_eq!(parameters.cfg_scale, Some(7.0));
+        assert_eq!(parameters.seed, Some(42));
+        assert_eq!(
+            (parameters.width, parameters.height),
+            (Some(1024), Some(1024))
+        );
+        assert_eq!(
+            parameters.model.as_deref(),
+            Some("sd_xl_base_1.0.safetensors")
+        );
+    }
+
+    #[test]
+    fn a1111_scheme_uses_text_parameters() {
+        let text = "a cat\nNegative prompt: lowres\nSteps: 30, Sampler: DPM++ 2M SDE Karras, \
+                    Seed: 5, Size: 1152x896, Version: Fooocus v2.5.3";
+        let a1111 = parse_a1111_parameters(text).unwrap();
+        assert_eq!(
+            parse_fooocus_parameters(&[], None, Some(&a1111)),
+            Some(a1111.clone())
+        );
+
+        let chunks = [make_text_chunk("fooocus_scheme", "a1111")];
+        let text = text.replace("Fooocus v2.5.3", "v1.10.1");
+        let a1111 = parse_a1111_parameters(&text).unwrap();
+        assert_eq!(
+            parse_fooocus_parameters(&chunks, None, Some(&a1111)),
+            Some(a1111.clone())
+        );
+        assert_eq!(parse_fooocus_parameters(&[], None, Some(&a1111)), None);
+    }
+
+    #[test]
+    fn json_from_other_tools_is_not_fooocus() {
+        let json = r#"{"prompt": "a cat", "steps": 20, "version": "1.0"}"#;
+        assert_eq!(parse_fooocus_parameters(&[], Some(json), None), None);
+    }
+}
@@ -0,0 +1,89 @@
+use crate::api::c2pa::has_c2pa_manifest;
+use crate::api::metadata::ImageInfo;
+use crate::fields::text_chunk;
+
+/// 生成图片的工具
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Generator {
+    NovelAi,
+    /// AUTOMATIC1111 WebUI 及 Forge 等兼容的分支
+    A1111,
+    ComfyUi,
+    InvokeAi,
+    Fooocus,
+    SwarmUi,
+    Midjourney,
+    /// DALL·E 等只写入 C2PA 内容凭据的工具
+    C2pa,
+    Unknown,
+}
+
+/// 根据文本块的键名、JSON 结构和 `Software` / `Source` 判断生成工具，
+/// `exif_software` 是 JPEG / WebP / TIFF 中 EXIF 的 Software 标签
+///
+/// 多个工具的特征同时出现时，优先选择写入了专用字段的工具，例如 SwarmUI 和 InvokeAI 优先于 ComfyUI
+pub(crate) fn detect_generator(
+    info: &ImageInfo,
+    input_bytes: &[u8],
+    exif_software: Option<&str>,
+) -> Generator {
+    let chunk = |key: &str| text_chunk(&info.text_chunks, key);
+    let software = chunk("Software")
+        .or(exif_software)
+        .or_else(|| info.nai_metadata.as_ref()?.software.as_deref())
+        .unwrap_or_default();
+    let source = chunk("Source")
+        .or_else(|| info.nai_metadata.as_ref()?.source.as_deref())
+        .unwrap_or_default();
+    if info.nai_metadata.is_some() || software == "NovelAI" || source.starts_with("NovelAI") {
+        return Generator::NovelAi;
+    }
+
+    if info.swarmui_parameters.is_some() || chunk("sui_image_params").is_some() {
+        return Generator::SwarmUi;
+    }
+
+    if info.invokeai.is_some()
+        || [
+            "invokeai_metadata",
+            "invokeai_graph",
+            "sd-metadata",
+            "Dream",
+        ]
+        .iter()
+        .any(|key| chunk(key).is_some())
+    {
+        return Generator::InvokeAi;
+    }
+
+    if info.fooocus_parameters.is_some() || chunk("fooocus_scheme").is_some() {
+        return Generator::Fooocus;
+    }
+
+    if info.comfyui.is_some() {
+        return Generator::ComfyUi;
+    }
+    if info.a1111_parameters.is_some() {
+        return Generator::A1111;
+    }
+
+    // Midjourney 在描述的末尾写入 `Job ID: <uuid>`
+    let descriptions = [
+        chunk("Description"),
+        info.metadata_string.as_deref(),
+        info.xmp_packet.as_deref(),
+    ];
+    if descriptions
+        .into_iter()
+        .flatten()
+        .any(|text| text.contains("Job ID:"))
+    {
+        return Generator::Midjourney;
+    }
+
+    // 清单无法解析时仍然根据块的类型判断
+    if !info.c2pa_manifests.is_empty() || has_c2pa_manifest(input_bytes) {
+        return Generator::C2pa;
+    }
+    Generator::Unknown
+}
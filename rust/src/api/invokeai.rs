@@ -0,0 +1,694 @@
+use serde_json::{Map, Value};
+
+use crate::api::metadata::PngTextChunk;
+use crate::fields::{get_string, get_u64, text_chunk};
+
+/// InvokeAI 图片中的 `invokeai_metadata` 和 `invokeai_graph` 文本块，以及解析出的生成参数
+#[derive(Debug, Clone, PartialEq)]
+pub struct InvokeAiMetadata {
+    /// `invokeai_metadata` 文本块 (生成参数)
+    pub metadata: Option<String>,
+    /// `invokeai_graph` 文本块 (节点图)
+    pub graph: Option<String>,
+    pub app_version: Option<String>,
+    /// 例如 `txt2img`、`sdxl_img2img`
+    pub generation_mode: Option<String>,
+    pub positive_prompt: Option<String>,
+    pub negative_prompt: Option<String>,
+    /// SDXL 的风格提示词
+    pub positive_style_prompt: Option<String>,
+    pub negative_style_prompt: Option<String>,
+    pub seed: Option<u64>,
+    pub steps: Option<u32>,
+    pub cfg_scale: Option<f64>,
+    pub cfg_rescale_multiplier: Option<f64>,
+    pub scheduler: Option<String>,
+    pub width: Option<u32>,
+    pub height: Option<u32>,
+    pub model: Option<String>,
+    /// InvokeAI 模型管理器记录的哈希，例如 `blake3:...`
+    pub model_hash: Option<String>,
+    pub vae: Option<String>,
+    pub loras: Vec<InvokeAiLora>,
+    /// 画布中的区域提示词
+    pub regional_prompts: Vec<InvokeAiRegionalPrompt>,
+    /// ControlNet、T2I-Adapter 和 IP-Adapter
+    pub control_layers: Vec<InvokeAiControlLayer>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct InvokeAiLora {
+    pub name: String,
+    pub weight: Option<f64>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct InvokeAiRegionalPrompt {
+    pub name: Option<String>,
+    pub positive_prompt: Option<String>,
+    pub negative_prompt: Option<String>,
+    pub is_enabled: bool,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct InvokeAiControlLayer {
+    /// `controlnet`、`t2i_adapter` 或 `ip_adapter`
+    pub adapter_type: String,
+    pub model: Option<String>,
+    pub weight: Option<f64>,
+    pub begin_step_percent: Option<f64>,
+    pub end_step_percent: Option<f64>,
+    pub is_enabled: bool,
+}
+
+/// 根据 PNG 文本块的键名提取 InvokeAI 数据，没有任何 InvokeAI 文本块时返回 `None`
+///
+/// 优先使用 `invokeai_metadata`，没有时从节点图的 `core_metadata` 节点或各个节点的输入中还原，
+/// 最后是 2.x 的 `sd-metadata` 和更早的 `Dream` 文本块
+pub(crate) fn extract_invokeai_metadata(chunks: &[PngTextChunk]) -> Option<InvokeAiMetadata> {
+    let metadata = text_chunk(chunks, "invokeai_metadata");
+    let graph = text_chunk(chunks, "invokeai_graph");
+    let legacy = text_chunk(chunks, "sd-metadata");
+    let dream = text_chunk(chunks, "Dream");
+    if metadata.is_none() && graph.is_none() && legacy.is_none() && dream.is_none() {
+        return None;
+    }
+
+    let parse = |text: Option<&str>| {
+        text.and_then(|text| serde_json::from_str::<Value>(text).ok())
+            .filter(Value::is_object)
+    };
+    let graph_value = parse(graph);
+    let fields = parse(metadata)
+        .or_else(|| graph_value.as_ref().and_then(graph_metadata_node).cloned())
+        .or_else(|| graph_value.as_ref().and_then(summarize_graph))
+        .or_else(|| parse(legacy).as_ref().and_then(legacy_metadata))
+        .or_else(|| dream.and_then(dream_metadata))
+        .unwrap_or_default();
+    let empty = Map::new();
+    let fields = fields.as_object().unwrap_or(&empty);
+
+    let mut control_layers = vec![];
+    for (key, adapter_type, model_key, weight_key) in [
+        (
+            "controlnets",
+            "controlnet",
+            "control_model",
+            "control_weight",
+        ),
+        ("t2iAdapters", "t2i_adapter", "t2i_adapter_model", "weight"),
+        ("ipAdapters", "ip_adapter", "ip_adapter_model", "weight"),
+    ] {
+        for adapter in array(fields, key) {
+            add_control_layer(
+                &mut control_layers,
+                InvokeAiControlLayer {
+                    adapter_type: adapter_type.to_string(),
+                    model: adapter.get(model_key).and_then(model_name),
+                    weight: adapter.get(weight_key).and_then(Value::as_f64),
+                    begin_step_percent: adapter.get("begin_step_percent").and_then(Value::as_f64),
+                    end_step_percent: adapter.get("end_step_percent").and_then(Value::as_f64),
+                    is_enabled: true,
+                },
+            );
+        }
+    }
+    let regional_prompts = parse_canvas_layers(fields, &mut control_layers);
+
+    let model = fields.get("model");
+    Some(InvokeAiMetadata {
+        app_version: get_string(fields, "app_version"),
+        generation_mode: get_string(fields, "generation_mode"),
+        positive_prompt: get_string(fields, "positive_prompt"),
+        negative_prompt: get_string(fields, "negative_prompt"),
+        positive_style_prompt: get_string(fields, "positive_style_prompt"),
+        negative_style_prompt: get_string(fields, "negative_style_prompt"),
+        seed: get_u64(fields, "seed"),
+        steps: get_u64(fields, "steps").and_then(|steps| u32::try_from(steps).ok()),
+        cfg_scale: fields.get("cfg_scale").and_then(Value::as_f64),
+        cfg_rescale_multiplier: fields.get("cfg_rescale_multiplier").and_then(Value::as_f64),
+        scheduler: get_string(fields, "scheduler"),
+        width: get_u64(fields, "width").and_then(|width| u32::try_from(width).ok()),
+        height: get_u64(fields, "height").and_then(|height| u32::try_from(height).ok()),
+        model: model.and_then(model_name),
+        model_hash: model
+            .and_then(|model| model.get("hash"))
+            .and_then(Value::as_str)
+            .map(str::to_string),
+        vae: fields.get("vae").and_then(model_name),
+        loras: array(fields, "loras").filter_map(parse_lora).collect(),
+        regional_prompts,
+        control_layers,
+        metadata: metadata.map(str::to_string),
+        graph: graph.map(str::to_string),
+    })
+}
+
+// 3.x 以后的节点图中有一个保存全部生成参数的 `core_metadata` 节点
+fn graph_metadata_node(graph: &Value) -> Option<&Value> {
+    graph_nodes(graph).find(|node| node_type(node) == Some("core_metadata"))
+}
+
+// 没有元数据节点时，按节点类型和连线找出各个参数
+fn summarize_graph(graph: &Value) -> Option<Value> {
+    let mut fields = Map::new();
+    let edges = graph.get("edges").and_then(Value::as_array);
+    let node = |id: &str| {
+        graph_nodes(graph).find(|node| node.get("id").and_then(Value::as_str) == Some(id))
+    };
+
+    // 连接到去噪节点 `positive_conditioning` / `negative_conditioning` 的文本编码节点
+    for (field, key) in [
+        ("positive_conditioning", "positive_prompt"),
+        ("negative_conditioning", "negative_prompt"),
+    ] {
+        let prompt = edges
+            .into_iter()
+            .flatten()
+            .filter(|edge| {
+                edge.pointer("/destination/field").and_then(Value::as_str) == Some(field)
+            })
+            .filter_map(|edge| node(edge.pointer("/source/node_id")?.as_str()?))
+            .find_map(|source| source.get("prompt")?.as_str());
+        if let Some(prompt) = prompt {
+            fields.insert(key.to_string(), prompt.into());
+        }
+    }
+
+    for node in graph_nodes(graph) {
+        let node_type = node_type(node).unwrap_or_default();
+        let keys: &[&str] = match node_type {
+            "noise" => &["seed", "width", "height"],
+            "denoise_latents" | "flux_denoise" => &["steps", "cfg_scale", "scheduler"],
+            _ if node_type.ends_with("model_loader") => &["model"],
+            _ => &[],
+        };
+        for key in keys {
+            if let Some(value) = node.get(key) {
+                fields.entry(key.to_string()).or_insert(value.clone());
+            }
+        }
+    }
+    (!fields.is_empty()).then_some(Value::Object(fields))
+}
+
+// 2.x 的 `sd-metadata`: `{"model_weights": ..., "image": {"prompt": [{"prompt": ..., "weight": 1.0}], ...}}`
+fn legacy_metadata(legacy: &Value) -> Option<Value> {
+    let image = legacy.get("image")?.as_object()?;
+    let prompt = match image.get("prompt")? {
+        Value::String(prompt) => prompt.clone(),
+        Value::Array(prompts) => prompts
+            .iter()
+            .filter_map(|prompt| prompt.get("prompt")?.as_str())
+            .collect::<Vec<_>>()
+            .join(" "),
+        _ => return None,
+    };
+
+    let mut fields = prompt_fields(&prompt);
+    for key in ["seed", "steps", "cfg_scale", "width", "height"] {
+        if let Some(value) = image.get(key) {
+            fields.insert(key.to_string(), value.clone());
+        }
+    }
+    for (key, source) in [
+        ("scheduler", image.get("sampler")),
+        ("generation_mode", image.get("type")),
+        ("app_version", legacy.get("app_version")),
+    ] {
+        if let Some(value) = source.filter(|value| value.is_string()) {
+            fields.insert(key.to_string(), value.clone());
+        }
+    }
+    if let Some(name) = legacy.get("model_weights").and_then(Value::as_str) {
+        let mut model = Map::new();
+        model.insert("name".to_string(), name.into());
+        if let Some(hash) = legacy.get("model_hash").filter(|hash| hash.is_string()) {
+            model.insert("hash".to_string(), hash.clone());
+        }
+        fields.insert("model".to_string(), Value::Object(model));
+    }
+    Some(Value::Object(fields))
+}
+
+// 更早的 `Dream` 文本块是命令行: `"a cat [lowres]" -s 50 -S 42 -W 512 -H 512 -C 7.5 -A k_lms`
+fn dream_metadata(text: &str) -> Option<Value> {
+    let (prompt, options) = text.trim().strip_prefix('"')?.rsplit_once('"')?;
+    let mut fields = prompt_fields(prompt);
+    let mut tokens = options.split_whitespace();
+    while let Some(flag) = tokens.next() {
+        let key = match flag {
+            "-s" => "steps",
+            "-S" => "seed",
+            "-W" => "width",
+            "-H" => "height",
+            "-C" => "cfg_scale",
+            "-A" => "scheduler",
+            _ => continue,
+        };
+        let Some(value) = tokens.next() else {
+            break;
+        };
+        let value = serde_json::from_str::<Value>(value)
+            .ok()
+            .filter(Value::is_number)
+            .unwrap_or_else(|| value.into());
+        fields.insert(key.to_string(), value);
+    }
+    Some(Value::Object(fields))
+}
+
+// 2.x 把反向提示词写在正向提示词的方括号中: `a cat [lowres, blurry]`
+fn prompt_fields(prompt: &str) -> Map<String, Value> {
+    let mut positive = String::new();
+    let mut negative = vec![];
+    let mut depth = 0usize;
+    for c in prompt.chars() {
+        match c {
+            '[' => {
+                depth += 1;
+                if depth == 1 {
+                    negative.push(String::new());
+                    continue;
+                }
+            }
+            ']' if depth > 0 => {
+                depth -= 1;
+                if depth == 0 {
+                    continue;
+                }
+            }
+            _ => {}
+        }
+        match negative.last_mut().filter(|_| depth > 0) {
+            Some(text) => text.push(c),
+            None => positive.push(c),
+        }
+    }
+
+    let mut fields = Map::new();
+    let positive = positive.split_whitespace().collect::<Vec<_>>().join(" ");
+    fields.insert("positive_prompt".to_string(), positive.into());
+    let negative: Vec<&str> = negative
+        .iter()
+        .map(|text| text.trim())
+        .filter(|text| !text.is_empty())
+        .collect();
+    if !negative.is_empty() {
+        fields.insert("negative_prompt".to_string(), negative.join(", ").into());
+    }
+    fields
+}
+
+// 节点可能以对象 (按编号索引) 或数组的形式保存
+fn graph_nodes(graph: &Value) -> Box<dyn Iterator<Item = &Value> + '_> {
+    match graph.get("nodes") {
+        Some(Value::Object(nodes)) => Box::new(nodes.values()),
+        Some(Value::Array(nodes)) => Box::new(nodes.iter()),
+        _ => Box::new(std::iter::empty()),
+    }
+}
+
+fn node_type(node: &Value) -> Option<&str> {
+    node.get("type").and_then(Value::as_str)
+}
+
+/// 画布中的图层: 5.x 保存在 `canvas_v2_metadata`，4.x 保存在 `control_layers.layers`
+fn parse_canvas_layers(
+    fields: &Map<String, Value>,
+    control_layers: &mut Vec<InvokeAiControlLayer>,
+) -> Vec<InvokeAiRegionalPrompt> {
+    let mut regional_prompts = vec![];
+    let canvas = fields.get("canvas_v2_metadata").and_then(Value::as_object);
+    let legacy_layers = fields
+        .get("control_layers")
+        .and_then(|layers| layers.get("layers"))
+        .and_then(Value::as_array);
+
+    let regional_layers = canvas
+        .into_iter()
+        .flat_map(|canvas| array(canvas, "regionalGuidance"))
+        .chain(
+            legacy_layers
+                .into_iter()
+                .flatten()
+                .filter(|layer| node_type(layer) == Some("regional_guidance_layer")),
+        );
+    for layer in regional_layers {
+        let prompt = |key: &str| {
+            layer
+                .get(key)
+                .and_then(Value::as_str)
+                .filter(|prompt| !prompt.is_empty())
+                .map(str::to_string)
+        };
+        regional_prompts.push(InvokeAiRegionalPrompt {
+            name: prompt("name"),
+            positive_prompt: prompt("positivePrompt"),
+            negative_prompt: prompt("negativePrompt"),
+            is_enabled: is_enabled(layer),
+        });
+    }
+
+    // 控制图层的 `controlAdapter` 和参考图的 `ipAdapter` 结构相同
+    let adapter_layers = canvas
+        .into_iter()
+        .flat_map(|canvas| array(canvas, "controlLayers").chain(array(canvas, "referenceImages")))
+        .chain(legacy_layers.into_iter().flatten());
+    for layer in adapter_layers {
+        let Some(adapter) = layer
+            .get("controlAdapter")
+            .or_else(|| layer.get("ipAdapter"))
+        else {
+            continue;
+        };
+        let (begin_step_percent, end_step_percent) = match adapter
+            .get("beginEndStepPct")
+            .and_then(Value::as_array)
+            .map(Vec::as_slice)
+        {
+            Some([begin, end]) => (begin.as_f64(), end.as_f64()),
+            _ => (None, None),
+        };
+        let adapter_type = match node_type(adapter) {
+            Some(adapter_type) => adapter_type.to_string(),
+            None if layer.get("ipAdapter").is_some() => "ip_adapter".to_string(),
+            None => continue,
+        };
+        add_control_layer(
+            control_layers,
+            InvokeAiControlLayer {
+                adapter_type,
+                model: adapter.get("model").and_then(model_name),
+                weight: adapter.get("weight").and_then(Value::as_f64),
+                begin_step_percent,
+                end_step_percent,
+                is_enabled: is_enabled(layer),
+            },
+        );
+    }
+    regional_prompts
+}
+
+// 新版本同时在顶层参数和画布图层中记录同一个适配器，按类型和模型去重
+fn add_control_layer(layers: &mut Vec<InvokeAiControlLayer>, layer: InvokeAiControlLayer) {
+    let duplicate = layers.iter().any(|existing| {
+        existing.adapter_type == layer.adapter_type
+            && existing.model == layer.model
+            && existing.weight == layer.weight
+    });
+    if !duplicate {
+        layers.push(layer);
+    }
+}
+
+fn is_enabled(layer: &Value) -> bool {
+    layer
+        .get("isEnabled")
+        .and_then(Value::as_bool)
+        .unwrap_or(true)
+}
+
+// 4.x 以后为 `{"model": {...}, "weight": 0.75}`，3.x 为 `{"lora": {...}, "weight": 0.75}`
+fn parse_lora(value: &Value) -> Option<InvokeAiLora> {
+    let model = value.get("model").or_else(|| value.get("lora"))?;
+    Some(InvokeAiLora {
+        name: model_name(model)?,
+        weight: value.get("weight").and_then(Value::as_f64),
+    })
+}
+
+// 模型可能是字符串，也可能是 `{"name": ...}` (4.x 以后) 或 `{"model_name": ...}` (3.x)
+fn model_name(value: &Value) -> Option<String> {
+    match value {
+        Value::String(name) => Some(name.clone()),
+        Value::Object(model) => {
+            get_string(model, "name").or_else(|| get_string(model, "model_name"))
+        }
+        _ => None,
+    }
+}
+
+fn array<'a>(object: &'a Map<String, Value>, key: &str) -> impl Iterator<Item = &'a Value> {
+    object
+        .get(key)
+        .and_then(Value::as_array)
+        .into_iter()
+        .flatten()
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+    use crate::test_fixtures::make_text_chunk;
+
+    fn extract(chunks: &[(&str, Value)]) -> InvokeAiMetadata {
+        let chunks: Vec<PngTextChunk> = chunks
+            .iter()
+            .map(|(keyword, value)| {
+                let text = match value {
+                    Value::String(text) => text.clone(),
+                    value => value.to_string(),
+                };
+                make_text_chunk(keyword, &text)
+            })
+            .collect();
+        extract_invokeai_metadata(&chunks).unwrap()
+    }
+
+    fn core_metadata() -> Value {
+        json!({
+            "app_version": "4.2.9",
+            "generation_mode": "sdxl_txt2img",
+            "positive_prompt": "a cat",
+            "negative_prompt": "lowres",
+            "seed": 42,
+            "steps": 30,
+            "cfg_scale": 6.5,
+            "scheduler": "dpmpp_2m",
+            "width": 1024,
+            "height": 768,
+            "model": {"name": "sdxl", "hash": "blake3:abc"},
+            "loras": [
+                {"model": {"name": "detail"}, "weight": 0.75},
+                {"lora": {"model_name": "style"}, "weight": 0.5}
+            ],
+            "controlnets": [
+                {"control_model": {"name": "canny"}, "control_weight": 0.8,
+                 "begin_step_percent": 0.0, "end_step_percent": 0.9}
+            ]
+        })
+    }
+
+    #[test]
+    fn metadata_chunk_is_parsed() {
+        let metadata = extract(&[("invokeai_metadata", core_metadata())]);
+        assert_eq!(metadata.app_version.as_deref(), Some("4.2.9"));
+        assert_eq!(metadata.generation_mode.as_deref(), Some("sdxl_txt2img"));
+        assert_eq!(metadata.positive_prompt.as_deref(), Some("a cat"));
+        assert_eq!(metadata.negative_prompt.as_deref(), Some("lowres"));
+        assert_eq!(metadata.seed, Some(42));
+        assert_eq!(metadata.steps, Some(30));
+        assert_eq!(metadata.cfg_scale, Some(6.5));
+        assert_eq!(metadata.scheduler.as_deref(), Some("dpmpp_2m"));
+        assert_eq!((metadata.width, metadata.height), (Some(1024), Some(768)));
+        assert_eq!(metadata.model.as_deref(), Some("sdxl"));
+        assert_eq!(metadata.model_hash.as_deref(), Some("blake3:abc"));
+        assert_eq!(
+            metadata.loras,
+            [
+                InvokeAiLora {
+                    name: "detail".to_string(),
+                    weight: Some(0.75)
+                },
+                InvokeAiLora {
+                    name: "style".to_string(),
+                    weight: Some(0.5)
+                }
+            ]
+        );
+        assert_eq!(
+            metadata.control_layers,
+            [InvokeAiControlLayer {
+                adapter_type: "controlnet".to_string(),
+                model: Some("canny".to_string()),
+                weight: Some(0.8),
+                begin_step_percent: Some(0.0),
+                end_step_percent: Some(0.9),
+                is_enabled: true,
+            }]
+        );
+        assert!(metadata.metadata.is_some());
+        assert_eq!(metadata.graph, None);
+    }
+
+    #[test]
+    fn core_metadata_node_is_used_without_metadata_chunk() {
+        let mut node = core_metadata();
+        node["id"] = "core_metadata".into();
+        node["type"] = "core_metadata".into();
+        let graph = json!({"nodes": {"core_metadata": node}, "edges": []});
+        let metadata = extract(&[("invokeai_graph", graph)]);
+        assert_eq!(metadata.positive_prompt.as_deref(), Some("a cat"));
+        assert_eq!(metadata.seed, Some(42));
+        assert_eq!(metadata.loras.len(), 2);
+        assert_eq!(metadata.metadata, None);
+        assert!(metadata.graph.is_some());
+    }
+
+    #[test]
+    fn graph_without_metadata_node_is_summarized() {
+        let graph = json!({
+            "nodes": [
+                {"id": "positive", "type": "compel", "prompt": "a cat"},
+                {"id": "negative", "type": "compel", "prompt": "lowres"},
+                {"id": "noise", "type": "noise", "seed": 7, "width": 512, "height": 640},
+                {"id": "denoise", "type": "denoise_latents", "steps": 20, "cfg_scale": 7.5,
+                 "scheduler": "euler"},
+                {"id": "loader", "type": "main_model_loader", "model": {"name": "sd15"}}
+            ],
+            "edges": [
+                {"source": {"node_id": "negative", "field": "conditioning"},
+                 "destination": {"node_id": "denoise", "field": "negative_conditioning"}},
+                {"source": {"node_id": "positive", "field": "conditioning"},
+                 "destination": {"node_id": "denoise", "field": "positive_conditioning"}}
+            ]
+        });
+        let metadata = extract(&[("invokeai_graph", graph)]);
+        assert_eq!(metadata.positive_prompt.as_deref(), Some("a cat"));
+        assert_eq!(metadata.negative_prompt.as_deref(), Some("lowres"));
+        assert_eq!(metadata.seed, Some(7));
+        assert_eq!((metadata.width, metadata.height), (Some(512), Some(640)));
+        assert_eq!(metadata.steps, Some(20));
+        assert_eq!(metadata.cfg_scale, Some(7.5));
+        assert_eq!(metadata.scheduler.as_deref(), Some("euler"));
+        assert_eq!(metadata.model.as_deref(), Some("sd15"));
+    }
+
+    #[test]
+    fn legacy_sd_metadata_is_parsed() {
+        let legacy = json!({
+            "model": "stable diffusion",
+            "model_weights": "stable-diffusion-1.5",
+            "model_hash": "cc6cb27103417325ff94f52b7a5d2dde45a7515b25c255d8e396c90014281516",
+            "app_id": "invoke-ai/InvokeAI",
+            "app_version": "2.3.0",
+            "image": {
+                "prompt": [{"prompt": "a cat [lowres, blurry]", "weight": 1.0}],
+                "steps": 50,
+                "cfg_scale": 7.5,
+                "height": 512,
+                "width": 512,
+                "seed": 3357757885_u64,
+                "type": "txt2img",
+                "sampler": "k_lms"
+            }
+        });
+        let metadata = extract(&[("sd-metadata", legacy)]);
+        assert_eq!(metadata.positive_prompt.as_deref(), Some("a cat"));
+        assert_eq!(metadata.negative_prompt.as_deref(), Some("lowres, blurry"));
+        assert_eq!(metadata.seed, Some(3357757885));
+        assert_eq!(metadata.steps, Some(50));
+        assert_eq!(metadata.cfg_scale, Some(7.5));
+        assert_eq!(metadata.scheduler.as_deref(), Some("k_lms"));
+        assert_eq!(metadata.generation_mode.as_deref(), Some("txt2img"));
+        assert_eq!(metadata.app_version.as_deref(), Some("2.3.0"));
+        assert_eq!(metadata.model.as_deref(), Some("stable-diffusion-1.5"));
+        assert!(metadata.model_hash.unwrap().starts_with("cc6cb271"));
+        assert_eq!(metadata.metadata, None);
+    }
+
+    #[test]
+    fn dream_command_line_is_parsed() {
+        let dream = r#""a [[nested]] cat [lowres]" -s 50 -S 42 -W 512 -H 768 -C 7.5 -A k_euler_a"#;
+        let metadata = extract(&[("Dream", dream.into())]);
+        assert_eq!(metadata.positive_prompt.as_deref(), Some("a cat"));
+        assert_eq!(
+            metadata.negative_prompt.as_deref(),
+            Some("[nested], lowres")
+        );
+        assert_eq!(metadata.steps, Some(50));
+        assert_eq!(metadata.seed, Some(42));
+        assert_eq!((metadata.width, metadata.height), (Some(512), Some(768)));
+        assert_eq!(metadata.cfg_scale, Some(7.5));
+        assert_eq!(metadata.scheduler.as_deref(), Some("k_euler_a"));
+    }
+
+    #[test]
+    fn canvas_layers_are_parsed_and_deduplicated() {
+        let mut fields = core_metadata();
+        fields["canvas_v2_metadata"] = json!({
+            "regionalGuidance": [
+                {"name": "sky", "positivePrompt": "blue sky", "negativePrompt": "",
+                 "isEnabled": false}
+            ],
+            "controlLayers": [
+                {"isEnabled": true, "controlAdapter": {"type": "controlnet",
+                 "model": {"name": "canny"}, "weight": 0.8, "beginEndStepPct": [0.0, 0.9]}},
+                {"isEnabled": false, "controlAdapter": {"type": "t2i_adapter",
+                 "model": {"name": "depth"}, "weight": 1.0, "beginEndStepPct": [0.1, 1.0]}}
+            ],
+            "referenceImages": [
+                {"ipAdapter": {"model": {"name": "ip"}, "weight": 0.5}}
+            ]
+        });
+        let metadata = extract(&[("invokeai_metadata", fields)]);
+        assert_eq!(
+            metadata.regional_prompts,
+            [InvokeAiRegionalPrompt {
+                name: Some("sky".to_string()),
+                positive_prompt: Some("blue sky".to_string()),
+                negative_prompt: None,
+                is_enabled: false,
+            }]
+        );
+        let layers: Vec<_> = metadata
+            .control_layers
+            .iter()
+            .map(|layer| {
+                (
+                    layer.adapter_type.as_str(),
+                    layer.model.as_deref(),
+                    layer.begin_step_percent,
+                    layer.is_enabled,
+                )
+            })
+            .collect();
+        // 顶层参数中已有的 canny 不重复添加
+        assert_eq!(
+            layers,
+            [
+                ("controlnet", Some("canny"), Some(0.0), true),
+                ("t2i_adapter", Some("depth"), Some(0.1), false),
+                ("ip_adapter", Some("ip"), None, true),
+            ]
+        );
+    }
+
+    #[test]
+    fn legacy_control_layers_are_parsed() {
+        let fields = json!({
+            "control_layers": {"layers": [
+                {"type": "regional_guidance_layer", "positivePrompt": "red hat"},
+                {"type": "ip_adapter_layer", "ipAdapter": {"type": "ip_adapter",
+                 "model": {"name": "ip"}, "weight": 0.6}}
+            ]}
+        });
+        let metadata = extract(&[("invokeai_metadata", fields)]);
+        assert_eq!(metadata.regional_prompts.len(), 1);
+        assert_eq!(
+            metadata.regional_prompts[0].positive_prompt.as_deref(),
+            Some("red hat")
+        );
+        assert!(metadata.regional_prompts[0].is_enabled);
+        assert_eq!(metadata.control_layers[0].weight, Some(0.6));
+    }
+
+    #[test]
+    fn other_chunks_are_not_invokeai() {
+        let chunks = [make_text_chunk("parameters", "a cat")];
+        assert_eq!(extract_invokeai_metadata(&chunks), None);
+    }
+}
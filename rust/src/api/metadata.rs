@@ -1,147 +1,1235 @@
-use std::io::{Cursor, Read};
+use std::io::{Cursor, Read, Write};
 
 use anyhow::{anyhow, Error};
-use flate2::read::GzDecoder;
-use flutter_rust_bridge::frb;
-use image::GenericImageView;
+use base64::prelude::{Engine, BASE64_STANDARD};
+use ed25519_dalek::{Signature, VerifyingKey};
+use flate2::read::{GzDecoder, ZlibDecoder};
+use flate2::write::GzEncoder;
+use flate2::Compression;
+use image::{DynamicImage, GenericImageView, ImageFormat, ImageReader};
+use serde_json::Value;
+use sha2::{Digest, Sha256};
+
+use crate::api::a1111::{parse_a1111_parameters, A1111Parameters};
+use crate::api::c2pa::{read_c2pa_manifests, C2paManifest};
+use crate::api::comfyui::{extract_comfyui_metadata, ComfyUiMetadata};
+use crate::api::error::{MetadataError, MetadataErrorKind};
+use crate::api::fooocus::parse_fooocus_parameters;
+use crate::api::generator::{detect_generator, Generator};
+use crate::api::invokeai::{extract_invokeai_metadata, InvokeAiMetadata};
+use crate::api::nai::{parse_nai_metadata, parse_nai_text_chunks, NaiMetadata};
+use crate::api::networks::{collect_network_references, NetworkReference};
+use crate::api::swarmui::parse_swarmui_parameters;
+use crate::api::writer::{jpeg_segments, JPEG_APP1};
+use crate::api::xmp::{parse_xmp, XmpMetadata, XMP_HEADER, XMP_KEYWORD};
+use crate::fields::text_chunk;
 
 pub struct ImageInfo {
     pub aspect_ratio: f64,
+    pub generator: Generator,
     pub metadata_string: Option<String>,
+    pub nai_metadata: Option<NaiMetadata>,
+    pub a1111_parameters: Option<A1111Parameters>,
+    /// Fooocus 和 SwarmUI 的参数，转换为与 A1111 相同的字段，原始键值对保存在 `settings` 中
+    pub fooocus_parameters: Option<A1111Parameters>,
+    pub swarmui_parameters: Option<A1111Parameters>,
+    pub comfyui: Option<ComfyUiMetadata>,
+    pub invokeai: Option<InvokeAiMetadata>,
+    /// 从 A1111 参数和 ComfyUI 节点图中汇总的 LoRA、嵌入和超网络
+    pub networks: Vec<NetworkReference>,
+    pub text_chunks: Vec<PngTextChunk>,
+    /// PNG `XML:com.adobe.xmp` 文本块、JPEG APP1 段或 WebP `XMP ` 块中的原始 XMP 数据
+    pub xmp_packet: Option<String>,
+    pub xmp: Option<XmpMetadata>,
+    /// C2PA 内容凭据 (DALL·E、Adobe Firefly 等)，最后一个是当前图片的清单
+    pub c2pa_manifests: Vec<C2paManifest>,
+    /// 找到 stealth magic 但隐写数据损坏时的错误类别和描述，其余字段仍然来自文本块和 EXIF
+    pub stealth_error_kind: Option<MetadataErrorKind>,
+    pub stealth_error_message: Option<String>,
+}
+
+pub enum PngTextChunkType {
+    /// tEXt
+    Text,
+    /// zTXt
+    CompressedText,
+    /// iTXt
+    InternationalText,
+}
+
+pub struct PngTextChunk {
+    pub chunk_type: PngTextChunkType,
+    pub keyword: String,
+    pub text: String,
+    /// 仅 iTXt 块包含语言标签和翻译后的关键字
+    pub language_tag: Option<String>,
+    pub translated_keyword: Option<String>,
 }
 
 #[flutter_rust_bridge::frb(sync)]
 pub fn extract_metadata(input_bytes: &[u8]) -> Result<ImageInfo, Error> {
-    let exif_info = extract_general_info(input_bytes)?;
-    let nai_metadata_result = extract_nai_data(input_bytes);
-    if nai_metadata_result.is_ok() {
-        // Use NAI metadata string first
-        return Ok(ImageInfo {
-            aspect_ratio: exif_info.aspect_ratio,
-            metadata_string: nai_metadata_result.ok(),
+    Ok(read_image_info(input_bytes)?)
+}
+
+/// 与 [`extract_metadata`] 相同，但返回具体的错误类型，供扫描时区分失败原因
+pub(crate) fn read_image_info(input_bytes: &[u8]) -> Result<ImageInfo, MetadataError> {
+    // 宽高只读取文件头，不解码像素
+    let dimensions = ImageReader::new(Cursor::new(input_bytes))
+        .with_guessed_format()
+        .map_err(|error| MetadataError::Decode(error.to_string()))?
+        .into_dimensions()?;
+    let mut exif_info = extract_general_info(input_bytes, dimensions)?;
+
+    // 只有无损格式才可能保存隐写数据，此时才解码一次像素
+    if may_contain_stealth_data(input_bytes) {
+        let img = image::load_from_memory(input_bytes)?;
+        // magic 不匹配时视为没有隐写数据，magic 匹配但数据损坏时记录错误，仍然使用文本块和 EXIF
+        let nai_data = extract_nai_data(&img).unwrap_or_else(|error| {
+            exif_info.stealth_error_kind = Some(error.kind());
+            exif_info.stealth_error_message = Some(error.to_string());
+            None
         });
+        if let Some(nai_data) = nai_data {
+            // Use NAI metadata string first
+            let mut info = ImageInfo {
+                nai_metadata: parse_nai_metadata(&nai_data),
+                metadata_string: Some(nai_data),
+                a1111_parameters: None,
+                fooocus_parameters: None,
+                swarmui_parameters: None,
+                generator: Generator::NovelAi,
+                ..exif_info
+            };
+            info.networks = collect_network_references(&info);
+            return Ok(info);
+        }
     }
     // Or fallback to EXIF metadata
-    return Ok(exif_info);
+    Ok(exif_info)
+}
+
+// JPEG、有损 WebP 等格式会破坏最低位，不需要检查隐写数据
+pub(crate) fn may_contain_stealth_data(input_bytes: &[u8]) -> bool {
+    match infer::get(input_bytes).map(|kind| kind.mime_type()) {
+        Some("image/png") => true,
+        Some("image/webp") => webp_chunks(input_bytes).any(|(fourcc, _)| &fourcc == b"VP8L"),
+        _ => false,
+    }
+}
+
+/// 隐写数据所在的通道: alpha 每个像素 1 bit，RGB 每个像素 3 bit
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum StealthChannel {
+    Alpha,
+    Rgb,
+}
+
+// stealth-pnginfo 的四种模式，magic 长度都是 15 字节
+const STEALTH_MODES: [(&str, StealthChannel, bool); 4] = [
+    ("stealth_pnginfo", StealthChannel::Alpha, false),
+    ("stealth_pngcomp", StealthChannel::Alpha, true),
+    ("stealth_rgbinfo", StealthChannel::Rgb, false),
+    ("stealth_rgbcomp", StealthChannel::Rgb, true),
+];
+const STEALTH_MAGIC_LEN: usize = 15;
+
+// 声明的隐写数据长度和 gzip 解压后的长度上限，NAI 的实际数据只有几 KB
+const MAX_STEALTH_PAYLOAD_SIZE: usize = 4 * 1024 * 1024;
+const MAX_STEALTH_DECOMPRESSED_SIZE: u64 = 16 * 1024 * 1024;
+
+fn extract_nai_data(img: &DynamicImage) -> Result<Option<String>, MetadataError> {
+    let Some((mut reader, compressed)) = find_stealth_payload(img) else {
+        return Ok(None);
+    };
+
+    // 长度以 bit 为单位
+    let data_length = reader.read_int32()?;
+    let payload_size = usize::try_from(data_length).map_err(|_| {
+        MetadataError::InvalidStealthPayload(format!("negative length {data_length}"))
+    })? / 8;
+    if payload_size > MAX_STEALTH_PAYLOAD_SIZE {
+        return Err(MetadataError::OversizedStealthPayload(payload_size as u64));
+    }
+    let payload = reader.read_bytes(payload_size)?;
+    let payload = if compressed {
+        let mut decompressed = vec![];
+        GzDecoder::new(payload.as_slice())
+            .take(MAX_STEALTH_DECOMPRESSED_SIZE + 1)
+            .read_to_end(&mut decompressed)
+            .map_err(|error| MetadataError::InvalidStealthPayload(error.to_string()))?;
+        if decompressed.len() as u64 > MAX_STEALTH_DECOMPRESSED_SIZE {
+            return Err(MetadataError::OversizedStealthPayload(
+                decompressed.len() as u64
+            ));
+        }
+        decompressed
+    } else {
+        payload
+    };
+    String::from_utf8(payload)
+        .map(Some)
+        .map_err(|error| MetadataError::InvalidStealthPayload(error.to_string()))
+}
+
+// 依次检查各通道的 magic，返回已读过 magic 的 reader 以及数据是否经过压缩
+fn find_stealth_payload(img: &DynamicImage) -> Option<(StealthReader<'_>, bool)> {
+    // 没有 alpha 通道的图片只可能使用 RGB 模式
+    let channels: &[StealthChannel] = if img.color().has_alpha() {
+        &[StealthChannel::Alpha, StealthChannel::Rgb]
+    } else {
+        &[StealthChannel::Rgb]
+    };
+
+    channels.iter().find_map(|&channel| {
+        let mut reader = StealthReader::new(img, channel);
+        // 先只读取 magic，不匹配时立即放弃，不再遍历其余像素
+        let magic_string = reader.read_bytes(STEALTH_MAGIC_LEN).ok()?;
+        let &(_, _, compressed) = STEALTH_MODES.iter().find(|(magic, mode_channel, _)| {
+            *mode_channel == channel && magic.as_bytes() == magic_string
+        })?;
+        Some((reader, compressed))
+    })
+}
+
+/// 返回带有 stealth magic 的通道，没有隐写数据时返回 `None`
+pub(crate) fn find_stealth_channel(img: &DynamicImage) -> Option<StealthChannel> {
+    find_stealth_payload(img).map(|(reader, _)| reader.channel)
 }
 
-#[frb(opaque)]
-pub struct DataReader {
-    data: Vec<u8>,
-    index: usize,
+/// 按列优先顺序逐个读取像素最低位，只访问实际需要的像素
+struct StealthReader<'a> {
+    img: &'a DynamicImage,
+    channel: StealthChannel,
+    x: u32,
+    y: u32,
+    // RGB 模式下当前像素内的分量序号
+    component: usize,
 }
 
-impl DataReader {
-    pub fn new(data: Vec<u8>) -> DataReader {
-        DataReader { data, index: 0 }
+impl<'a> StealthReader<'a> {
+    fn new(img: &'a DynamicImage, channel: StealthChannel) -> Self {
+        StealthReader {
+            img,
+            channel,
+            x: 0,
+            y: 0,
+            component: 0,
+        }
+    }
+
+    // 剩余可读取的 bit 数
+    fn remaining_bits(&self) -> usize {
+        let (width, height) = self.img.dimensions();
+        let components = match self.channel {
+            StealthChannel::Alpha => 1,
+            StealthChannel::Rgb => 3,
+        };
+        let read =
+            (self.x as usize * height as usize + self.y as usize) * components + self.component;
+        (width as usize * height as usize * components).saturating_sub(read)
     }
 
-    pub fn read_bit(&mut self) -> u8 {
-        let bit = self.data[self.index] & 1; // 只读取最低位
-        self.index += 1;
-        bit
+    fn read_bit(&mut self) -> Result<u8, MetadataError> {
+        let (width, height) = self.img.dimensions();
+        if self.x >= width || height == 0 {
+            return Err(MetadataError::TruncatedStealthPayload);
+        }
+        let pixel = self.img.get_pixel(self.x, self.y);
+        let (value, components) = match self.channel {
+            StealthChannel::Alpha => (pixel[3], 1),
+            StealthChannel::Rgb => (pixel[self.component], 3),
+        };
+
+        self.component += 1;
+        if self.component == components {
+            self.component = 0;
+            self.y += 1;
+            if self.y == height {
+                self.y = 0;
+                self.x += 1;
+            }
+        }
+        Ok(value & 1)
     }
 
-    pub fn read_byte(&mut self) -> u8 {
+    fn read_byte(&mut self) -> Result<u8, MetadataError> {
         let mut byte = 0;
         for i in 0..8 {
-            byte |= self.read_bit() << (7 - i);
+            byte |= self.read_bit()? << (7 - i);
         }
-        byte
+        Ok(byte)
     }
 
-    pub fn read_bytes(&mut self, n: usize) -> Vec<u8> {
+    fn read_bytes(&mut self, n: usize) -> Result<Vec<u8>, MetadataError> {
+        if n > self.remaining_bits() / 8 {
+            return Err(MetadataError::TruncatedStealthPayload);
+        }
         (0..n).map(|_| self.read_byte()).collect()
     }
 
-    pub fn read_int32(&mut self) -> i32 {
-        let bytes = self.read_bytes(4);
-        let bytes4: [u8; 4] = bytes.try_into().unwrap();
-        i32::from_be_bytes(bytes4)
+    fn read_int32(&mut self) -> Result<i32, MetadataError> {
+        let bytes = self.read_bytes(4)?;
+        Ok(i32::from_be_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]))
     }
 }
 
-fn extract_nai_data(input_bytes: &[u8]) -> Result<String, Error> {
+/// 把元数据 gzip 压缩后写入像素最低位 (`stealth_pngcomp` / `stealth_rgbcomp`)，返回 PNG 数据
+///
+/// 布局与读取时一致: 按列优先顺序依次写入 magic、以 bit 为单位的 32 位长度和压缩后的数据。
+/// 输出统一为 8 位 PNG，alpha 模式下没有 alpha 通道的图片会补上不透明的 alpha
+pub fn embed_stealth_metadata(
+    input_bytes: &[u8],
+    metadata: String,
+    channel: StealthChannel,
+) -> Result<Vec<u8>, Error> {
     let img = image::load_from_memory(input_bytes)?;
-    let (width, height) = img.dimensions();
 
-    let mut lowest_data = vec![];
+    let mut gz = GzEncoder::new(vec![], Compression::default());
+    gz.write_all(metadata.as_bytes())?;
+    let payload = gz.finish()?;
+    if payload.len() > MAX_STEALTH_PAYLOAD_SIZE {
+        return Err(anyhow!(
+            "Stealth payload is too large: {} bytes",
+            payload.len()
+        ));
+    }
+    let magic = match channel {
+        StealthChannel::Alpha => "stealth_pngcomp",
+        StealthChannel::Rgb => "stealth_rgbcomp",
+    };
+    let mut bytes = magic.as_bytes().to_vec();
+    bytes.extend_from_slice(&((payload.len() * 8) as i32).to_be_bytes());
+    bytes.extend_from_slice(&payload);
+
+    let (width, height) = img.dimensions();
+    let components = match channel {
+        StealthChannel::Alpha => 1,
+        StealthChannel::Rgb => 3,
+    };
+    let capacity = width as usize * height as usize * components;
+    if bytes.len() * 8 > capacity {
+        return Err(anyhow!(
+            "Image is too small for the stealth payload: {} bits needed, {capacity} available",
+            bytes.len() * 8
+        ));
+    }
 
-    for x in 0..width {
-        for y in 0..height {
-            let pixel = img.get_pixel(x, y);
-            let a = pixel[3]; // 获取 alpha 值
-            lowest_data.push(a);
+    let img = match (channel, img.color().has_alpha()) {
+        (StealthChannel::Rgb, false) => {
+            let mut buffer = img.to_rgb8();
+            write_stealth_bits(&mut buffer, (width, height), 3, 0..3, &bytes);
+            DynamicImage::ImageRgb8(buffer)
         }
+        (StealthChannel::Rgb, true) => {
+            let mut buffer = img.to_rgba8();
+            write_stealth_bits(&mut buffer, (width, height), 4, 0..3, &bytes);
+            DynamicImage::ImageRgba8(buffer)
+        }
+        (StealthChannel::Alpha, _) => {
+            let mut buffer = img.to_rgba8();
+            write_stealth_bits(&mut buffer, (width, height), 4, 3..4, &bytes);
+            DynamicImage::ImageRgba8(buffer)
+        }
+    };
+
+    let mut output = Cursor::new(vec![]);
+    img.write_to(&mut output, ImageFormat::Png)?;
+    Ok(output.into_inner())
+}
+
+// 按列优先顺序把每个 bit 写入像素中指定分量的最低位，调用前已确认容量足够
+fn write_stealth_bits(
+    pixels: &mut [u8],
+    (width, height): (u32, u32),
+    pixel_size: usize,
+    components: std::ops::Range<usize>,
+    bytes: &[u8],
+) {
+    let (width, height) = (width as usize, height as usize);
+    let positions = (0..width).flat_map(|x| {
+        let components = components.clone();
+        (0..height).flat_map(move |y| {
+            components
+                .clone()
+                .map(move |component| (y * width + x) * pixel_size + component)
+        })
+    });
+    let bits = bytes
+        .iter()
+        .flat_map(|byte| (0..8).rev().map(move |i| (byte >> i) & 1));
+    for (index, bit) in positions.zip(bits) {
+        pixels[index] = (pixels[index] & !1) | bit;
     }
+}
 
-    let mut reader = DataReader::new(lowest_data);
-    let magic = "stealth_pngcomp";
-    let magic_string = String::from_utf8(reader.read_bytes(magic.len()))
-        .unwrap_or("Magic number not found".to_string());
+/// NovelAI 图片签名的验证结果
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum NaiSignatureStatus {
+    /// 签名有效，像素和 Comment 与生成时一致
+    Valid,
+    /// 有签名但验证失败，像素或 Comment 被修改过
+    Tampered,
+    /// Comment 中没有 `signed_hash` (v3 之前的图片，或不是 NAI 生成的图片)
+    Unsigned,
+}
+
+// NovelAI 公布的验证公钥
+const NAI_VERIFY_KEY: &str = "Y2JcQAOhLwzwSDUJPNgL04nS0Tbqm7cSRc4xk0vRMic=";
 
-    if magic == magic_string {
-        let data_length = reader.read_int32() as usize;
-        let gzip_bytes = reader.read_bytes(data_length / 8);
-        let mut gz = GzDecoder::new(gzip_bytes.as_slice());
-        let mut decompressed_data = String::new();
-        gz.read_to_string(&mut decompressed_data)?;
-        Ok(decompressed_data)
+/// 离线验证 NovelAI 图片的 ed25519 签名
+///
+/// `signed_hash` 是对 sha256(RGB 像素 + 去掉 `signed_hash` 后的 Comment) 的签名。
+/// 不包含 alpha 通道，因此隐写数据不影响验证。Comment 优先读取 PNG 文本块，其次是隐写数据
+#[flutter_rust_bridge::frb(sync)]
+pub fn verify_nai_signature(input_bytes: &[u8]) -> Result<NaiSignatureStatus, Error> {
+    let img = image::load_from_memory(input_bytes)?;
+    let comment = text_chunk(&read_png_text_chunks(input_bytes), "Comment")
+        .map(str::to_string)
+        .or_else(|| {
+            let nai_data = extract_nai_data(&img).ok()??;
+            let root: Value = serde_json::from_str(&nai_data).ok()?;
+            root.get("Comment")?.as_str().map(str::to_string)
+        });
+    let signed_hash = comment.as_deref().and_then(|comment| {
+        let comment: Value = serde_json::from_str(comment).ok()?;
+        comment.get("signed_hash")?.as_str().map(str::to_string)
+    });
+    let (Some(comment), Some(signed_hash)) = (comment, signed_hash) else {
+        return Ok(NaiSignatureStatus::Unsigned);
+    };
+
+    let key = BASE64_STANDARD
+        .decode(NAI_VERIFY_KEY)
+        .ok()
+        .and_then(|bytes| VerifyingKey::from_bytes(&bytes.try_into().ok()?).ok())
+        .ok_or_else(|| anyhow!("无效的 NovelAI 公钥 (Invalid NovelAI verify key)"))?;
+    if nai_signature_matches(&img, &comment, &signed_hash, &key) {
+        Ok(NaiSignatureStatus::Valid)
     } else {
-        Err(Error::msg("Magic does not match"))
+        Ok(NaiSignatureStatus::Tampered)
     }
 }
 
-fn extract_general_info(input_bytes: &[u8]) -> Result<ImageInfo, Error> {
+fn nai_signature_matches(
+    img: &DynamicImage,
+    comment: &str,
+    signed_hash: &str,
+    key: &VerifyingKey,
+) -> bool {
+    let Some(signature) = BASE64_STANDARD
+        .decode(signed_hash)
+        .ok()
+        .and_then(|bytes| Signature::from_slice(&bytes).ok())
+    else {
+        return false;
+    };
+    let Some(unsigned_comment) = remove_signed_hash(comment, signed_hash) else {
+        return false;
+    };
+    let mut hasher = Sha256::new();
+    hasher.update(img.to_rgb8().as_raw());
+    hasher.update(unsigned_comment.as_bytes());
+    key.verify_strict(&hasher.finalize(), &signature).is_ok()
+}
+
+// NAI 的 Comment 由 Python `json.dumps` 生成，签名时使用的是删除 `signed_hash` 后重新序列化的文本。
+// base64 中没有需要转义的字符，直接从原文中删除这一项和相邻的分隔符即可得到相同的文本
+fn remove_signed_hash(comment: &str, signed_hash: &str) -> Option<String> {
+    let entry = format!("\"signed_hash\": \"{signed_hash}\"");
+    let start = comment.find(&entry)?;
+    let end = start + entry.len();
+    let (start, end) = if comment[end..].starts_with(", ") {
+        (start, end + 2)
+    } else if comment[..start].ends_with(", ") {
+        (start - 2, end)
+    } else {
+        (start, end)
+    };
+    Some(format!("{}{}", &comment[..start], &comment[end..]))
+}
+
+fn extract_general_info(
+    input_bytes: &[u8],
+    dimensions: (u32, u32),
+) -> Result<ImageInfo, MetadataError> {
     // 计算宽高比
-    let img = image::load_from_memory(input_bytes)?;
-    let (width, height) = img.dimensions();
+    let (width, height) = dimensions;
     if height == 0 {
-        return Err(anyhow!("图片高度为零 (Image height is zero)"));
+        return Err(MetadataError::InvalidDimensions(width, height));
     }
     let aspect_ratio = width as f64 / height as f64;
 
     // 使用 infer 推断文件类型，选择性地提取元数据
-    let kind =
-        infer::get(input_bytes).ok_or_else(|| anyhow!("无法识别的文件类型 (Unknown file type)"))?;
+    let kind = infer::get(input_bytes).ok_or(MetadataError::UnsupportedFormat)?;
     let mime_type = kind.mime_type();
 
+    let mut png_text_chunks = vec![];
+    let mut xmp_packet = None;
+    let mut exif_software = None;
     let metadata_string = match mime_type {
         // 专门处理 PNG，读取文本块
         "image/png" => {
-            let decoder = png::Decoder::new(Cursor::new(input_bytes));
-            decoder.read_info().ok().and_then(|reader| {
-                let info = reader.info();
-                info.utf8_text
-                    .iter()
-                    .find_map(|chunk| chunk.get_text().ok())
-                    .or_else(|| {
-                        info.uncompressed_latin1_text
-                            .iter()
-                            .map(|chunk| chunk.text.clone())
-                            .next()
+            png_text_chunks = read_png_text_chunks(input_bytes);
+            xmp_packet = png_text_chunks
+                .iter()
+                .find(|chunk| chunk.keyword == XMP_KEYWORD)
+                .map(|chunk| chunk.text.clone());
+            // 优先使用 InvokeAI 的生成参数 (与节点图的先后顺序不固定)，其次是 iTXt，最后是第一个 tEXt / zTXt。
+            // XMP 有单独的字段，与其他格式一样不作为 metadata_string
+            png_text_chunks
+                .iter()
+                .find(|chunk| chunk.keyword == "invokeai_metadata")
+                .or_else(|| {
+                    png_text_chunks.iter().find(|chunk| {
+                        matches!(chunk.chunk_type, PngTextChunkType::InternationalText)
+                            && chunk.keyword != XMP_KEYWORD
                     })
-            })
+                })
+                .or_else(|| {
+                    png_text_chunks
+                        .iter()
+                        .find(|chunk| chunk.keyword != XMP_KEYWORD)
+                })
+                .map(|chunk| chunk.text.clone())
         }
 
         // 专门处理 JPEG 和 TIFF，读取 EXIF
         "image/jpeg" | "image/tiff" => {
-            let mut cursor = Cursor::new(input_bytes);
-            exif::Reader::new()
-                .read_from_container(&mut cursor)
+            // JPEG 的 XMP 保存在以命名空间开头的 APP1 段中
+            xmp_packet = jpeg_segments(input_bytes)
                 .ok()
-                .and_then(|exif| {
-                    exif.get_field(exif::Tag::ImageDescription, exif::In::PRIMARY)
-                        .and_then(|field| Some(field.display_value().to_string()))
+                .into_iter()
+                .flatten()
+                .find_map(|(marker, data, _)| {
+                    data.strip_prefix(XMP_HEADER)
+                        .filter(|_| marker == JPEG_APP1)
                 })
+                .map(|packet| String::from_utf8_lossy(packet).to_string());
+            let mut cursor = Cursor::new(input_bytes);
+            let exif = exif::Reader::new().read_from_container(&mut cursor).ok();
+            exif_software = exif.as_ref().and_then(read_exif_software);
+            exif.as_ref().and_then(read_exif_text)
+        }
+
+        // WebP 的 EXIF 和 XMP 分别保存在 RIFF 的 `EXIF` 和 `XMP ` 块中
+        "image/webp" => {
+            let mut exif_text = None;
+            for (fourcc, data) in webp_chunks(input_bytes) {
+                match &fourcc {
+                    b"EXIF" => {
+                        // 部分工具会保留 JPEG APP1 的 `Exif\0\0` 前缀
+                        let tiff = data.strip_prefix(b"Exif\0\0").unwrap_or(data);
+                        let exif = exif::Reader::new().read_raw(tiff.to_vec()).ok();
+                        exif_software = exif.as_ref().and_then(read_exif_software);
+                        exif_text = exif.as_ref().and_then(read_exif_text);
+                    }
+                    b"XMP " => xmp_packet = Some(String::from_utf8_lossy(data).to_string()),
+                    _ => {}
+                }
+            }
+            exif_text
         }
 
-        // 其他支持的格式 (WEBP, GIF, BMP 等) 通常没有标准化的文本元数据字段，只返回宽高比
+        // 其他支持的格式 (GIF, BMP 等) 通常没有标准化的文本元数据字段，只返回宽高比
         _ => None,
     };
 
-    Ok(ImageInfo {
+    // A1111 / Forge 的 parameters 文本，PNG 中优先使用同名文本块
+    let a1111_parameters = text_chunk(&png_text_chunks, "parameters")
+        .or(metadata_string.as_deref())
+        .and_then(parse_a1111_parameters);
+
+    let fooocus_parameters = parse_fooocus_parameters(
+        &png_text_chunks,
+        metadata_string.as_deref(),
+        a1111_parameters.as_ref(),
+    );
+    let swarmui_parameters = parse_swarmui_parameters(&png_text_chunks, metadata_string.as_deref());
+
+    let mut info = ImageInfo {
         aspect_ratio,
+        generator: Generator::Unknown,
         metadata_string,
+        // 有隐写数据时在 read_image_info 中替换
+        nai_metadata: parse_nai_text_chunks(&png_text_chunks),
+        a1111_parameters,
+        fooocus_parameters,
+        swarmui_parameters,
+        comfyui: extract_comfyui_metadata(&png_text_chunks),
+        invokeai: extract_invokeai_metadata(&png_text_chunks),
+        networks: vec![],
+        text_chunks: png_text_chunks,
+        xmp: xmp_packet.as_deref().and_then(parse_xmp),
+        xmp_packet,
+        c2pa_manifests: read_c2pa_manifests(input_bytes),
+        stealth_error_kind: None,
+        stealth_error_message: None,
+    };
+    info.generator = detect_generator(&info, input_bytes, exif_software.as_deref());
+    info.networks = collect_network_references(&info);
+    Ok(info)
+}
+
+// XPComment (Windows 资源管理器的“备注”)，kamadak-exif 没有预定义这个标签
+pub(crate) const TAG_XP_COMMENT: exif::Tag = exif::Tag(exif::Context::Tiff, 0x9c9c);
+
+/// 读取 EXIF 中的文本描述
+///
+/// 优先级: UserComment (A1111 / Forge 写入参数的位置) > ImageDescription > XPComment
+fn read_exif_text(exif: &exif::Exif) -> Option<String> {
+    let field_value = |tag: exif::Tag| {
+        exif.get_field(tag, exif::In::PRIMARY)
+            .map(|field| &field.value)
+    };
+    let user_comment = match field_value(exif::Tag::UserComment) {
+        Some(exif::Value::Undefined(bytes, _)) => decode_user_comment(bytes, exif.little_endian()),
+        _ => None,
+    };
+    let image_description = || match field_value(exif::Tag::ImageDescription) {
+        Some(exif::Value::Ascii(lines)) => non_empty_text(
+            &lines
+                .iter()
+                .map(|line| String::from_utf8_lossy(line))
+                .collect::<Vec<_>>()
+                .join("\n"),
+        ),
+        _ => None,
+    };
+    // XPComment 固定为 UTF-16LE，以 BYTE 类型存储
+    let xp_comment = || match field_value(TAG_XP_COMMENT) {
+        Some(exif::Value::Byte(bytes)) => non_empty_text(&decode_utf16_with(bytes, true)),
+        _ => None,
+    };
+    user_comment.or_else(image_description).or_else(xp_comment)
+}
+
+// EXIF 的 Software 标签 (0x0131)，JPEG / WebP 中代替 PNG 的 `Software` 文本块
+fn read_exif_software(exif: &exif::Exif) -> Option<String> {
+    match &exif
+        .get_field(exif::Tag::Software, exif::In::PRIMARY)?
+        .value
+    {
+        exif::Value::Ascii(lines) => non_empty_text(&String::from_utf8_lossy(lines.first()?)),
+        _ => None,
+    }
+}
+
+/// 解码 UserComment，前 8 个字节是字符集标识
+///
+/// - `ASCII\0\0\0`: 规范要求 ASCII，但很多工具直接写入 UTF-8
+/// - `UNICODE\0`: UTF-16，字节序见 [`decode_utf16`]
+/// - `JIS\0\0\0\0\0`: 含转义序列时按 ISO-2022-JP，否则按 Shift_JIS
+/// - 8 个 `\0`: 未定义字符集，按 UTF-8 处理
+///
+/// 没有可识别前缀时整个值按 UTF-8 处理
+fn decode_user_comment(bytes: &[u8], little_endian: bool) -> Option<String> {
+    let text = match bytes.split_at_checked(8) {
+        Some((b"ASCII\0\0\0", payload)) | Some((b"\0\0\0\0\0\0\0\0", payload)) => {
+            String::from_utf8_lossy(payload).to_string()
+        }
+        Some((b"UNICODE\0", payload)) => decode_utf16(payload, little_endian),
+        Some((b"JIS\0\0\0\0\0", payload)) => {
+            let encoding = if payload.contains(&0x1b) {
+                encoding_rs::ISO_2022_JP
+            } else {
+                encoding_rs::SHIFT_JIS
+            };
+            encoding.decode_without_bom_handling(payload).0.to_string()
+        }
+        _ => String::from_utf8_lossy(bytes).to_string(),
+    };
+    non_empty_text(&text)
+}
+
+fn non_empty_text(text: &str) -> Option<String> {
+    let text = text.trim_end_matches('\0');
+    if text.trim().is_empty() {
+        None
+    } else {
+        Some(text.to_string())
+    }
+}
+
+// A1111 (piexif) 总是写入大端 UTF-16，与 TIFF 字节序无关，因此优先根据 BOM 和内容判断
+fn decode_utf16(payload: &[u8], little_endian: bool) -> String {
+    match payload {
+        [0xFE, 0xFF, rest @ ..] => decode_utf16_with(rest, false),
+        [0xFF, 0xFE, rest @ ..] => decode_utf16_with(rest, true),
+        _ => {
+            // ASCII 字符在大端编码中高位字节为 0，出现在偶数位置
+            let zeros_at = |parity: usize| {
+                payload
+                    .iter()
+                    .skip(parity)
+                    .step_by(2)
+                    .filter(|&&byte| byte == 0)
+                    .count()
+            };
+            let (even, odd) = (zeros_at(0), zeros_at(1));
+            let little_endian = if even == odd {
+                little_endian
+            } else {
+                odd > even
+            };
+            decode_utf16_with(payload, little_endian)
+        }
+    }
+}
+
+fn decode_utf16_with(payload: &[u8], little_endian: bool) -> String {
+    let units: Vec<u16> = payload
+        .chunks_exact(2)
+        .map(|pair| {
+            if little_endian {
+                u16::from_le_bytes([pair[0], pair[1]])
+            } else {
+                u16::from_be_bytes([pair[0], pair[1]])
+            }
+        })
+        .collect();
+    String::from_utf16_lossy(&units)
+}
+
+/// 遍历 WebP (RIFF) 数据块，返回 FourCC 和块数据
+pub(crate) fn webp_chunks(input_bytes: &[u8]) -> impl Iterator<Item = ([u8; 4], &[u8])> {
+    let is_webp =
+        input_bytes.len() >= 12 && &input_bytes[0..4] == b"RIFF" && &input_bytes[8..12] == b"WEBP";
+    let mut offset = if is_webp { 12 } else { input_bytes.len() };
+    std::iter::from_fn(move || {
+        let header = input_bytes.get(offset..offset.checked_add(8)?)?;
+        let fourcc = [header[0], header[1], header[2], header[3]];
+        let size = u32::from_le_bytes([header[4], header[5], header[6], header[7]]) as usize;
+        let data_start = offset + 8;
+        let data = input_bytes.get(data_start..data_start.checked_add(size)?)?;
+        // 奇数长度的块后面有一个填充字节
+        offset = data_start + size + (size & 1);
+        Some((fourcc, data))
+    })
+}
+
+pub(crate) const PNG_SIGNATURE: [u8; 8] = [0x89, b'P', b'N', b'G', 0x0D, 0x0A, 0x1A, 0x0A];
+
+// 单个压缩文本块解压后的大小上限
+const MAX_TEXT_CHUNK_SIZE: u64 = 16 * 1024 * 1024;
+
+// 按文件顺序读取全部 tEXt / zTXt / iTXt 块，包括位于 IDAT 之后的块
+fn read_png_text_chunks(input_bytes: &[u8]) -> Vec<PngTextChunk> {
+    png_chunks(input_bytes)
+        .filter_map(|(chunk_type, data)| match &chunk_type {
+            b"tEXt" => decode_text_chunk(data),
+            b"zTXt" => decode_compressed_text_chunk(data),
+            b"iTXt" => decode_international_text_chunk(data),
+            _ => None,
+        })
+        .collect()
+}
+
+/// 遍历 PNG 数据块，返回块类型和块数据 (不校验 CRC)
+pub(crate) fn png_chunks(input_bytes: &[u8]) -> impl Iterator<Item = ([u8; 4], &[u8])> {
+    let mut offset = if input_bytes.starts_with(&PNG_SIGNATURE) {
+        PNG_SIGNATURE.len()
+    } else {
+        input_bytes.len()
+    };
+    std::iter::from_fn(move || {
+        let header = input_bytes.get(offset..offset.checked_add(8)?)?;
+        let length = u32::from_be_bytes([header[0], header[1], header[2], header[3]]) as usize;
+        let chunk_type = [header[4], header[5], header[6], header[7]];
+        let data_start = offset + 8;
+        let data = input_bytes.get(data_start..data_start.checked_add(length)?)?;
+        // 数据之后还有 4 字节 CRC，IEND 之后的附加数据不属于 PNG，不再按数据块解析
+        offset = if &chunk_type == b"IEND" {
+            input_bytes.len()
+        } else {
+            data_start + length + 4
+        };
+        Some((chunk_type, data))
+    })
+}
+
+fn decode_text_chunk(data: &[u8]) -> Option<PngTextChunk> {
+    let (keyword, text) = split_null(data)?;
+    Some(PngTextChunk {
+        chunk_type: PngTextChunkType::Text,
+        keyword: latin1_to_string(keyword),
+        text: latin1_to_string(text),
+        language_tag: None,
+        translated_keyword: None,
     })
 }
+
+fn decode_compressed_text_chunk(data: &[u8]) -> Option<PngTextChunk> {
+    let (keyword, rest) = split_null(data)?;
+    // 第一个字节是压缩方式，目前只定义了 0 (zlib)
+    let (&method, compressed) = rest.split_first()?;
+    if method != 0 {
+        return None;
+    }
+    let text = inflate(compressed)?;
+    Some(PngTextChunk {
+        chunk_type: PngTextChunkType::CompressedText,
+        keyword: latin1_to_string(keyword),
+        text: latin1_to_string(&text),
+        language_tag: None,
+        translated_keyword: None,
+    })
+}
+
+fn decode_international_text_chunk(data: &[u8]) -> Option<PngTextChunk> {
+    let (keyword, rest) = split_null(data)?;
+    let [compression_flag, _compression_method, rest @ ..] = rest else {
+        return None;
+    };
+    let (language_tag, rest) = split_null(rest)?;
+    let (translated_keyword, text) = split_null(rest)?;
+    let text = if *compression_flag == 1 {
+        String::from_utf8(inflate(text)?).ok()?
+    } else {
+        String::from_utf8(text.to_vec()).ok()?
+    };
+    let non_empty = |bytes: &[u8]| {
+        Some(String::from_utf8_lossy(bytes).to_string()).filter(|value| !value.is_empty())
+    };
+    Some(PngTextChunk {
+        chunk_type: PngTextChunkType::InternationalText,
+        keyword: latin1_to_string(keyword),
+        text,
+        language_tag: non_empty(language_tag),
+        translated_keyword: non_empty(translated_keyword),
+    })
+}
+
+fn split_null(data: &[u8]) -> Option<(&[u8], &[u8])> {
+    let index = data.iter().position(|&byte| byte == 0)?;
+    Some((&data[..index], &data[index + 1..]))
+}
+
+fn latin1_to_string(bytes: &[u8]) -> String {
+    bytes.iter().map(|&byte| byte as char).collect()
+}
+
+fn inflate(compressed: &[u8]) -> Option<Vec<u8>> {
+    let mut decompressed = vec![];
+    ZlibDecoder::new(compressed)
+        .take(MAX_TEXT_CHUNK_SIZE)
+        .read_to_end(&mut decompressed)
+        .ok()?;
+    Some(decompressed)
+}
+
+#[cfg(test)]
+mod tests {
+    use proptest::prelude::*;
+
+    use super::*;
+    use crate::api::writer::rewrite_metadata;
+    use crate::test_fixtures::{
+        append_webp_chunk, insert_jpeg_segment, make_text_chunk, source_image, NAI_JSON,
+    };
+
+    // 按列优先顺序把数据写入 alpha 最低位，像素不够时截断
+    fn stealth_png(width: u32, height: u32, bytes: &[u8]) -> Vec<u8> {
+        let mut pixels = vec![0xff; (width * height * 4) as usize];
+        let bits = bytes
+            .iter()
+            .flat_map(|byte| (0..8).rev().map(move |i| (byte >> i) & 1));
+        let positions = (0..width).flat_map(|x| (0..height).map(move |y| (x, y)));
+        for ((x, y), bit) in positions.zip(bits) {
+            let alpha = ((y * width + x) * 4 + 3) as usize;
+            pixels[alpha] = (pixels[alpha] & !1) | bit;
+        }
+
+        let mut output = vec![];
+        let mut encoder = png::Encoder::new(&mut output, width, height);
+        encoder.set_color(png::ColorType::Rgba);
+        let mut writer = encoder.write_header().unwrap();
+        writer.write_image_data(&pixels).unwrap();
+        writer.finish().unwrap();
+        output
+    }
+
+    fn stealth_bytes(magic: &str, declared_bits: i32, payload: &[u8]) -> Vec<u8> {
+        let mut bytes = magic.as_bytes().to_vec();
+        bytes.extend_from_slice(&declared_bits.to_be_bytes());
+        bytes.extend_from_slice(payload);
+        bytes
+    }
+
+    fn decode(png: &[u8]) -> Result<Option<String>, MetadataError> {
+        extract_nai_data(&image::load_from_memory(png).unwrap())
+    }
+
+    #[test]
+    fn gzip_bomb_is_rejected() {
+        let mut gz = GzEncoder::new(vec![], Compression::best());
+        gz.write_all(&vec![b'a'; MAX_STEALTH_DECOMPRESSED_SIZE as usize + 1])
+            .unwrap();
+        let payload = gz.finish().unwrap();
+        let bytes = stealth_bytes("stealth_pngcomp", (payload.len() * 8) as i32, &payload);
+        let side = ((bytes.len() * 8) as f64).sqrt().ceil() as u32;
+
+        let error = decode(&stealth_png(side, side, &bytes)).unwrap_err();
+        assert!(
+            matches!(error, MetadataError::OversizedStealthPayload(_)),
+            "{error}"
+        );
+    }
+
+    #[test]
+    fn embedded_metadata_is_extracted() {
+        // 有损格式作为输入也可以，输出总是 PNG
+        let jpeg = source_image(64, 64, ImageFormat::Jpeg, false);
+        let png =
+            embed_stealth_metadata(&jpeg, NAI_JSON.to_string(), StealthChannel::Alpha).unwrap();
+
+        let info = extract_metadata(&png).unwrap();
+        assert_eq!(info.metadata_string.as_deref(), Some(NAI_JSON));
+        assert_eq!(info.nai_metadata.unwrap().seed, Some(1234));
+        assert_eq!(info.aspect_ratio, 1.0);
+    }
+
+    #[test]
+    fn embedding_keeps_pixels_except_lowest_bits() {
+        let source = source_image(32, 16, ImageFormat::Png, true);
+        let png = embed_stealth_metadata(&source, "{}".to_string(), StealthChannel::Rgb).unwrap();
+        let before = image::load_from_memory(&source).unwrap().to_rgba8();
+        let after = image::load_from_memory(&png).unwrap().to_rgba8();
+        for (a, b) in before.pixels().zip(after.pixels()) {
+            assert_eq!(a[0] >> 1, b[0] >> 1);
+            assert_eq!(a[1] >> 1, b[1] >> 1);
+            assert_eq!(a[2] >> 1, b[2] >> 1);
+            assert_eq!(a[3], b[3]);
+        }
+    }
+
+    #[test]
+    fn embedding_into_small_image_is_an_error() {
+        let source = source_image(4, 4, ImageFormat::Png, true);
+        assert!(embed_stealth_metadata(&source, "{}".to_string(), StealthChannel::Alpha).is_err());
+    }
+
+    #[test]
+    fn nai_text_chunks_are_read_without_stealth_data() {
+        let source = source_image(16, 16, ImageFormat::Png, false);
+        let chunks = [
+            make_text_chunk("Title", "NovelAI generated image"),
+            make_text_chunk("Description", "1girl"),
+            make_text_chunk("Software", "NovelAI"),
+            make_text_chunk("Comment", r#"{"prompt": "1girl", "seed": 1234}"#),
+        ];
+        let png = rewrite_metadata(&source, &chunks).unwrap();
+
+        let info = extract_metadata(&png).unwrap();
+        assert_eq!(info.generator, Generator::NovelAi);
+        let nai = info.nai_metadata.unwrap();
+        assert_eq!(nai.prompt.as_deref(), Some("1girl"));
+        assert_eq!(nai.seed, Some(1234));
+    }
+
+    #[test]
+    fn exif_software_is_used_to_detect_generator() {
+        let field = exif::Field {
+            tag: exif::Tag::Software,
+            ifd_num: exif::In::PRIMARY,
+            value: exif::Value::Ascii(vec![b"NovelAI".to_vec()]),
+        };
+        let mut writer = exif::experimental::Writer::new();
+        writer.push_field(&field);
+        let mut tiff = Cursor::new(vec![]);
+        writer.write(&mut tiff, false).unwrap();
+
+        let mut segment = b"Exif\0\0".to_vec();
+        segment.extend_from_slice(tiff.get_ref());
+        let jpeg = source_image(16, 16, ImageFormat::Jpeg, false);
+        let output = insert_jpeg_segment(&jpeg, JPEG_APP1, &segment);
+
+        let info = extract_metadata(&output).unwrap();
+        assert_eq!(info.generator, Generator::NovelAi);
+    }
+
+    #[test]
+    fn xmp_is_not_used_as_metadata_string() {
+        let xmp = r#"<x:xmpmeta xmlns:x="adobe:ns:meta/"><rdf:RDF xmlns:rdf="http://www.w3.org/1999/02/22-rdf-syntax-ns#"><rdf:Description xmlns:xmp="http://ns.adobe.com/xap/1.0/" xmp:Rating="5"/></rdf:RDF></x:xmpmeta>"#;
+        let png = source_image(16, 16, ImageFormat::Png, false);
+        let png = rewrite_metadata(&png, &[make_text_chunk(XMP_KEYWORD, xmp)]).unwrap();
+        let webp = source_image(16, 16, ImageFormat::WebP, false);
+        let webp = append_webp_chunk(&webp, b"XMP ", xmp.as_bytes());
+        let mut jpeg_segment = XMP_HEADER.to_vec();
+        jpeg_segment.extend_from_slice(xmp.as_bytes());
+        let jpeg = source_image(16, 16, ImageFormat::Jpeg, false);
+        let jpeg = insert_jpeg_segment(&jpeg, JPEG_APP1, &jpeg_segment);
+
+        for input in [png, webp, jpeg] {
+            let info = extract_metadata(&input).unwrap();
+            assert_eq!(info.metadata_string, None);
+            assert_eq!(info.xmp_packet.as_deref(), Some(xmp));
+            assert_eq!(info.xmp.unwrap().rating, Some(5.0));
+        }
+    }
+
+    #[test]
+    fn damaged_stealth_payload_keeps_text_chunks() {
+        // 声明的长度超出 16x16 的 alpha 通道容量
+        let bytes = stealth_bytes("stealth_pnginfo", 8 * 100, b"{}");
+        let chunks = [make_text_chunk(
+            "parameters",
+            "1girl\nSteps: 28, Sampler: Euler, Seed: 1",
+        )];
+        let png = rewrite_metadata(&stealth_png(16, 16, &bytes), &chunks).unwrap();
+
+        let info = extract_metadata(&png).unwrap();
+        assert_eq!(
+            info.stealth_error_kind,
+            Some(MetadataErrorKind::TruncatedStealthPayload)
+        );
+        assert_eq!(info.a1111_parameters.unwrap().seed, Some(1));
+        assert_eq!(info.aspect_ratio, 1.0);
+    }
+
+    #[test]
+    fn signed_hash_is_removed_with_its_separator() {
+        let cases = [
+            (r#"{"signed_hash": "abc=", "seed": 1}"#, r#"{"seed": 1}"#),
+            (
+                r#"{"prompt": "cat", "signed_hash": "abc=", "seed": 1}"#,
+                r#"{"prompt": "cat", "seed": 1}"#,
+            ),
+            (r#"{"seed": 1, "signed_hash": "abc="}"#, r#"{"seed": 1}"#),
+            (r#"{"signed_hash": "abc="}"#, "{}"),
+        ];
+        for (comment, expected) in cases {
+            assert_eq!(
+                remove_signed_hash(comment, "abc=").as_deref(),
+                Some(expected)
+            );
+        }
+        assert_eq!(remove_signed_hash(r#"{"seed": 1}"#, "abc="), None);
+    }
+
+    #[test]
+    fn image_without_signed_hash_is_unsigned() {
+        let source = source_image(16, 16, ImageFormat::Png, false);
+        let chunks = [make_text_chunk(
+            "Comment",
+            r#"{"prompt": "cat", "seed": 1}"#,
+        )];
+        let png = rewrite_metadata(&source, &chunks).unwrap();
+
+        assert_eq!(
+            verify_nai_signature(&png).unwrap(),
+            NaiSignatureStatus::Unsigned
+        );
+        assert_eq!(
+            verify_nai_signature(&source).unwrap(),
+            NaiSignatureStatus::Unsigned
+        );
+    }
+
+    #[test]
+    fn changed_pixels_break_the_signature() {
+        use ed25519_dalek::{Signer, SigningKey};
+
+        let key = SigningKey::from_bytes(&[7; 32]);
+        let mut img = image::load_from_memory(&source_image(16, 16, ImageFormat::Png, true))
+            .unwrap()
+            .to_rgba8();
+        let unsigned_comment = r#"{"prompt": "cat", "seed": 1}"#;
+        let mut hasher = Sha256::new();
+        hasher.update(DynamicImage::ImageRgba8(img.clone()).to_rgb8().as_raw());
+        hasher.update(unsigned_comment.as_bytes());
+        let signed_hash = BASE64_STANDARD.encode(key.sign(&hasher.finalize()).to_bytes());
+        let comment = format!(r#"{{"prompt": "cat", "signed_hash": "{signed_hash}", "seed": 1}}"#);
+        let matches = |img: &image::RgbaImage| {
+            nai_signature_matches(
+                &DynamicImage::ImageRgba8(img.clone()),
+                &comment,
+                &signed_hash,
+                &key.verifying_key(),
+            )
+        };
+        assert!(matches(&img));
+
+        // alpha 通道不参与签名，可以写入隐写数据
+        img.get_pixel_mut(3, 5).0[3] ^= 1;
+        assert!(matches(&img));
+
+        img.get_pixel_mut(3, 5).0[0] ^= 1;
+        assert!(!matches(&img));
+    }
+
+    #[test]
+    fn valid_payload_round_trips() {
+        let bytes = stealth_bytes("stealth_pnginfo", 16, b"{}");
+        assert_eq!(
+            decode(&stealth_png(16, 16, &bytes)).unwrap().as_deref(),
+            Some("{}")
+        );
+    }
+
+    fn user_comment(prefix: &[u8], payload: &[u8]) -> Vec<u8> {
+        [prefix, payload].concat()
+    }
+
+    fn utf16(text: &str, little_endian: bool) -> Vec<u8> {
+        text.encode_utf16()
+            .flat_map(|unit| {
+                if little_endian {
+                    unit.to_le_bytes()
+                } else {
+                    unit.to_be_bytes()
+                }
+            })
+            .collect()
+    }
+
+    #[test]
+    fn unicode_user_comment_byte_order_is_detected() {
+        let text = "1girl, 猫";
+        for little_endian in [false, true] {
+            let payload = utf16(text, little_endian);
+            // TIFF 字节序与内容不一致时按内容判断
+            for tiff_little_endian in [false, true] {
+                assert_eq!(
+                    decode_user_comment(&user_comment(b"UNICODE\0", &payload), tiff_little_endian)
+                        .as_deref(),
+                    Some(text)
+                );
+            }
+        }
+
+        // 没有 ASCII 字符时无法根据内容判断，使用 TIFF 字节序
+        let payload = utf16("猫", true);
+        assert_eq!(
+            decode_user_comment(&user_comment(b"UNICODE\0", &payload), true).as_deref(),
+            Some("猫")
+        );
+    }
+
+    #[test]
+    fn unicode_user_comment_bom_overrides_byte_order() {
+        let text = "猫";
+        let big_endian = [&[0xFE, 0xFF][..], &utf16(text, false)].concat();
+        let little_endian = [&[0xFF, 0xFE][..], &utf16(text, true)].concat();
+        for tiff_little_endian in [false, true] {
+            assert_eq!(decode_utf16(&big_endian, tiff_little_endian), text);
+            assert_eq!(decode_utf16(&little_endian, tiff_little_endian), text);
+        }
+    }
+
+    #[test]
+    fn odd_length_utf16_drops_the_last_byte() {
+        let mut payload = utf16("cat", false);
+        payload.push(0x00);
+        assert_eq!(decode_utf16(&payload, true), "cat");
+        assert_eq!(decode_utf16(&[0x00], false), "");
+        assert_eq!(decode_user_comment(b"UNICODE\0\0", false), None);
+    }
+
+    #[test]
+    fn jis_user_comment_is_decoded() {
+        // Shift_JIS
+        let payload = [0x83, 0x65, 0x83, 0x58, 0x83, 0x67];
+        assert_eq!(
+            decode_user_comment(&user_comment(b"JIS\0\0\0\0\0", &payload), false).as_deref(),
+            Some("テスト")
+        );
+        // 含转义序列的 ISO-2022-JP
+        let payload = [
+            0x1b, 0x24, 0x42, 0x25, 0x46, 0x25, 0x39, 0x25, 0x48, 0x1b, 0x28, 0x42,
+        ];
+        assert_eq!(
+            decode_user_comment(&user_comment(b"JIS\0\0\0\0\0", &payload), false).as_deref(),
+            Some("テスト")
+        );
+    }
+
+    #[test]
+    fn ascii_and_undefined_user_comments_are_read_as_utf8() {
+        for prefix in [b"ASCII\0\0\0", b"\0\0\0\0\0\0\0\0"] {
+            assert_eq!(
+                decode_user_comment(&user_comment(prefix, "猫\0\0".as_bytes()), false).as_deref(),
+                Some("猫")
+            );
+            assert_eq!(
+                decode_user_comment(&user_comment(prefix, b"  \0"), false),
+                None
+            );
+        }
+        // 没有字符集标识
+        assert_eq!(
+            decode_user_comment(b"Steps: 20", false).as_deref(),
+            Some("Steps: 20")
+        );
+        assert_eq!(decode_user_comment(b"", false), None);
+    }
+
+    proptest! {
+        #[test]
+        fn stealth_reader_never_panics(
+            data in proptest::collection::vec(any::<u8>(), 0..256),
+            rgb in any::<bool>(),
+            ops in proptest::collection::vec((0u8..4, 0usize..64), 0..32),
+        ) {
+            // 任意尺寸 (包括 0 像素) 的图片，读取超出范围时必须返回错误
+            let width = (data.len() / 4).min(8) as u32;
+            let height = ((data.len() / 4) as u32).checked_div(width).unwrap_or(0);
+            let pixels = data[..(width * height * 4) as usize].to_vec();
+            let img = DynamicImage::ImageRgba8(image::RgbaImage::from_raw(width, height, pixels).unwrap());
+            let channel = if rgb { StealthChannel::Rgb } else { StealthChannel::Alpha };
+            let mut reader = StealthReader::new(&img, channel);
+            for (op, n) in ops {
+                let _ = match op {
+                    0 => reader.read_bit().map(|_| ()),
+                    1 => reader.read_byte().map(|_| ()),
+                    2 => reader.read_bytes(n).map(|_| ()),
+                    _ => reader.read_int32().map(|_| ()),
+                };
+            }
+        }
+
+        #[test]
+        fn forged_length_is_an_error(
+            declared_bits in any::<i32>(),
+            payload in proptest::collection::vec(any::<u8>(), 0..32),
+            compressed in any::<bool>(),
+        ) {
+            // 16x16 的 alpha 通道最多容纳 32 字节，声明的长度超出时必须返回错误
+            let magic = if compressed { "stealth_pngcomp" } else { "stealth_pnginfo" };
+            let bytes = stealth_bytes(magic, declared_bits, &payload);
+            let result = decode(&stealth_png(16, 16, &bytes));
+            if declared_bits < 0 || declared_bits as usize / 8 > 32 - 19 {
+                prop_assert!(result.is_err());
+            }
+        }
+
+        #[test]
+        fn truncated_image_is_an_error(cut in 0usize..19) {
+            let bytes = stealth_bytes("stealth_pnginfo", 16, b"{}");
+            // 图片只容纳 cut 字节，magic 不完整时视为没有隐写数据，长度或数据不完整时返回错误
+            let result = decode(&stealth_png(1, cut as u32 * 8 + 1, &bytes[..cut]));
+            if cut < STEALTH_MAGIC_LEN {
+                prop_assert!(matches!(result, Ok(None)));
+            } else {
+                prop_assert!(matches!(result, Err(MetadataError::TruncatedStealthPayload)));
+            }
+        }
+
+        #[test]
+        fn embed_round_trips(
+            metadata in ".{0,256}",
+            rgb in any::<bool>(),
+            alpha in any::<bool>(),
+        ) {
+            let channel = if rgb { StealthChannel::Rgb } else { StealthChannel::Alpha };
+            let source = source_image(128, 96, ImageFormat::Png, alpha);
+            let png = embed_stealth_metadata(&source, metadata.clone(), channel).unwrap();
+            let decoded = decode(&png).unwrap();
+            prop_assert_eq!(decoded.as_deref(), Some(metadata.as_str()));
+        }
+
+        #[test]
+        fn random_bytes_never_panic(data in proptest::collection::vec(any::<u8>(), 0..512)) {
+            let _ = extract_metadata(&data);
+        }
+    }
+}
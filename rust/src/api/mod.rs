@@ -1,3 +1,18 @@
+pub mod a1111;
+pub mod c2pa;
+pub mod comfyui;
+pub mod error;
+pub mod fooocus;
+pub mod generator;
+pub mod invokeai;
 pub mod metadata;
+pub mod models;
+pub mod nai;
+pub mod networks;
+pub mod prompt;
 pub mod scan;
 pub mod simple;
+pub mod strip;
+pub mod swarmui;
+pub mod writer;
+pub mod xmp;
@@ -0,0 +1,250 @@
+use std::collections::HashMap;
+use std::fs::{self, File};
+use std::io::{Read, Seek, SeekFrom};
+use std::path::Path;
+use std::time::UNIX_EPOCH;
+
+use anyhow::{anyhow, Error};
+use rayon::prelude::*;
+
+/// 本地模型文件及其哈希
+#[derive(Debug, Clone, PartialEq)]
+pub struct ModelFile {
+    pub file_path: String,
+    pub file_size: u64,
+    pub file_last_modified: u64,
+    /// 完整文件的 sha256，小写十六进制
+    pub sha256: String,
+    /// 旧版 WebUI 的 8 位哈希: 偏移 1 MiB 处 64 KiB 数据的 sha256
+    pub auto_v1: String,
+    /// 新版 WebUI 的 10 位哈希: 完整 sha256 的前 10 位
+    pub auto_v2: String,
+}
+
+const MODEL_EXTENSIONS: [&str; 2] = ["safetensors", "ckpt"];
+const AUTO_V1_OFFSET: u64 = 0x100000;
+const AUTO_V1_LENGTH: u64 = 0x10000;
+
+/// 递归索引文件夹中的 safetensors / ckpt 模型并计算哈希
+///
+/// 计算完整文件的 sha256 很慢，`cached_models` 中路径、大小和修改时间都一致的文件直接复用已有结果。
+/// 无法读取的文件会被跳过
+#[flutter_rust_bridge::frb]
+pub fn index_model_folder(
+    folder_path: String,
+    cached_models: Vec<ModelFile>,
+) -> Result<Vec<ModelFile>, Error> {
+    if !Path::new(&folder_path).is_dir() {
+        return Err(anyhow!("文件夹不存在 (Folder not found): {}", folder_path));
+    }
+
+    let model_paths: Vec<String> = walkdir::WalkDir::new(&folder_path)
+        .into_iter()
+        .filter_map(Result::ok)
+        .filter(|e| e.file_type().is_file())
+        .filter(|e| {
+            e.path()
+                .extension()
+                .and_then(|extension| extension.to_str())
+                .is_some_and(|extension| {
+                    MODEL_EXTENSIONS
+                        .iter()
+                        .any(|known| extension.eq_ignore_ascii_case(known))
+                })
+        })
+        .map(|e| e.path().to_str().unwrap_or_default().to_string())
+        .collect();
+
+    let cache: HashMap<String, ModelFile> = cached_models
+        .into_iter()
+        .map(|model| (model.file_path.clone(), model))
+        .collect();
+
+    // 大文件的哈希并行计算
+    Ok(model_paths
+        .par_iter()
+        .filter_map(|path| {
+            let metadata = fs::metadata(path).ok()?;
+            let file_size = metadata.len();
+            let file_last_modified = metadata
+                .modified()
+                .ok()
+                .and_then(|modified_time| modified_time.duration_since(UNIX_EPOCH).ok())
+                .map(|duration| duration.as_secs())
+                .unwrap_or(0);
+            match cache.get(path) {
+                Some(cached)
+                    if cached.file_size == file_size
+                        && cached.file_last_modified == file_last_modified =>
+                {
+                    Some(cached.clone())
+                }
+                _ => hash_model_file(path, file_size, file_last_modified).ok(),
+            }
+        })
+        .collect())
+}
+
+/// 根据图片中的模型哈希查找本地模型文件
+///
+/// 接受 AutoV1 (8 位)、AutoV2 (10 位) 或完整的 sha256，大小写不敏感。
+/// 也可以传入 `model.ckpt [a9263745]` 这样的模型标题，此时取最后一段
+#[flutter_rust_bridge::frb(sync)]
+pub fn resolve_model_hash(model_hash: String, models: Vec<ModelFile>) -> Option<ModelFile> {
+    let hash = model_hash
+        .split_whitespace()
+        .last()?
+        .trim_matches(|c| c == '[' || c == ']')
+        .to_ascii_lowercase();
+    if hash.len() < 8 || !hash.chars().all(|c| c.is_ascii_hexdigit()) {
+        return None;
+    }
+
+    // 8 位哈希优先按 AutoV1 匹配，其次按 sha256 前缀匹配
+    models
+        .iter()
+        .find(|model| hash.len() == 8 && model.auto_v1 == hash)
+        .or_else(|| models.iter().find(|model| model.sha256.starts_with(&hash)))
+        .cloned()
+}
+
+fn hash_model_file(
+    path: &str,
+    file_size: u64,
+    file_last_modified: u64,
+) -> Result<ModelFile, Error> {
+    let sha256 = sha256::try_digest(Path::new(path))?;
+
+    // 文件小于 1 MiB 时读到的是空数据，与 WebUI 的行为一致
+    let mut file = File::open(path)?;
+    file.seek(SeekFrom::Start(AUTO_V1_OFFSET))?;
+    let mut sample = vec![];
+    file.take(AUTO_V1_LENGTH).read_to_end(&mut sample)?;
+    let auto_v1 = sha256::digest(&sample)[..8].to_string();
+
+    Ok(ModelFile {
+        file_path: path.to_string(),
+        file_size,
+        file_last_modified,
+        auto_v2: sha256[..10].to_string(),
+        sha256,
+        auto_v1,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const MIB: usize = 0x100000;
+
+    // 不同长度的文件及其 sha256 和 AutoV1，由 Python hashlib 独立计算
+    const FIXTURES: [(&str, usize, &str, &str); 3] = [
+        (
+            "small.safetensors",
+            1000,
+            "f3f55c45264850b8475533289ff43ab81fa1eb3bf781267db645e1ce0c193379",
+            // 空数据的 sha256
+            "e3b0c442",
+        ),
+        (
+            "partial.ckpt",
+            MIB + 10,
+            "46f8e1e8d469a53c0ffe0899e5823dc005e28a24526ac252e9a3e231b598bc32",
+            "130611b3",
+        ),
+        (
+            "nested/full.SAFETENSORS",
+            MIB + 0x10000 + 100,
+            "12fdac7cb93060b5ec129d0217c5e8603ab283edbe421b88ac02e62b0c5a59cc",
+            "b23f3f2f",
+        ),
+    ];
+
+    fn model_folder(name: &str) -> std::path::PathBuf {
+        let folder = std::env::temp_dir().join(format!("models-{}-{name}", std::process::id()));
+        let _ = fs::remove_dir_all(&folder);
+        fs::create_dir_all(folder.join("nested")).unwrap();
+        for (file_name, length, _, _) in FIXTURES {
+            let data: Vec<u8> = (0..length).map(|i| (i * 31 % 251) as u8).collect();
+            fs::write(folder.join(file_name), data).unwrap();
+        }
+        fs::write(folder.join("notes.txt"), "not a model").unwrap();
+        folder
+    }
+
+    fn index(folder: &Path, cached_models: Vec<ModelFile>) -> Vec<ModelFile> {
+        let mut models =
+            index_model_folder(folder.to_str().unwrap().to_string(), cached_models).unwrap();
+        models.sort_by_key(|model| model.file_size);
+        models
+    }
+
+    #[test]
+    fn hashes_match_known_digests() {
+        let folder = model_folder("digests");
+        let models = index(&folder, vec![]);
+        fs::remove_dir_all(&folder).unwrap();
+
+        assert_eq!(models.len(), FIXTURES.len());
+        for (model, (file_name, length, sha256, auto_v1)) in models.iter().zip(FIXTURES) {
+            assert!(model.file_path.ends_with(file_name));
+            assert_eq!(model.file_size, length as u64);
+            assert_eq!(model.sha256, sha256);
+            assert_eq!(model.auto_v1, auto_v1);
+            assert_eq!(model.auto_v2, sha256[..10]);
+        }
+    }
+
+    #[test]
+    fn unchanged_files_reuse_cached_hashes() {
+        let folder = model_folder("cache");
+        let mut cached = index(&folder, vec![]);
+        cached[0].sha256 = "cached".to_string();
+        // 大小不一致的缓存重新计算
+        cached[1].file_size += 1;
+        cached[1].sha256 = "stale".to_string();
+        let models = index(&folder, cached);
+        fs::remove_dir_all(&folder).unwrap();
+
+        assert_eq!(models[0].sha256, "cached");
+        assert_eq!(models[1].sha256, FIXTURES[1].2);
+    }
+
+    #[test]
+    fn missing_folder_is_an_error() {
+        let folder = std::env::temp_dir().join("models-missing-folder");
+        assert!(index_model_folder(folder.to_str().unwrap().to_string(), vec![]).is_err());
+    }
+
+    #[test]
+    fn hashes_are_resolved_by_length() {
+        let models: Vec<ModelFile> = FIXTURES
+            .iter()
+            .map(|(file_name, length, sha256, auto_v1)| ModelFile {
+                file_path: file_name.to_string(),
+                file_size: *length as u64,
+                file_last_modified: 0,
+                sha256: sha256.to_string(),
+                auto_v1: auto_v1.to_string(),
+                auto_v2: sha256[..10].to_string(),
+            })
+            .collect();
+        let resolve = |hash: &str| {
+            resolve_model_hash(hash.to_string(), models.clone()).map(|model| model.file_path)
+        };
+
+        assert_eq!(resolve("B23F3F2F").as_deref(), Some(FIXTURES[2].0));
+        assert_eq!(resolve("46f8e1e8d4").as_deref(), Some(FIXTURES[1].0));
+        assert_eq!(resolve(FIXTURES[0].2).as_deref(), Some(FIXTURES[0].0));
+        // 8 位哈希不是 AutoV1 时按 sha256 前缀匹配
+        assert_eq!(resolve("12fdac7c").as_deref(), Some(FIXTURES[2].0));
+        assert_eq!(
+            resolve("full.ckpt [130611b3]").as_deref(),
+            Some(FIXTURES[1].0)
+        );
+        assert_eq!(resolve("1234567"), None);
+        assert_eq!(resolve("not a hash"), None);
+        assert_eq!(resolve("00000000"), None);
+    }
+}
@@ -0,0 +1,352 @@
+use serde_json::{Map, Value};
+
+use crate::api::metadata::PngTextChunk;
+use crate::fields::{get_f64, get_string, get_u32, get_u64};
+
+/// NovelAI 生成参数 (NovelAI generation parameters)
+#[derive(Debug, Clone, PartialEq)]
+pub struct NaiMetadata {
+    pub title: Option<String>,
+    pub description: Option<String>,
+    pub software: Option<String>,
+    pub source: Option<String>,
+    pub generation_time: Option<f64>,
+    pub prompt: Option<String>,
+    pub negative_prompt: Option<String>,
+    pub seed: Option<u64>,
+    pub steps: Option<u32>,
+    pub sampler: Option<String>,
+    pub noise_schedule: Option<String>,
+    pub scale: Option<f64>,
+    pub cfg_rescale: Option<f64>,
+    pub uncond_scale: Option<f64>,
+    pub width: Option<u32>,
+    pub height: Option<u32>,
+    pub n_samples: Option<u32>,
+    pub sm: Option<bool>,
+    pub sm_dyn: Option<bool>,
+    pub request_type: Option<String>,
+    pub signed_hash: Option<String>,
+    pub v4_prompt: Option<NaiV4Prompt>,
+    pub v4_negative_prompt: Option<NaiV4Prompt>,
+    pub reference_information_extracted: Vec<f64>,
+    pub reference_strength: Vec<f64>,
+}
+
+/// V4 模型的结构化提示词 (Structured V4 prompt)
+#[derive(Debug, Clone, PartialEq)]
+pub struct NaiV4Prompt {
+    pub base_caption: String,
+    pub char_captions: Vec<NaiCharCaption>,
+    pub use_coords: Option<bool>,
+    pub use_order: Option<bool>,
+}
+
+/// V4 角色提示词 (V4 character caption)
+#[derive(Debug, Clone, PartialEq)]
+pub struct NaiCharCaption {
+    pub char_caption: String,
+    pub centers: Vec<NaiCoordinate>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct NaiCoordinate {
+    pub x: f64,
+    pub y: f64,
+}
+
+// NAI 写入 PNG 文本块时使用与隐写 JSON 相同的键名
+const NAI_TEXT_KEYWORDS: [&str; 6] = [
+    "Title",
+    "Description",
+    "Software",
+    "Source",
+    "Generation time",
+    "Comment",
+];
+
+/// 解析隐写数据中的 JSON 文本，Comment 字段本身也是一段 JSON 字符串
+pub(crate) fn parse_nai_metadata(json_text: &str) -> Option<NaiMetadata> {
+    let root: Value = serde_json::from_str(json_text).ok()?;
+    Some(nai_metadata_from_root(root.as_object()?))
+}
+
+/// 读取 PNG 文本块中的 NAI 参数，用于没有隐写数据的图片 (早期的图片，或 alpha 通道被去除的图片)
+///
+/// `Software` 为 `NovelAI` 或 `Source` 以 `NovelAI` 开头时才视为 NAI 图片
+pub(crate) fn parse_nai_text_chunks(chunks: &[PngTextChunk]) -> Option<NaiMetadata> {
+    let root: Map<String, Value> = chunks
+        .iter()
+        .filter(|chunk| NAI_TEXT_KEYWORDS.contains(&chunk.keyword.as_str()))
+        .map(|chunk| (chunk.keyword.clone(), Value::String(chunk.text.clone())))
+        .collect();
+    let is_nai = get_string(&root, "Software").as_deref() == Some("NovelAI")
+        || get_string(&root, "Source").is_some_and(|source| source.starts_with("NovelAI"));
+    is_nai.then(|| nai_metadata_from_root(&root))
+}
+
+fn nai_metadata_from_root(root: &Map<String, Value>) -> NaiMetadata {
+    // Comment 通常是字符串形式的 JSON，少数工具会直接写成对象
+    let comment = match root.get("Comment") {
+        Some(Value::String(text)) => serde_json::from_str::<Value>(text).ok(),
+        Some(value @ Value::Object(_)) => Some(value.clone()),
+        _ => None,
+    };
+    let empty = Map::new();
+    let comment = comment
+        .as_ref()
+        .and_then(Value::as_object)
+        .unwrap_or(&empty);
+
+    let generation_time = match root.get("Generation time") {
+        Some(Value::String(text)) => text.trim().parse().ok(),
+        Some(value) => value.as_f64(),
+        None => None,
+    };
+
+    NaiMetadata {
+        title: get_string(root, "Title"),
+        description: get_string(root, "Description"),
+        software: get_string(root, "Software"),
+        source: get_string(root, "Source"),
+        generation_time,
+        prompt: get_string(comment, "prompt").or_else(|| get_string(root, "Description")),
+        negative_prompt: get_string(comment, "uc"),
+        seed: get_u64(comment, "seed"),
+        steps: get_u32(comment, "steps"),
+        sampler: get_string(comment, "sampler"),
+        noise_schedule: get_string(comment, "noise_schedule"),
+        scale: get_f64(comment, "scale"),
+        cfg_rescale: get_f64(comment, "cfg_rescale"),
+        uncond_scale: get_f64(comment, "uncond_scale"),
+        width: get_u32(comment, "width"),
+        height: get_u32(comment, "height"),
+        n_samples: get_u32(comment, "n_samples"),
+        sm: comment.get("sm").and_then(Value::as_bool),
+        sm_dyn: comment.get("sm_dyn").and_then(Value::as_bool),
+        request_type: get_string(comment, "request_type"),
+        signed_hash: get_string(comment, "signed_hash"),
+        v4_prompt: comment.get("v4_prompt").and_then(parse_v4_prompt),
+        v4_negative_prompt: comment.get("v4_negative_prompt").and_then(parse_v4_prompt),
+        reference_information_extracted: get_reference_values(
+            comment,
+            "reference_information_extracted",
+        ),
+        reference_strength: get_reference_values(comment, "reference_strength"),
+    }
+}
+
+fn parse_v4_prompt(value: &Value) -> Option<NaiV4Prompt> {
+    let object = value.as_object()?;
+    let caption = object.get("caption")?.as_object()?;
+    let char_captions = caption
+        .get("char_captions")
+        .and_then(Value::as_array)
+        .map(|captions| captions.iter().filter_map(parse_char_caption).collect())
+        .unwrap_or_default();
+
+    Some(NaiV4Prompt {
+        base_caption: get_string(caption, "base_caption").unwrap_or_default(),
+        char_captions,
+        use_coords: object.get("use_coords").and_then(Value::as_bool),
+        use_order: object.get("use_order").and_then(Value::as_bool),
+    })
+}
+
+fn parse_char_caption(value: &Value) -> Option<NaiCharCaption> {
+    let object = value.as_object()?;
+    let centers = object
+        .get("centers")
+        .and_then(Value::as_array)
+        .map(|centers| {
+            centers
+                .iter()
+                .filter_map(|center| {
+                    Some(NaiCoordinate {
+                        x: center.get("x")?.as_f64()?,
+                        y: center.get("y")?.as_f64()?,
+                    })
+                })
+                .collect()
+        })
+        .unwrap_or_default();
+
+    Some(NaiCharCaption {
+        char_caption: get_string(object, "char_caption").unwrap_or_default(),
+        centers,
+    })
+}
+
+// 多参考图使用 `*_multiple` 数组，旧版本只有单个数值
+fn get_reference_values(object: &Map<String, Value>, key: &str) -> Vec<f64> {
+    if let Some(values) = object
+        .get(&format!("{key}_multiple"))
+        .and_then(Value::as_array)
+    {
+        return values.iter().filter_map(Value::as_f64).collect();
+    }
+    get_f64(object, key).into_iter().collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+    use crate::test_fixtures::{make_text_chunk, NAI_JSON};
+
+    // NAI Diffusion V4.5 图片隐写数据中的 Comment (有删减)
+    fn v4_comment() -> Value {
+        json!({
+            "prompt": "2girls, outdoors",
+            "steps": 28,
+            "height": 1216,
+            "width": 832,
+            "scale": 5.0,
+            "uncond_scale": 0.0,
+            "cfg_rescale": 0.0,
+            "seed": 3041618442_u64,
+            "n_samples": 1,
+            "noise_schedule": "karras",
+            "sampler": "k_euler_ancestral",
+            "sm": false,
+            "sm_dyn": false,
+            "request_type": "PromptGenerateRequest",
+            "signed_hash": "c2lnbmF0dXJl",
+            "v4_prompt": {
+                "caption": {
+                    "base_caption": "2girls, outdoors",
+                    "char_captions": [
+                        {"char_caption": "girl, red hair", "centers": [{"x": 0.3, "y": 0.5}]},
+                        {"char_caption": "girl, blue hair", "centers": [{"x": 0.7, "y": 0.5}]}
+                    ]
+                },
+                "use_coords": true,
+                "use_order": true
+            },
+            "v4_negative_prompt": {
+                "caption": {
+                    "base_caption": "lowres",
+                    "char_captions": [{"char_caption": "", "centers": [{"x": 0.3, "y": 0.5}]}]
+                },
+                "legacy_uc": false
+            },
+            "reference_information_extracted_multiple": [1.0, 0.5],
+            "reference_strength_multiple": [0.6, 0.4],
+            "uc": "lowres, bad anatomy"
+        })
+    }
+
+    fn root(comment: &Value) -> String {
+        json!({
+            "Title": "NovelAI generated image",
+            "Description": "2girls, outdoors",
+            "Software": "NovelAI",
+            "Source": "NovelAI Diffusion V4.5 4BDE2A90",
+            "Generation time": "5.2",
+            "Comment": comment.to_string(),
+        })
+        .to_string()
+    }
+
+    #[test]
+    fn comment_string_is_parsed() {
+        let metadata = parse_nai_metadata(NAI_JSON).unwrap();
+        assert_eq!(metadata.software.as_deref(), Some("NovelAI"));
+        assert_eq!(metadata.prompt.as_deref(), Some("1girl"));
+        assert_eq!(metadata.negative_prompt.as_deref(), Some("lowres"));
+        assert_eq!(metadata.seed, Some(1234));
+        assert_eq!(metadata.v4_prompt, None);
+        assert!(metadata.reference_strength.is_empty());
+    }
+
+    #[test]
+    fn v4_fields_and_character_prompts_are_parsed() {
+        let metadata = parse_nai_metadata(&root(&v4_comment())).unwrap();
+        assert_eq!(metadata.title.as_deref(), Some("NovelAI generated image"));
+        assert_eq!(
+            metadata.source.as_deref(),
+            Some("NovelAI Diffusion V4.5 4BDE2A90")
+        );
+        assert_eq!(metadata.generation_time, Some(5.2));
+        assert_eq!(
+            metadata.negative_prompt.as_deref(),
+            Some("lowres, bad anatomy")
+        );
+        assert_eq!(metadata.seed, Some(3041618442));
+        assert_eq!(metadata.steps, Some(28));
+        assert_eq!((metadata.width, metadata.height), (Some(832), Some(1216)));
+        assert_eq!(metadata.scale, Some(5.0));
+        assert_eq!(metadata.sampler.as_deref(), Some("k_euler_ancestral"));
+        assert_eq!(metadata.noise_schedule.as_deref(), Some("karras"));
+        assert_eq!(metadata.sm, Some(false));
+        assert_eq!(metadata.signed_hash.as_deref(), Some("c2lnbmF0dXJl"));
+        assert_eq!(metadata.reference_information_extracted, [1.0, 0.5]);
+        assert_eq!(metadata.reference_strength, [0.6, 0.4]);
+
+        let v4_prompt = metadata.v4_prompt.unwrap();
+        assert_eq!(v4_prompt.base_caption, "2girls, outdoors");
+        assert_eq!(v4_prompt.use_coords, Some(true));
+        assert_eq!(
+            v4_prompt.char_captions,
+            [
+                NaiCharCaption {
+                    char_caption: "girl, red hair".to_string(),
+                    centers: vec![NaiCoordinate { x: 0.3, y: 0.5 }],
+                },
+                NaiCharCaption {
+                    char_caption: "girl, blue hair".to_string(),
+                    centers: vec![NaiCoordinate { x: 0.7, y: 0.5 }],
+                },
+            ]
+        );
+        let v4_negative_prompt = metadata.v4_negative_prompt.unwrap();
+        assert_eq!(v4_negative_prompt.base_caption, "lowres");
+        assert_eq!(v4_negative_prompt.use_coords, None);
+        assert_eq!(v4_negative_prompt.char_captions[0].char_caption, "");
+    }
+
+    #[test]
+    fn comment_object_and_single_reference_values_are_parsed() {
+        let root = json!({
+            "Comment": {"prompt": "1girl", "steps": 28.0, "reference_strength": 0.6}
+        });
+        let metadata = parse_nai_metadata(&root.to_string()).unwrap();
+        assert_eq!(metadata.prompt.as_deref(), Some("1girl"));
+        assert_eq!(metadata.steps, Some(28));
+        assert_eq!(metadata.reference_strength, [0.6]);
+    }
+
+    #[test]
+    fn description_is_used_without_comment_prompt() {
+        let root = json!({"Description": "1girl", "Comment": "not json"});
+        let metadata = parse_nai_metadata(&root.to_string()).unwrap();
+        assert_eq!(metadata.prompt.as_deref(), Some("1girl"));
+        assert_eq!(metadata.seed, None);
+        assert_eq!(parse_nai_metadata("not json"), None);
+        assert_eq!(parse_nai_metadata("[]"), None);
+    }
+
+    #[test]
+    fn text_chunks_need_novelai_software_or_source() {
+        let comment = v4_comment().to_string();
+        let chunks = [
+            make_text_chunk("Source", "NovelAI Diffusion V4.5 4BDE2A90"),
+            make_text_chunk("Comment", &comment),
+            make_text_chunk("parameters", "ignored"),
+        ];
+        let metadata = parse_nai_text_chunks(&chunks).unwrap();
+        assert_eq!(metadata.software, None);
+        assert_eq!(
+            metadata.negative_prompt.as_deref(),
+            Some("lowres, bad anatomy")
+        );
+        assert_eq!(metadata.v4_prompt.unwrap().char_captions.len(), 2);
+
+        let chunks = [
+            make_text_chunk("Software", "Other"),
+            make_text_chunk("Comment", &comment),
+        ];
+        assert_eq!(parse_nai_text_chunks(&chunks), None);
+    }
+}
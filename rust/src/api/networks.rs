@@ -0,0 +1,419 @@
+use serde_json::Value;
+
+use crate::api::a1111::A1111Parameters;
+use crate::api::metadata::ImageInfo;
+use crate::api::prompt::{parse_prompt, PromptDialect, PromptTag, PromptTagKind};
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum NetworkKind {
+    /// LoRA / LyCORIS
+    Lora,
+    /// Textual Inversion
+    Embedding,
+    Hypernetwork,
+}
+
+/// 生成图片时使用的附加网络
+#[derive(Debug, Clone, PartialEq)]
+pub struct NetworkReference {
+    pub kind: NetworkKind,
+    /// 去除目录和扩展名后的名称，例如 `loras/style.safetensors` 记为 `style`
+    pub name: String,
+    /// LoRA 和超网络为强度 (ComfyUI 中为 `strength_model`)，嵌入为提示词中的权重
+    pub weight: Option<f64>,
+    /// ComfyUI LoRA 加载器的 `strength_clip`
+    pub clip_weight: Option<f64>,
+    /// A1111 `Lora hashes` / `TI hashes` / `Hypernet hash` 中记录的哈希
+    pub hash: Option<String>,
+}
+
+// 模型文件常见的扩展名，比较名称前去掉
+const MODEL_EXTENSIONS: [&str; 5] = [".safetensors", ".ckpt", ".pt", ".pth", ".bin"];
+
+/// 汇总 A1111 / Fooocus / SwarmUI 参数、ComfyUI 节点图和 InvokeAI 参数中的附加网络，
+/// 同一类型同名的网络只保留一项
+pub(crate) fn collect_network_references(info: &ImageInfo) -> Vec<NetworkReference> {
+    let mut networks = vec![];
+    if let Some(parameters) = &info.a1111_parameters {
+        collect_a1111_networks(parameters, &mut networks);
+    }
+    if let Some(parameters) = &info.fooocus_parameters {
+        collect_fooocus_networks(parameters, &mut networks);
+    }
+    if let Some(parameters) = &info.swarmui_parameters {
+        // SwarmUI 的提示词同样支持 `<lora:name:0.8>`
+        collect_a1111_networks(parameters, &mut networks);
+        collect_swarmui_networks(parameters, &mut networks);
+    }
+    if let Some(summary) = info
+        .comfyui
+        .as_ref()
+        .and_then(|comfyui| comfyui.summary.as_ref())
+    {
+        for lora in &summary.loras {
+            add_network(
+                &mut networks,
+                NetworkReference {
+                    kind: NetworkKind::Lora,
+                    name: normalize_name(&lora.name),
+                    weight: lora.strength_model,
+                    clip_weight: lora.strength_clip,
+                    hash: None,
+                },
+            );
+        }
+        // ComfyUI 在提示词中以 `embedding:name` 引用嵌入
+        let prompts = [&summary.positive_prompt, &summary.negative_prompt];
+        for prompt in prompts.into_iter().flatten() {
+            for name in comfyui_embeddings(prompt) {
+                add_network(&mut networks, network(NetworkKind::Embedding, name));
+            }
+        }
+    }
+    for lora in info.invokeai.iter().flat_map(|invokeai| &invokeai.loras) {
+        add_network(
+            &mut networks,
+            NetworkReference {
+                weight: lora.weight,
+                ..network(NetworkKind::Lora, &lora.name)
+            },
+        );
+    }
+    networks
+}
+
+// `lora_combined_1: name : 0.5`，旧版本的日志格式为 `LoRA 1: name : 0.5`
+fn collect_fooocus_networks(parameters: &A1111Parameters, networks: &mut Vec<NetworkReference>) {
+    for (key, value) in &parameters.settings {
+        if !(key.starts_with("lora_combined_") || key.starts_with("LoRA ")) {
+            continue;
+        }
+        let (name, weight) = match value.rsplit_once(" : ") {
+            Some((name, weight)) => (name, weight.trim().parse().ok()),
+            None => (value.as_str(), None),
+        };
+        add_network(
+            networks,
+            NetworkReference {
+                weight,
+                ..network(NetworkKind::Lora, name)
+            },
+        );
+    }
+}
+
+// `loras` 和 `loraweights` 是按顺序对应的两个 JSON 数组
+fn collect_swarmui_networks(parameters: &A1111Parameters, networks: &mut Vec<NetworkReference>) {
+    let array = |key: &str| {
+        parameters
+            .settings
+            .iter()
+            .find(|(name, _)| name == key)
+            .and_then(|(_, value)| serde_json::from_str::<Vec<Value>>(value).ok())
+            .unwrap_or_default()
+    };
+    let weights = array("loraweights");
+    for (index, name) in array("loras").iter().enumerate() {
+        let Some(name) = name.as_str() else {
+            continue;
+        };
+        let weight = weights.get(index).and_then(|weight| match weight {
+            Value::String(text) => text.trim().parse().ok(),
+            other => other.as_f64(),
+        });
+        add_network(
+            networks,
+            NetworkReference {
+                weight,
+                ..network(NetworkKind::Lora, name)
+            },
+        );
+    }
+}
+
+fn collect_a1111_networks(parameters: &A1111Parameters, networks: &mut Vec<NetworkReference>) {
+    let get = |key: &str| {
+        parameters
+            .settings
+            .iter()
+            .find(|(name, _)| name == key)
+            .map(|(_, value)| value.as_str())
+    };
+    let tags: Vec<PromptTag> = [
+        Some(&parameters.prompt),
+        parameters.negative_prompt.as_ref(),
+    ]
+    .into_iter()
+    .flatten()
+    .flat_map(|prompt| parse_prompt(prompt.clone(), PromptDialect::A1111))
+    .collect();
+
+    // 提示词中的 `<lora:name:0.8>` 和 `<hypernet:name:0.5>`
+    for tag in &tags {
+        let kind = match tag.kind {
+            PromptTagKind::Lora => NetworkKind::Lora,
+            PromptTagKind::Hypernetwork => NetworkKind::Hypernetwork,
+            _ => continue,
+        };
+        add_network(
+            networks,
+            NetworkReference {
+                weight: Some(tag.weight),
+                ..network(kind, &tag.text)
+            },
+        );
+    }
+
+    // `Lora hashes: "name: hash, ..."`，没有出现在提示词中的 LoRA 也记录下来
+    for (name, hash) in get("Lora hashes").map(parse_hashes).unwrap_or_default() {
+        add_network(
+            networks,
+            NetworkReference {
+                hash: Some(hash),
+                ..network(NetworkKind::Lora, name)
+            },
+        );
+    }
+
+    // 嵌入没有单独的语法，在提示词中按名称查找权重
+    for (name, hash) in get("TI hashes").map(parse_hashes).unwrap_or_default() {
+        let weight = tags
+            .iter()
+            .find(|tag| tag.kind == PromptTagKind::Tag && tag.text == name)
+            .map(|tag| tag.weight);
+        add_network(
+            networks,
+            NetworkReference {
+                weight,
+                hash: Some(hash),
+                ..network(NetworkKind::Embedding, name)
+            },
+        );
+    }
+
+    // 旧版 WebUI 在设置中选择的超网络: `Hypernet: name, Hypernet hash: ..., Hypernet strength: 0.5`
+    if let Some(name) = get("Hypernet") {
+        add_network(
+            networks,
+            NetworkReference {
+                weight: get("Hypernet strength").and_then(|value| value.parse().ok()),
+                hash: get("Hypernet hash").map(str::to_string),
+                ..network(NetworkKind::Hypernetwork, name)
+            },
+        );
+    }
+}
+
+fn network(kind: NetworkKind, name: &str) -> NetworkReference {
+    NetworkReference {
+        kind,
+        name: normalize_name(name),
+        weight: None,
+        clip_weight: None,
+        hash: None,
+    }
+}
+
+// 已存在同名网络时只补充缺少的字段
+fn add_network(networks: &mut Vec<NetworkReference>, network: NetworkReference) {
+    if network.name.is_empty() {
+        return;
+    }
+    match networks
+        .iter_mut()
+        .find(|existing| existing.kind == network.kind && existing.name == network.name)
+    {
+        Some(existing) => {
+            existing.weight = existing.weight.or(network.weight);
+            existing.clip_weight = existing.clip_weight.or(network.clip_weight);
+            existing.hash = existing.hash.take().or(network.hash);
+        }
+        None => networks.push(network),
+    }
+}
+
+// `name1: hash1, name2: hash2`，名称本身可能包含冒号，按最后一个冒号拆分
+fn parse_hashes(value: &str) -> Vec<(&str, String)> {
+    value
+        .split(',')
+        .filter_map(|entry| entry.rsplit_once(':'))
+        .map(|(name, hash)| (name.trim(), hash.trim().to_string()))
+        .filter(|(name, hash)| !name.is_empty() && !hash.is_empty())
+        .collect()
+}
+
+fn comfyui_embeddings(prompt: &str) -> Vec<&str> {
+    prompt
+        .match_indices("embedding:")
+        .map(|(index, prefix)| {
+            let rest = &prompt[index + prefix.len()..];
+            let end = rest
+                .find(|c: char| c.is_whitespace() || matches!(c, ',' | '(' | ')' | ':'))
+                .unwrap_or(rest.len());
+            &rest[..end]
+        })
+        .collect()
+}
+
+fn normalize_name(name: &str) -> String {
+    let name = name.trim();
+    let file_name = name.rsplit(['/', '\\']).next().unwrap_or(name);
+    MODEL_EXTENSIONS
+        .iter()
+        .find_map(|extension| {
+            let split = file_name.len().checked_sub(extension.len())?;
+            let (stem, suffix) = file_name.split_at_checked(split)?;
+            suffix.eq_ignore_ascii_case(extension).then_some(stem)
+        })
+        .unwrap_or(file_name)
+        .to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use image::ImageFormat;
+    use serde_json::json;
+
+    use super::*;
+    use crate::api::metadata::read_image_info;
+    use crate::test_fixtures::{insert_png_chunk, source_image};
+
+    fn networks(keyword: &str, text: &str) -> Vec<NetworkReference> {
+        let mut data = keyword.as_bytes().to_vec();
+        data.push(0);
+        data.extend_from_slice(text.as_bytes());
+        let png = source_image(8, 8, ImageFormat::Png, false);
+        let png = insert_png_chunk(&png, b"tEXt", &data);
+        read_image_info(&png).unwrap().networks
+    }
+
+    fn reference(
+        kind: NetworkKind,
+        name: &str,
+        weight: Option<f64>,
+        hash: Option<&str>,
+    ) -> NetworkReference {
+        NetworkReference {
+            kind,
+            name: name.to_string(),
+            weight,
+            clip_weight: None,
+            hash: hash.map(str::to_string),
+        }
+    }
+
+    #[test]
+    fn a1111_networks_are_merged_with_hashes() {
+        let text = "<lora:loras/Style.safetensors:0.8>, <lyco:detail:0.5>, <hypernet:anime:0.6>, \
+                    (easynegative:1.2), <lora:Style:0.3>\n\
+                    Negative prompt: <lora:bad.pt:-1>\n\
+                    Steps: 20, Sampler: Euler a, CFG scale: 7, \
+                    Lora hashes: \"Style: abc123, unused: def456\", \
+                    TI hashes: \"easynegative: c74b4e810b03\", \
+                    Hypernet: anime, Hypernet hash: 5b1e5dbd, Hypernet strength: 0.3";
+        assert_eq!(
+            networks("parameters", text),
+            [
+                // 同名的 LoRA 只保留第一个权重，并补充哈希
+                reference(NetworkKind::Lora, "Style", Some(0.8), Some("abc123")),
+                reference(NetworkKind::Lora, "detail", Some(0.5), None),
+                reference(
+                    NetworkKind::Hypernetwork,
+                    "anime",
+                    Some(0.6),
+                    Some("5b1e5dbd")
+                ),
+                reference(NetworkKind::Lora, "bad", Some(-1.0), None),
+                reference(NetworkKind::Lora, "unused", None, Some("def456")),
+                reference(
+                    NetworkKind::Embedding,
+                    "easynegative",
+                    Some(1.2),
+                    Some("c74b4e810b03")
+                ),
+            ]
+        );
+    }
+
+    #[test]
+    fn hypernet_setting_without_prompt_tag_keeps_strength() {
+        let text = "a cat\nSteps: 20, Hypernet: anime, Hypernet strength: 0.3, Seed: 1";
+        assert_eq!(
+            networks("parameters", text),
+            [reference(
+                NetworkKind::Hypernetwork,
+                "anime",
+                Some(0.3),
+                None
+            )]
+        );
+    }
+
+    #[test]
+    fn comfyui_loras_and_embeddings_are_collected() {
+        let graph = json!({
+            "1": {"class_type": "CheckpointLoaderSimple", "inputs": {"ckpt_name": "model.safetensors"}},
+            "2": {"class_type": "LoraLoader", "inputs": {
+                "lora_name": "sub\\style.safetensors", "strength_model": 0.8, "strength_clip": 0.5,
+                "model": ["1", 0], "clip": ["1", 1]}},
+            "3": {"class_type": "CLIPTextEncode", "inputs": {
+                "text": "1girl, (embedding:good.pt:1.1)", "clip": ["2", 1]}},
+            "4": {"class_type": "CLIPTextEncode", "inputs": {
+                "text": "embedding:bad, embedding:good", "clip": ["2", 1]}},
+            "5": {"class_type": "KSampler", "inputs": {
+                "model": ["2", 0], "positive": ["3", 0], "negative": ["4", 0], "seed": 1}},
+        });
+        assert_eq!(
+            networks("prompt", &graph.to_string()),
+            [
+                NetworkReference {
+                    clip_weight: Some(0.5),
+                    ..reference(NetworkKind::Lora, "style", Some(0.8), None)
+                },
+                reference(NetworkKind::Embedding, "good", None, None),
+                reference(NetworkKind::Embedding, "bad", None, None),
+            ]
+        );
+    }
+
+    #[test]
+    fn swarmui_lora_arrays_are_paired_by_index() {
+        let json = json!({"sui_image_params": {
+            "prompt": "a cat <lora:inline:0.4>",
+            "steps": 20,
+            "loras": ["first", "second.safetensors"],
+            "loraweights": ["0.7", 1],
+        }});
+        assert_eq!(
+            networks("parameters", &json.to_string()),
+            [
+                reference(NetworkKind::Lora, "inline", Some(0.4), None),
+                reference(NetworkKind::Lora, "first", Some(0.7), None),
+                reference(NetworkKind::Lora, "second", Some(1.0), None),
+            ]
+        );
+    }
+
+    #[test]
+    fn fooocus_lora_settings_are_collected() {
+        let json = json!({
+            "prompt": "a cat",
+            "lora_combined_1": "sd_xl_offset_example-lora_1.0.safetensors : 0.1",
+            "LoRA 2": "detail.safetensors",
+            "version": "Fooocus v2.5.3",
+        });
+        assert_eq!(
+            networks("parameters", &json.to_string()),
+            [
+                // 按 JSON 键名的顺序
+                reference(NetworkKind::Lora, "detail", None, None),
+                reference(
+                    NetworkKind::Lora,
+                    "sd_xl_offset_example-lora_1.0",
+                    Some(0.1),
+                    None
+                ),
+            ]
+        );
+    }
+}
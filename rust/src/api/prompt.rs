@@ -0,0 +1,822 @@
+/// 提示词语法
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum PromptDialect {
+    /// `{tag}` / `[tag]` 强调和 `1.2::tag::` 权重
+    NovelAi,
+    /// `(tag:1.2)`、`[from:to:when]`、`BREAK` 和 `<lora:name:0.8>`
+    A1111,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum PromptTagKind {
+    Tag,
+    Lora,
+    Hypernetwork,
+    /// A1111 的 `BREAK`，把提示词分成独立的 75 token 块
+    Break,
+}
+
+/// 提示词中的一个标签及其实际权重
+#[derive(Debug, Clone, PartialEq)]
+pub struct PromptTag {
+    pub text: String,
+    /// 嵌套括号的权重相乘后的结果，保留 4 位小数；LoRA 为网络强度
+    pub weight: f64,
+    pub kind: PromptTagKind,
+    /// 提示词编辑 `[from:to:when]` 中开始和停止生效的位置，小于 1 时为步数比例
+    pub starts_at: Option<f64>,
+    pub ends_at: Option<f64>,
+}
+
+// A1111 的 `(tag)` / `[tag]` 和 NAI 的 `{tag}` / `[tag]` 每层的倍率
+const A1111_EMPHASIS: f64 = 1.1;
+const NAI_EMPHASIS: f64 = 1.05;
+
+// 超过这个深度的括号按普通文本处理，避免递归过深
+const MAX_NESTING_DEPTH: usize = 64;
+
+/// 把提示词拆分为标签，标签之间以逗号或换行分隔
+///
+/// 括号不匹配时按 WebUI 的行为处理: 未闭合的括号作用到结尾，多余的右括号忽略
+#[flutter_rust_bridge::frb(sync)]
+pub fn parse_prompt(prompt: String, dialect: PromptDialect) -> Vec<PromptTag> {
+    let mut parser = PromptParser {
+        chars: prompt.chars().collect(),
+        position: 0,
+        depth: 0,
+        dialect,
+    };
+    let pieces = parser.parse_sequence(Context::Root).0;
+    split_tags(pieces, dialect)
+}
+
+/// 把提示词从一种语法转换为另一种，等价于 `parse_prompt` 后再 `render_prompt`
+#[flutter_rust_bridge::frb(sync)]
+pub fn convert_prompt(prompt: String, from: PromptDialect, to: PromptDialect) -> String {
+    render_prompt(parse_prompt(prompt, from), to)
+}
+
+/// 把标签按指定语法重新拼接为提示词，标签之间以 `, ` 分隔
+///
+/// 权重的取整规则:
+/// - A1111: 权重保留 2 位小数，为 1 时不加括号，否则写作 `(tag:1.1)`
+/// - NAI: 权重与 1.05 的整数次幂相差小于 0.005 时写作对应层数的 `{}` / `[]`，最多 20 层，
+///   否则写作保留 2 位小数的 `1.2::tag::`
+///
+/// 因此 `{{tag}}` (1.1025) 转换为 `(tag:1.1)`，`(tag:1.1)` 再转换回 `{{tag}}`；
+/// 20 层以内的 NAI 括号和 2 位小数的 A1111 权重都可以无损往返。
+///
+/// NAI 不支持的 LoRA、超网络、`BREAK` 和提示词编辑会被丢弃，只保留标签本身
+#[flutter_rust_bridge::frb(sync)]
+pub fn render_prompt(tags: Vec<PromptTag>, dialect: PromptDialect) -> String {
+    tags.iter()
+        .filter_map(|tag| match dialect {
+            PromptDialect::A1111 => Some(render_a1111_tag(tag)),
+            PromptDialect::NovelAi => render_nai_tag(tag),
+        })
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+// 解析过程中的片段，权重是相对于外层括号的倍率
+#[derive(Debug, Clone)]
+enum Piece {
+    Text {
+        text: String,
+        weight: f64,
+        starts_at: Option<f64>,
+        ends_at: Option<f64>,
+    },
+    Network {
+        kind: PromptTagKind,
+        name: String,
+        weight: f64,
+    },
+}
+
+impl Piece {
+    fn text(text: impl Into<String>) -> Piece {
+        Piece::Text {
+            text: text.into(),
+            weight: 1.0,
+            starts_at: None,
+            ends_at: None,
+        }
+    }
+
+    fn scale(&mut self, factor: f64) {
+        if let Piece::Text { weight, .. } = self {
+            *weight *= factor;
+        }
+    }
+
+    // 嵌套的提示词编辑以内层为准
+    fn schedule(&mut self, start: Option<f64>, end: Option<f64>) {
+        if let Piece::Text {
+            starts_at, ends_at, ..
+        } = self
+        {
+            *starts_at = starts_at.or(start);
+            *ends_at = ends_at.or(end);
+        }
+    }
+}
+
+#[derive(Clone, Copy, PartialEq)]
+enum Context {
+    Root,
+    // A1111 `(...)`
+    Paren,
+    // A1111 `[...]`，其中的 `:` 和 `|` 是分隔符
+    Bracket,
+    // NAI `{...}`
+    Brace,
+    // NAI `[...]`
+    NaiBracket,
+    // NAI `1.2::...::`
+    NaiWeight,
+}
+
+// 序列结束的原因
+#[derive(Clone, Copy, PartialEq)]
+enum End {
+    Eof,
+    Close,
+    Separator(char),
+    Weight(f64),
+}
+
+struct PromptParser {
+    chars: Vec<char>,
+    position: usize,
+    depth: usize,
+    dialect: PromptDialect,
+}
+
+impl PromptParser {
+    fn peek(&self, offset: usize) -> Option<char> {
+        self.chars.get(self.position + offset).copied()
+    }
+
+    fn parse_nested(&mut self, context: Context) -> (Vec<Piece>, End) {
+        self.depth += 1;
+        let result = self.parse_sequence(context);
+        self.depth -= 1;
+        result
+    }
+
+    fn parse_sequence(&mut self, context: Context) -> (Vec<Piece>, End) {
+        let can_nest = self.depth < MAX_NESTING_DEPTH;
+        let mut pieces = vec![];
+        let mut text = String::new();
+        macro_rules! flush {
+            () => {
+                if !text.is_empty() {
+                    pieces.push(Piece::text(std::mem::take(&mut text)));
+                }
+            };
+        }
+
+        while let Some(c) = self.peek(0) {
+            match (self.dialect, c) {
+                (PromptDialect::A1111, '\\') if self.peek(1).is_some() => {
+                    text.extend(self.peek(1));
+                    self.position += 2;
+                }
+                (PromptDialect::A1111, '(') if can_nest => {
+                    flush!();
+                    self.position += 1;
+                    let (mut inner, end) = self.parse_nested(Context::Paren);
+                    let factor = match end {
+                        End::Weight(weight) => weight,
+                        _ => A1111_EMPHASIS,
+                    };
+                    inner.iter_mut().for_each(|piece| piece.scale(factor));
+                    pieces.extend(inner);
+                }
+                (PromptDialect::A1111, '[') if can_nest => {
+                    flush!();
+                    self.position += 1;
+                    pieces.extend(self.parse_a1111_bracket());
+                }
+                (PromptDialect::A1111, '<') => {
+                    flush!();
+                    match self.parse_network() {
+                        Some(piece) => pieces.push(piece),
+                        None => {
+                            text.push(c);
+                            self.position += 1;
+                        }
+                    }
+                }
+                (PromptDialect::A1111, ':') if context == Context::Paren => {
+                    match self.parse_paren_weight() {
+                        Some(weight) => {
+                            flush!();
+                            return (pieces, End::Weight(weight));
+                        }
+                        None => {
+                            text.push(c);
+                            self.position += 1;
+                        }
+                    }
+                }
+                (PromptDialect::A1111, ':' | '|') if context == Context::Bracket => {
+                    flush!();
+                    self.position += 1;
+                    return (pieces, End::Separator(c));
+                }
+                (PromptDialect::A1111, ')') | (PromptDialect::A1111, ']') => {
+                    self.position += 1;
+                    let expected = if c == ')' {
+                        Context::Paren
+                    } else {
+                        Context::Bracket
+                    };
+                    if context == expected {
+                        flush!();
+                        return (pieces, End::Close);
+                    }
+                }
+                (PromptDialect::NovelAi, '{' | '[') if can_nest => {
+                    flush!();
+                    self.position += 1;
+                    let (inner_context, factor) = if c == '{' {
+                        (Context::Brace, NAI_EMPHASIS)
+                    } else {
+                        (Context::NaiBracket, 1.0 / NAI_EMPHASIS)
+                    };
+                    let (mut inner, _) = self.parse_nested(inner_context);
+                    inner.iter_mut().for_each(|piece| piece.scale(factor));
+                    pieces.extend(inner);
+                }
+                (PromptDialect::NovelAi, '}' | ']') => {
+                    self.position += 1;
+                    let expected = if c == '}' {
+                        Context::Brace
+                    } else {
+                        Context::NaiBracket
+                    };
+                    if context == expected {
+                        flush!();
+                        return (pieces, End::Close);
+                    }
+                }
+                (PromptDialect::NovelAi, ':') if self.peek(1) == Some(':') => {
+                    self.position += 2;
+                    flush!();
+                    if context == Context::NaiWeight {
+                        return (pieces, End::Close);
+                    }
+                    // 没有对应权重的 `::` 与逗号一样分隔标签
+                    pieces.push(Piece::text(","));
+                }
+                (PromptDialect::NovelAi, _) if can_nest && self.at_tag_start(&text) => {
+                    match self.parse_nai_weight() {
+                        Some(weight) => {
+                            flush!();
+                            let (mut inner, _) = self.parse_nested(Context::NaiWeight);
+                            inner.iter_mut().for_each(|piece| piece.scale(weight));
+                            pieces.extend(inner);
+                        }
+                        None => {
+                            text.push(c);
+                            self.position += 1;
+                        }
+                    }
+                }
+                _ => {
+                    text.push(c);
+                    self.position += 1;
+                }
+            }
+        }
+        flush!();
+        (pieces, End::Eof)
+    }
+
+    // `(tag:1.2)` 中冒号之后的数字，后面必须紧跟右括号。只向后扫描数字可能包含的字符，
+    // 不会因为每个冒号都查找到结尾而变成平方复杂度
+    fn parse_paren_weight(&mut self) -> Option<f64> {
+        let rest = &self.chars[self.position + 1..];
+        let close = rest.iter().position(|c| {
+            !(c.is_ascii_digit() || c.is_whitespace() || matches!(c, '.' | '-' | '+' | 'e' | 'E'))
+        })?;
+        if rest[close] != ')' {
+            return None;
+        }
+        let weight = rest[..close]
+            .iter()
+            .collect::<String>()
+            .trim()
+            .parse()
+            .ok()?;
+        self.position += close + 2;
+        Some(weight)
+    }
+
+    /// 解析 `[...]` 的内容，按分隔符区分提示词编辑、交替和减弱
+    fn parse_a1111_bracket(&mut self) -> Vec<Piece> {
+        let mut parts = vec![];
+        let mut separators = vec![];
+        loop {
+            let (pieces, end) = self.parse_nested(Context::Bracket);
+            parts.push(pieces);
+            match end {
+                End::Separator(separator) => separators.push(separator),
+                _ => break,
+            }
+        }
+
+        let when = parts.last().and_then(|pieces| pieces_number(pieces));
+        let all_colons = !separators.is_empty() && separators.iter().all(|c| *c == ':');
+        match (all_colons, parts.len(), when) {
+            // [from:to:when]，`from` 可以为空
+            (true, 3, Some(when)) => {
+                let mut from = parts.remove(0);
+                let mut to = parts.remove(0);
+                from.iter_mut()
+                    .for_each(|piece| piece.schedule(None, Some(when)));
+                to.iter_mut()
+                    .for_each(|piece| piece.schedule(Some(when), None));
+                from.into_iter().chain(to).collect()
+            }
+            // [to:when]
+            (true, 2, Some(when)) => {
+                let mut to = parts.remove(0);
+                to.iter_mut()
+                    .for_each(|piece| piece.schedule(Some(when), None));
+                to
+            }
+            // [a|b]，每一步轮流使用，权重不变，各选项分别作为独立的标签
+            _ if !separators.is_empty() && separators.iter().all(|c| *c == '|') => {
+                let mut pieces = vec![];
+                for (index, part) in parts.into_iter().enumerate() {
+                    if index > 0 {
+                        pieces.push(Piece::text(","));
+                    }
+                    pieces.extend(part);
+                }
+                pieces
+            }
+            // 普通的减弱，分隔符按原文保留
+            _ => {
+                let mut pieces = vec![];
+                for (index, part) in parts.into_iter().enumerate() {
+                    if index > 0 {
+                        pieces.push(Piece::text(separators[index - 1]));
+                    }
+                    pieces.extend(part);
+                }
+                pieces
+                    .iter_mut()
+                    .for_each(|piece| piece.scale(1.0 / A1111_EMPHASIS));
+                pieces
+            }
+        }
+    }
+
+    // `<lora:name:0.8>`、`<lyco:name>` 和 `<hypernet:name:0.5>`，其他尖括号内容按普通文本处理。
+    // 类型只能是单词，名称中不会有 `<` 和换行，遇到时立即停止，避免每个 `<` 都扫描到结尾
+    fn parse_network(&mut self) -> Option<Piece> {
+        let rest = &self.chars[self.position + 1..];
+        let kind_end = rest
+            .iter()
+            .position(|c| !(c.is_alphanumeric() || *c == '_'))?;
+        if rest[kind_end] != ':' {
+            return None;
+        }
+        let close = rest
+            .iter()
+            .skip(kind_end)
+            .position(|c| matches!(c, '>' | '<' | '\n'))?
+            + kind_end;
+        if rest[close] != '>' {
+            return None;
+        }
+        let kind: String = rest[..kind_end].iter().collect();
+        let content: String = rest[kind_end + 1..close].iter().collect();
+        let mut fields = content.split(':');
+        let kind = match kind.as_str() {
+            "lora" | "lyco" => PromptTagKind::Lora,
+            "hypernet" => PromptTagKind::Hypernetwork,
+            _ => return None,
+        };
+        let name = fields.next()?.trim().to_string();
+        if name.is_empty() {
+            return None;
+        }
+        let weight = fields
+            .next()
+            .and_then(|weight| weight.trim().parse().ok())
+            .unwrap_or(1.0);
+        self.position += close + 2;
+        Some(Piece::Network { kind, name, weight })
+    }
+
+    // NAI 的权重只出现在标签开头
+    fn at_tag_start(&self, text: &str) -> bool {
+        let c = self.peek(0);
+        (c.is_some_and(|c| c.is_ascii_digit() || c == '-' || c == '.'))
+            && text
+                .chars()
+                .last()
+                .is_none_or(|last| last == ',' || last.is_whitespace())
+    }
+
+    // `1.2::`、`-1::`，成功时跳过数字和 `::`
+    fn parse_nai_weight(&mut self) -> Option<f64> {
+        let rest = &self.chars[self.position..];
+        let length = rest
+            .iter()
+            .position(|c| !(c.is_ascii_digit() || *c == '.' || *c == '-'))?;
+        if rest.get(length..length + 2) != Some(&[':', ':']) {
+            return None;
+        }
+        let weight = rest[..length].iter().collect::<String>().parse().ok()?;
+        self.position += length + 2;
+        Some(weight)
+    }
+}
+
+fn pieces_number(pieces: &[Piece]) -> Option<f64> {
+    match pieces {
+        [Piece::Text { text, .. }] => text.trim().parse().ok(),
+        _ => None,
+    }
+}
+
+// 按逗号和换行切分文本片段；相邻片段权重不同时也作为不同的标签
+fn split_tags(pieces: Vec<Piece>, dialect: PromptDialect) -> Vec<PromptTag> {
+    let mut tags = vec![];
+    let mut current: Option<PromptTag> = None;
+    let flush = |current: &mut Option<PromptTag>, tags: &mut Vec<PromptTag>| {
+        if let Some(tag) = current.take() {
+            push_text_tag(tag, dialect, tags);
+        }
+    };
+
+    for piece in pieces {
+        match piece {
+            Piece::Network { kind, name, weight } => {
+                flush(&mut current, &mut tags);
+                tags.push(PromptTag {
+                    text: name,
+                    weight: round_weight(weight),
+                    kind,
+                    starts_at: None,
+                    ends_at: None,
+                });
+            }
+            Piece::Text {
+                text,
+                weight,
+                starts_at,
+                ends_at,
+            } => {
+                let weight = round_weight(weight);
+                for (index, fragment) in text.split([',', '\n']).enumerate() {
+                    if index > 0 {
+                        flush(&mut current, &mut tags);
+                    }
+                    let same_group = current.as_ref().is_some_and(|tag| {
+                        tag.weight == weight && tag.starts_at == starts_at && tag.ends_at == ends_at
+                    });
+                    match current.as_mut() {
+                        Some(tag) if same_group => tag.text.push_str(fragment),
+                        _ if fragment.trim().is_empty() => {}
+                        _ => {
+                            flush(&mut current, &mut tags);
+                            current = Some(PromptTag {
+                                text: fragment.to_string(),
+                                weight,
+                                kind: PromptTagKind::Tag,
+                                starts_at,
+                                ends_at,
+                            });
+                        }
+                    }
+                }
+            }
+        }
+    }
+    flush(&mut current, &mut tags);
+    tags
+}
+
+// 合并空白，A1111 中单独的 `BREAK` 拆分为分隔标记
+fn push_text_tag(tag: PromptTag, dialect: PromptDialect, tags: &mut Vec<PromptTag>) {
+    let mut words = vec![];
+    for word in tag.text.split_whitespace() {
+        if dialect == PromptDialect::A1111 && word == "BREAK" {
+            push_words(&mut words, &tag, tags);
+            tags.push(PromptTag {
+                text: word.to_string(),
+                weight: 1.0,
+                kind: PromptTagKind::Break,
+                starts_at: None,
+                ends_at: None,
+            });
+        } else {
+            words.push(word);
+        }
+    }
+    push_words(&mut words, &tag, tags);
+}
+
+fn push_words(words: &mut Vec<&str>, tag: &PromptTag, tags: &mut Vec<PromptTag>) {
+    if words.is_empty() {
+        return;
+    }
+    tags.push(PromptTag {
+        text: words.join(" "),
+        ..tag.clone()
+    });
+    words.clear();
+}
+
+// 避免 1.1 * 1.1 = 1.2100000000000002 这样的误差影响统计
+fn round_weight(weight: f64) -> f64 {
+    (weight * 10000.0).round() / 10000.0
+}
+
+// 目标语法中写出的权重和步数保留的小数位数
+const RENDERED_DECIMALS: i32 = 2;
+// NAI 权重与 1.05 的整数次幂足够接近且层数不多时才使用括号，
+// 层数再多时相邻两层的权重相差太小，保留 2 位小数后无法区分
+const NAI_EMPHASIS_TOLERANCE: f64 = 0.005;
+const MAX_NAI_EMPHASIS_LAYERS: i32 = 20;
+
+fn render_a1111_tag(tag: &PromptTag) -> String {
+    let weight = format_number(tag.weight);
+    let mut text = match tag.kind {
+        PromptTagKind::Break => return "BREAK".to_string(),
+        PromptTagKind::Lora => return format!("<lora:{}:{}>", tag.text, weight),
+        PromptTagKind::Hypernetwork => return format!("<hypernet:{}:{}>", tag.text, weight),
+        PromptTagKind::Tag => escape_a1111(&tag.text),
+    };
+    if weight != "1" {
+        text = format!("({}:{})", text, weight);
+    }
+    if let Some(starts_at) = tag.starts_at {
+        text = format!("[{}:{}]", text, format_number(starts_at));
+    }
+    if let Some(ends_at) = tag.ends_at {
+        text = format!("[{}::{}]", text, format_number(ends_at));
+    }
+    text
+}
+
+fn render_nai_tag(tag: &PromptTag) -> Option<String> {
+    if tag.kind != PromptTagKind::Tag {
+        return None;
+    }
+    if tag.weight <= 0.0 {
+        return Some(format!("{}::{}::", format_number(tag.weight), tag.text));
+    }
+
+    let layers = (tag.weight.ln() / NAI_EMPHASIS.ln()).round() as i32;
+    if layers.abs() > MAX_NAI_EMPHASIS_LAYERS
+        || (NAI_EMPHASIS.powi(layers) - tag.weight).abs() >= NAI_EMPHASIS_TOLERANCE
+    {
+        return Some(format!("{}::{}::", format_number(tag.weight), tag.text));
+    }
+    let (open, close) = if layers > 0 { ('{', '}') } else { ('[', ']') };
+    let layers = layers.unsigned_abs() as usize;
+    Some(format!(
+        "{}{}{}",
+        open.to_string().repeat(layers),
+        tag.text,
+        close.to_string().repeat(layers)
+    ))
+}
+
+// 转义会被 A1111 当作语法的字符
+fn escape_a1111(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for c in text.chars() {
+        if matches!(c, '(' | ')' | '[' | ']' | '\\') {
+            escaped.push('\\');
+        }
+        escaped.push(c);
+    }
+    escaped
+}
+
+// 保留 2 位小数并去掉多余的 0，例如 1.10 写作 1.1，1.00 写作 1
+fn format_number(value: f64) -> String {
+    let scale = 10f64.powi(RENDERED_DECIMALS);
+    let rounded = (value * scale).round() / scale;
+    let text = format!("{:.*}", RENDERED_DECIMALS as usize, rounded);
+    let text = text.trim_end_matches('0').trim_end_matches('.');
+    match text {
+        "-0" => "0".to_string(),
+        _ => text.to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use proptest::prelude::*;
+
+    use super::*;
+
+    fn nai_to_a1111(prompt: &str) -> String {
+        convert_prompt(
+            prompt.to_string(),
+            PromptDialect::NovelAi,
+            PromptDialect::A1111,
+        )
+    }
+
+    fn a1111_to_nai(prompt: &str) -> String {
+        convert_prompt(
+            prompt.to_string(),
+            PromptDialect::A1111,
+            PromptDialect::NovelAi,
+        )
+    }
+
+    #[test]
+    fn converts_nai_emphasis_to_explicit_weights() {
+        assert_eq!(
+            nai_to_a1111("1girl, {{blue eyes}}, [[[hat]]], 1.3::red hair::"),
+            "1girl, (blue eyes:1.1), (hat:0.86), (red hair:1.3)"
+        );
+    }
+
+    #[test]
+    fn converts_explicit_weights_to_nai_emphasis() {
+        assert_eq!(
+            a1111_to_nai("1girl, (blue eyes:1.1), ((smile)), [hat], (red hair:1.3)"),
+            "1girl, {{blue eyes}}, 1.21::smile::, [[hat]], 1.3::red hair::"
+        );
+    }
+
+    #[test]
+    fn drops_syntax_nai_does_not_support() {
+        assert_eq!(
+            a1111_to_nai("<lora:style:0.8>, cat BREAK [dog:0.5], \\(text\\)"),
+            "cat, dog, (text)"
+        );
+    }
+
+    #[test]
+    fn escapes_and_schedules_a1111_output() {
+        let prompt = "\\(text\\), [(dog:1.2):0.5], [cat::0.3], <lora:style:0.8>, BREAK";
+        let converted = convert_prompt(
+            prompt.to_string(),
+            PromptDialect::A1111,
+            PromptDialect::A1111,
+        );
+        assert_eq!(converted, prompt);
+    }
+
+    fn tag(text: &str, weight: f64, kind: PromptTagKind) -> PromptTag {
+        PromptTag {
+            text: text.to_string(),
+            weight,
+            kind,
+            starts_at: None,
+            ends_at: None,
+        }
+    }
+
+    #[test]
+    fn parses_prompt_editing() {
+        let tags = parse_prompt("[cat:dog:0.4], [:hat:10]".to_string(), PromptDialect::A1111);
+        let schedules: Vec<_> = tags
+            .iter()
+            .map(|tag| (tag.text.as_str(), tag.starts_at, tag.ends_at))
+            .collect();
+        assert_eq!(
+            schedules,
+            [
+                ("cat", None, Some(0.4)),
+                ("dog", Some(0.4), None),
+                ("hat", Some(10.0), None)
+            ]
+        );
+    }
+
+    #[test]
+    fn parses_break_and_networks() {
+        let tags = parse_prompt(
+            "cat BREAK dog, <lora:style:0.8>, <hypernet:net>, <other:x>".to_string(),
+            PromptDialect::A1111,
+        );
+        assert_eq!(
+            tags,
+            [
+                tag("cat", 1.0, PromptTagKind::Tag),
+                tag("BREAK", 1.0, PromptTagKind::Break),
+                tag("dog", 1.0, PromptTagKind::Tag),
+                tag("style", 0.8, PromptTagKind::Lora),
+                tag("net", 1.0, PromptTagKind::Hypernetwork),
+                tag("<other:x>", 1.0, PromptTagKind::Tag),
+            ]
+        );
+    }
+
+    #[test]
+    fn multiplies_nested_nai_braces() {
+        let tags = parse_prompt(
+            "{cat, {dog, [hat]}}, 1.5::{fox}::".to_string(),
+            PromptDialect::NovelAi,
+        );
+        let weights: Vec<_> = tags
+            .iter()
+            .map(|tag| (tag.text.as_str(), tag.weight))
+            .collect();
+        assert_eq!(
+            weights,
+            [
+                ("cat", 1.05),
+                ("dog", 1.1025),
+                ("hat", 1.05),
+                ("fox", 1.575)
+            ]
+        );
+    }
+
+    #[test]
+    fn unbalanced_brackets_follow_webui() {
+        // 未闭合的括号作用到结尾，多余的右括号忽略
+        let tags = parse_prompt("(cat, dog), hat)]".to_string(), PromptDialect::A1111);
+        let weights: Vec<_> = tags
+            .iter()
+            .map(|tag| (tag.text.as_str(), tag.weight))
+            .collect();
+        assert_eq!(weights, [("cat", 1.1), ("dog", 1.1), ("hat", 1.0)]);
+
+        let tags = parse_prompt("((cat, dog:1.2".to_string(), PromptDialect::A1111);
+        assert!(tags.iter().all(|tag| tag.weight == 1.21));
+        let tags = parse_prompt("{{cat}, ]dog".to_string(), PromptDialect::NovelAi);
+        let weights: Vec<_> = tags
+            .iter()
+            .map(|tag| (tag.text.as_str(), tag.weight))
+            .collect();
+        assert_eq!(weights, [("cat", 1.1025), ("dog", 1.05)]);
+    }
+
+    #[test]
+    fn long_unclosed_weights_are_parsed_quickly() {
+        let prompt = format!("({}", "a:".repeat(100_000));
+        let tags = parse_prompt(prompt, PromptDialect::A1111);
+        assert_eq!(tags.len(), 1);
+    }
+
+    #[test]
+    fn unclosed_angle_brackets_are_parsed_quickly() {
+        let tags = parse_prompt("<".repeat(100_000), PromptDialect::A1111);
+        assert_eq!(tags.len(), 1);
+        let tags = parse_prompt("<lora:a".repeat(50_000), PromptDialect::A1111);
+        assert_eq!(tags.len(), 1);
+        assert_eq!(tags[0].kind, PromptTagKind::Tag);
+    }
+
+    #[test]
+    fn stray_nai_weight_end_separates_tags() {
+        let tags = parse_prompt("cat::dog, 1.2::fox::".to_string(), PromptDialect::NovelAi);
+        let weights: Vec<_> = tags
+            .iter()
+            .map(|tag| (tag.text.as_str(), tag.weight))
+            .collect();
+        assert_eq!(weights, [("cat", 1.0), ("dog", 1.0), ("fox", 1.2)]);
+    }
+
+    proptest! {
+        // NAI 括号写法在 A1111 中往返后保持不变
+        #[test]
+        fn nai_emphasis_round_trips(
+            tags in prop::collection::vec(("[a-z]{1,8}( [a-z]{1,8})?", -20i32..=20), 1..8)
+        ) {
+            let prompt = tags
+                .iter()
+                .map(|(text, layers)| {
+                    let (open, close) = if *layers > 0 { ("{", "}") } else { ("[", "]") };
+                    let layers = layers.unsigned_abs() as usize;
+                    format!("{}{}{}", open.repeat(layers), text, close.repeat(layers))
+                })
+                .collect::<Vec<_>>()
+                .join(", ");
+            prop_assert_eq!(a1111_to_nai(&nai_to_a1111(&prompt)), prompt);
+        }
+
+        // 保留 2 位小数的 A1111 权重在 NAI 中往返后保持不变
+        #[test]
+        fn a1111_weights_round_trip(
+            tags in prop::collection::vec(("[a-z]{1,8}( [a-z]{1,8})?", 1u32..300), 1..8)
+        ) {
+            let prompt = tags
+                .iter()
+                .map(|(text, weight)| match *weight {
+                    100 => text.clone(),
+                    _ => format!("({}:{})", text, format_number(*weight as f64 / 100.0)),
+                })
+                .collect::<Vec<_>>()
+                .join(", ");
+            prop_assert_eq!(nai_to_a1111(&a1111_to_nai(&prompt)), prompt);
+        }
+    }
+}
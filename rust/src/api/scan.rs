@@ -1,15 +1,13 @@
-use crate::api::metadata::extract_metadata;
+use crate::api::c2pa::describe_c2pa_manifests;
+use crate::api::error::{MetadataError, MetadataErrorKind};
+use crate::api::metadata::read_image_info;
 use crate::frb_generated::StreamSink;
 use anyhow::Error;
 use rayon::prelude::*;
 use std::collections::HashMap;
 use std::fs;
+use std::sync::atomic::{AtomicU32, Ordering};
 use std::time::{SystemTime, UNIX_EPOCH};
-use std::{
-    fs::File,
-    io::Read,
-    sync::atomic::{AtomicU32, Ordering},
-};
 
 #[derive(Debug, Clone)]
 pub struct ScanProgress {
@@ -24,7 +22,11 @@ pub struct ImageScanResult {
     pub file_path: String,
     pub file_last_modified: u64,
     pub image_aspect_ratio: Option<f64>,
+    /// 用于搜索的元数据文本，只有 C2PA 清单的图片使用清单摘要
     pub metadata_text: Option<String>,
+    /// 文件无法读取或解析时的错误类别和描述。隐写数据损坏时也会记录，此时其余字段仍然有效
+    pub error_kind: Option<MetadataErrorKind>,
+    pub error_message: Option<String>,
 }
 
 #[derive(Debug, Clone)]
@@ -34,34 +36,41 @@ pub struct FolderScanResult {
     pub total_image_count: u32,
 }
 
-// 处理单个图片的函数
-fn process_single_image(image_path: &str) -> Result<ImageScanResult, Error> {
-    // 读取文件内容
-    let mut file = File::open(image_path)?;
-    let mut file_bytes = vec![];
-    file.read_to_end(&mut file_bytes)?;
+// 处理单个图片的函数，失败时在结果中记录错误，而不是丢弃该文件
+fn process_single_image(image_path: &str) -> ImageScanResult {
+    // 无法获取修改时间时记为 0，下次扫描会重新处理
+    let file_last_modified = fs::metadata(image_path)
+        .and_then(|metadata| metadata.modified())
+        .ok()
+        .and_then(|modified_time| modified_time.duration_since(UNIX_EPOCH).ok())
+        .map(|duration| duration.as_secs())
+        .unwrap_or(0);
 
-    let modified_time = file.metadata()?.modified()?;
-    let file_last_modified = modified_time.duration_since(UNIX_EPOCH)?.as_secs();
+    // 读取文件内容并提取数据
+    let result = fs::read(image_path)
+        .map_err(MetadataError::from)
+        .and_then(|file_bytes| read_image_info(&file_bytes));
 
-    // 提取数据
-    let metadata_result = extract_metadata(&file_bytes);
-    if metadata_result.is_ok() {
-        let data = metadata_result.unwrap();
-        return Ok(ImageScanResult {
+    match result {
+        Ok(data) => ImageScanResult {
             file_path: image_path.to_string(),
-            file_last_modified: file_last_modified,
+            file_last_modified,
             image_aspect_ratio: Some(data.aspect_ratio),
-            metadata_text: data.metadata_string,
-        });
+            metadata_text: data
+                .metadata_string
+                .or_else(|| describe_c2pa_manifests(&data.c2pa_manifests)),
+            error_kind: data.stealth_error_kind,
+            error_message: data.stealth_error_message,
+        },
+        Err(error) => ImageScanResult {
+            file_path: image_path.to_string(),
+            file_last_modified,
+            image_aspect_ratio: None,
+            metadata_text: None,
+            error_kind: Some(error.kind()),
+            error_message: Some(error.to_string()),
+        },
     }
-
-    Ok(ImageScanResult {
-        file_path: image_path.to_string(),
-        file_last_modified: file_last_modified,
-        image_aspect_ratio: None,
-        metadata_text: None,
-    })
 }
 
 #[flutter_rust_bridge::frb]
@@ -106,7 +115,7 @@ pub fn scan_folder(
     let total_to_process = images_to_process.len() as u32;
     let processed_count = AtomicU32::new(0);
     sink.add(ScanProgress {
-        total_to_process: total_to_process,
+        total_to_process,
         processed: 0,
         image_scan_results: None,
         folder_scan_result: None,
@@ -134,7 +143,7 @@ pub fn scan_folder(
     // 并行处理所有需要更新的图片
     let processing_results: Vec<ImageScanResult> = images_to_process
         .par_iter()
-        .filter_map(|path| {
+        .map(|path| {
             let process_result = process_single_image(path);
             // 每处理完一个，就原子性地增加计数器并发送进度
             let count = processed_count.fetch_add(1, Ordering::SeqCst) + 1;
@@ -144,13 +153,13 @@ pub fn scan_folder(
                 image_scan_results: None,
                 folder_scan_result: None,
             });
-            process_result.ok()
+            process_result
         })
         .collect();
 
     // 所有图片处理完毕，构建最终的文件夹扫描结果
     let folder_result = FolderScanResult {
-        folder_path: folder_path,
+        folder_path,
         total_image_count: all_files_in_folder.len() as u32, // 文件夹内图片总数
         scan_timestamp: SystemTime::now().duration_since(UNIX_EPOCH)?.as_secs(),
     };
@@ -0,0 +1,584 @@
+use std::io::Cursor;
+
+use anyhow::{anyhow, Error};
+use image::{DynamicImage, ImageFormat};
+
+use crate::api::c2pa::{has_c2pa_manifest, jpeg_c2pa_instances, jumbf_instance, JPEG_APP11};
+use crate::api::metadata::{
+    find_stealth_channel, may_contain_stealth_data, png_chunks, read_image_info, webp_chunks,
+    ImageInfo, PngTextChunk, PngTextChunkType, StealthChannel, PNG_SIGNATURE,
+};
+use crate::api::writer::{
+    build_exif, complete_png_chunks, complete_webp_chunks, jpeg_segments, rewrite_metadata,
+    riff_container, write_png_chunk, write_webp_chunk, Chunk, EXIF_HEADER, JPEG_APP1,
+    WEBP_FLAG_EXIF, WEBP_FLAG_XMP,
+};
+use crate::api::xmp::{XMP_EXTENSION_HEADER, XMP_HEADER, XMP_KEYWORD};
+
+/// 导出时要删除的元数据
+#[derive(Debug, Clone)]
+pub struct StripOptions {
+    /// PNG 的 tEXt / zTXt / iTXt 块 (包括 PNG 中的 XMP) 和 JPEG 的 COM 注释
+    pub remove_text_chunks: bool,
+    /// PNG 的 eXIf 块、JPEG 的 APP1 EXIF 段和 WebP 的 `EXIF` 块
+    pub remove_exif: bool,
+    /// PNG 的 `XML:com.adobe.xmp` 文本块、JPEG 的 APP1 XMP 段和 WebP 的 `XMP ` 块
+    pub remove_xmp: bool,
+    /// C2PA 内容凭据: PNG 的 `caBX` 块、JPEG 的 APP11 JUMBF 段和 WebP 的 `C2PA` 块
+    pub remove_c2pa: bool,
+    /// 像素最低位中的 stealth 数据，普通的删除数据块无法去除，需要无损地重新编码像素
+    pub remove_stealth: bool,
+    /// 只保留正向提示词，种子、模型路径等其他参数都不保留
+    ///
+    /// 无论其他选项如何，原有的文本 (PNG 的文本块、JPEG 的 COM 注释、EXIF 的 UserComment、
+    /// ImageDescription 和 XPComment) 和 stealth 数据都会删除，再把提示词写入 PNG 的
+    /// `Description` 文本块或 EXIF ImageDescription。其余 EXIF 字段和 XMP 仍按
+    /// `remove_exif` / `remove_xmp` 处理
+    pub keep_prompt: bool,
+}
+
+const JPEG_COM: u8 = 0xFE;
+
+// 重新编码像素后与颜色类型或动画帧相关的辅助块不再有效
+const PIXEL_DEPENDENT_CHUNKS: [&[u8; 4]; 8] = [
+    b"tRNS", b"bKGD", b"sBIT", b"hIST", b"sPLT", b"acTL", b"fcTL", b"fdAT",
+];
+
+/// 生成去除元数据后的图片副本，格式保持不变
+///
+/// 完成后重新提取一次元数据，确认选中的内容都已删除，否则返回错误
+pub fn strip_metadata(input_bytes: &[u8], options: StripOptions) -> Result<Vec<u8>, Error> {
+    let info = read_image_info(input_bytes)?;
+    let prompt = if options.keep_prompt {
+        original_prompt(&info)
+    } else {
+        None
+    };
+
+    // stealth 数据只可能存在于 PNG 和无损 WebP，找到 magic 时才重新编码像素。
+    // 隐写数据中也有种子等参数，只保留提示词时同样要删除
+    let remove_stealth = options.remove_stealth || options.keep_prompt;
+    let cleaned_image = if remove_stealth && may_contain_stealth_data(input_bytes) {
+        let img = image::load_from_memory(input_bytes)?;
+        find_stealth_channel(&img).map(|channel| clear_stealth_bits(&img, channel))
+    } else {
+        None
+    };
+
+    let mime_type = infer::get(input_bytes)
+        .map(|kind| kind.mime_type())
+        .ok_or_else(|| anyhow!("无法识别的文件类型 (Unknown file type)"))?;
+    let mut output = match mime_type {
+        "image/png" => strip_png(input_bytes, &options, cleaned_image.as_ref())?,
+        "image/jpeg" => strip_jpeg(input_bytes, &options)?,
+        "image/webp" => strip_webp(input_bytes, &options, cleaned_image.as_ref())?,
+        mime_type => {
+            return Err(anyhow!(
+                "不支持处理该文件类型 (Unsupported file type for stripping): {mime_type}"
+            ))
+        }
+    };
+
+    if let Some(prompt) = &prompt {
+        output = write_prompt(&output, mime_type, prompt)?;
+    }
+    verify_stripped(&output, &options, prompt.as_deref())?;
+    Ok(output)
+}
+
+// 依次使用 NAI、A1111 和 ComfyUI 解析出的正向提示词
+fn original_prompt(info: &ImageInfo) -> Option<String> {
+    let nai = info
+        .nai_metadata
+        .as_ref()
+        .and_then(|nai| nai.prompt.clone());
+    let a1111 = || {
+        info.a1111_parameters
+            .as_ref()
+            .map(|a1111| a1111.prompt.clone())
+    };
+    let comfyui = || {
+        info.comfyui
+            .as_ref()
+            .and_then(|comfyui| comfyui.summary.as_ref())
+            .and_then(|summary| summary.positive_prompt.clone())
+    };
+    nai.or_else(a1111)
+        .or_else(comfyui)
+        .filter(|prompt| !prompt.trim().is_empty())
+}
+
+// alpha 的最低位全部置 1，完全不透明的像素恢复为 255；RGB 的最低位换成随机值
+fn clear_stealth_bits(img: &DynamicImage, channel: StealthChannel) -> DynamicImage {
+    let random_bit = |value: u8| (value & !1) | rand::random::<bool>() as u8;
+    match (channel, img.color().has_alpha()) {
+        (StealthChannel::Rgb, false) => {
+            let mut buffer = img.to_rgb8();
+            buffer
+                .iter_mut()
+                .for_each(|value| *value = random_bit(*value));
+            DynamicImage::ImageRgb8(buffer)
+        }
+        (StealthChannel::Rgb, true) => {
+            let mut buffer = img.to_rgba8();
+            for pixel in buffer.pixels_mut() {
+                for value in &mut pixel.0[..3] {
+                    *value = random_bit(*value);
+                }
+            }
+            DynamicImage::ImageRgba8(buffer)
+        }
+        (StealthChannel::Alpha, _) => {
+            let mut buffer = img.to_rgba8();
+            for pixel in buffer.pixels_mut() {
+                pixel[3] |= 1;
+            }
+            DynamicImage::ImageRgba8(buffer)
+        }
+    }
+}
+
+fn encode_image(img: &DynamicImage, format: ImageFormat) -> Result<Vec<u8>, Error> {
+    let mut output = Cursor::new(vec![]);
+    img.write_to(&mut output, format)?;
+    Ok(output.into_inner())
+}
+
+/// 删除 PNG 中选中的数据块
+///
+/// 重新编码像素时 IHDR、PLTE、IDAT 取自新编码的文件，原文件中与像素无关的辅助块
+/// (iCCP、pHYs 等) 保持原来的位置。IEND 之后的附加数据可能藏有其他内容，总是删除
+fn strip_png(
+    input_bytes: &[u8],
+    options: &StripOptions,
+    cleaned_image: Option<&DynamicImage>,
+) -> Result<Vec<u8>, Error> {
+    let (source_chunks, _) = complete_png_chunks(input_bytes)?;
+    let keep = |(chunk_type, data): &Chunk| match chunk_type {
+        b"tEXt" | b"zTXt" | b"iTXt" => {
+            let is_xmp = data.split(|byte| *byte == 0).next() == Some(XMP_KEYWORD.as_bytes());
+            !options.remove_text_chunks
+                && if is_xmp {
+                    !options.remove_xmp
+                } else {
+                    !options.keep_prompt
+                }
+        }
+        b"eXIf" => !options.remove_exif,
+        b"caBX" => !options.remove_c2pa,
+        _ => true,
+    };
+    // 只保留提示词时，保留下来的 eXIf 也要删除其中的文本，没有剩下字段时删除整个 eXIf
+    let exif_without_text = match source_chunks
+        .iter()
+        .find(|(chunk_type, _)| chunk_type == b"eXIf")
+    {
+        Some((_, data)) if options.keep_prompt && !options.remove_exif => {
+            Some(build_exif(Some(data), None, None)?)
+        }
+        _ => None,
+    };
+    let source_chunks: Vec<Chunk> = source_chunks
+        .into_iter()
+        .filter(keep)
+        .filter_map(|(chunk_type, data)| match &exif_without_text {
+            Some(exif) if &chunk_type == b"eXIf" => exif.as_deref().map(|exif| (chunk_type, exif)),
+            _ => Some((chunk_type, data)),
+        })
+        .collect();
+
+    let mut output = PNG_SIGNATURE.to_vec();
+    let Some(img) = cleaned_image else {
+        for (chunk_type, data) in &source_chunks {
+            write_png_chunk(&mut output, chunk_type, data);
+        }
+        return Ok(output);
+    };
+
+    let encoded = encode_image(img, ImageFormat::Png)?;
+    let encoded_chunks: Vec<Chunk> = png_chunks(&encoded).collect();
+    let ancillary = |chunk_type: &[u8; 4]| {
+        chunk_type[0].is_ascii_lowercase() && !PIXEL_DEPENDENT_CHUNKS.contains(&chunk_type)
+    };
+    let first_idat = source_chunks
+        .iter()
+        .position(|(chunk_type, _)| chunk_type == b"IDAT")
+        .unwrap_or(source_chunks.len());
+    let (before_idat, after_idat) = source_chunks.split_at(first_idat);
+
+    for (chunk_type, data) in encoded_chunks
+        .iter()
+        .filter(|(chunk_type, _)| chunk_type == b"IHDR")
+    {
+        write_png_chunk(&mut output, chunk_type, data);
+    }
+    for (chunk_type, data) in before_idat
+        .iter()
+        .filter(|(chunk_type, _)| ancillary(chunk_type))
+    {
+        write_png_chunk(&mut output, chunk_type, data);
+    }
+    for (chunk_type, data) in encoded_chunks
+        .iter()
+        .filter(|(chunk_type, _)| chunk_type != b"IHDR" && chunk_type != b"IEND")
+    {
+        write_png_chunk(&mut output, chunk_type, data);
+    }
+    for (chunk_type, data) in after_idat
+        .iter()
+        .filter(|(chunk_type, _)| ancillary(chunk_type))
+    {
+        write_png_chunk(&mut output, chunk_type, data);
+    }
+    write_png_chunk(&mut output, b"IEND", &[]);
+    Ok(output)
+}
+
+// 删除 JPEG 中选中的段，SOS 之后的压缩数据原样复制
+fn strip_jpeg(input_bytes: &[u8], options: &StripOptions) -> Result<Vec<u8>, Error> {
+    let segments = jpeg_segments(input_bytes)?;
+    // C2PA 的 JUMBF 可能分成多个 APP11 段，按实例编号删除全部分段
+    let c2pa_instances = if options.remove_c2pa {
+        jpeg_c2pa_instances(&segments)
+    } else {
+        vec![]
+    };
+
+    let mut output = input_bytes[..2].to_vec();
+    let mut start = 2;
+    for (marker, data, end) in segments {
+        let remove = match marker {
+            JPEG_APP1 if data.starts_with(EXIF_HEADER) => options.remove_exif,
+            JPEG_APP1 if data.starts_with(XMP_HEADER) || data.starts_with(XMP_EXTENSION_HEADER) => {
+                options.remove_xmp
+            }
+            JPEG_COM => options.remove_text_chunks || options.keep_prompt,
+            JPEG_APP11 => {
+                jumbf_instance(data).is_some_and(|instance| c2pa_instances.contains(&instance))
+            }
+            _ => false,
+        };
+        if !remove {
+            output.extend_from_slice(&input_bytes[start..end]);
+        }
+        start = end;
+    }
+    output.extend_from_slice(&input_bytes[start..]);
+    Ok(output)
+}
+
+// 删除 WebP 中选中的块并更新 VP8X 标志，重新编码像素时替换 VP8L 块
+fn strip_webp(
+    input_bytes: &[u8],
+    options: &StripOptions,
+    cleaned_image: Option<&DynamicImage>,
+) -> Result<Vec<u8>, Error> {
+    let encoded = cleaned_image
+        .map(|img| encode_image(img, ImageFormat::WebP))
+        .transpose()?;
+    let encoded_vp8l = encoded.as_deref().and_then(|encoded| {
+        webp_chunks(encoded)
+            .find(|(fourcc, _)| fourcc == b"VP8L")
+            .map(|(_, data)| data)
+    });
+
+    let mut body = b"WEBP".to_vec();
+    for (fourcc, data) in complete_webp_chunks(input_bytes)? {
+        match &fourcc {
+            b"EXIF" if options.remove_exif => {}
+            b"XMP " if options.remove_xmp => {}
+            b"C2PA" if options.remove_c2pa => {}
+            b"VP8X" => {
+                let mut vp8x = data.to_vec();
+                if let Some(flags) = vp8x.first_mut() {
+                    if options.remove_exif {
+                        *flags &= !WEBP_FLAG_EXIF;
+                    }
+                    if options.remove_xmp {
+                        *flags &= !WEBP_FLAG_XMP;
+                    }
+                }
+                write_webp_chunk(&mut body, &fourcc, &vp8x);
+            }
+            b"VP8L" => write_webp_chunk(&mut body, &fourcc, encoded_vp8l.unwrap_or(data)),
+            _ => write_webp_chunk(&mut body, &fourcc, data),
+        }
+    }
+    Ok(riff_container(&body))
+}
+
+fn write_prompt(input_bytes: &[u8], mime_type: &str, prompt: &str) -> Result<Vec<u8>, Error> {
+    let (mut chunks, keyword) = if mime_type == "image/png" {
+        // PNG 需要带上保留下来的文本块，否则会被一起替换
+        (read_image_info(input_bytes)?.text_chunks, "Description")
+    } else {
+        (vec![], "ImageDescription")
+    };
+    chunks.push(PngTextChunk {
+        chunk_type: PngTextChunkType::Text,
+        keyword: keyword.to_string(),
+        text: prompt.to_string(),
+        language_tag: None,
+        translated_keyword: None,
+    });
+    rewrite_metadata(input_bytes, &chunks)
+}
+
+// 重新读取结果，除了写回的提示词之外不应再有选中的元数据
+fn verify_stripped(
+    output: &[u8],
+    options: &StripOptions,
+    prompt: Option<&str>,
+) -> Result<(), Error> {
+    let info = read_image_info(output)?;
+    let is_prompt = |text: &str| Some(text) == prompt;
+    let mut remaining = vec![];
+
+    if (options.remove_stealth || options.keep_prompt)
+        && may_contain_stealth_data(output)
+        && find_stealth_channel(&image::load_from_memory(output)?).is_some()
+    {
+        remaining.push("stealth");
+    }
+    // PNG 中的 XMP 也是文本块，保留 XMP 时只检查其他文本块
+    let text_remaining = info.text_chunks.iter().any(|chunk| {
+        !is_prompt(&chunk.text) && (chunk.keyword != XMP_KEYWORD || options.remove_text_chunks)
+    });
+    if (options.remove_text_chunks || options.keep_prompt) && text_remaining {
+        remaining.push("text chunks");
+    }
+    let is_png = infer::get(output).is_some_and(|kind| kind.mime_type() == "image/png");
+    // PNG 的 eXIf 不参与文本提取，直接检查数据块；其他格式检查 EXIF 中的文本
+    let exif_remaining = if is_png {
+        options.remove_exif && png_chunks(output).any(|(chunk_type, _)| &chunk_type == b"eXIf")
+    } else {
+        let exif_text = info
+            .metadata_string
+            .as_deref()
+            .filter(|text| !is_prompt(text));
+        (options.remove_exif || options.keep_prompt) && exif_text.is_some()
+    };
+    if exif_remaining {
+        remaining.push("EXIF");
+    }
+    if options.remove_xmp && info.xmp_packet.is_some() {
+        remaining.push("XMP");
+    }
+    if options.remove_c2pa && (!info.c2pa_manifests.is_empty() || has_c2pa_manifest(output)) {
+        remaining.push("C2PA");
+    }
+
+    if remaining.is_empty() {
+        Ok(())
+    } else {
+        Err(anyhow!(
+            "元数据未完全删除 (Metadata still present): {}",
+            remaining.join(", ")
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::api::generator::Generator;
+    use crate::api::metadata::embed_stealth_metadata;
+    use crate::test_fixtures::{
+        append_webp_chunk, insert_jpeg_segment, insert_png_chunk, make_text_chunk, source_image,
+        superbox, NAI_JSON,
+    };
+
+    fn manifest_store() -> Vec<u8> {
+        let assertions = superbox("c2pa.assertions", &[]);
+        superbox("c2pa", &[superbox("urn:uuid:test", &[assertions])])
+    }
+
+    fn with_c2pa(format: ImageFormat) -> Vec<u8> {
+        let source = source_image(16, 16, format, false);
+        let store = manifest_store();
+        match format {
+            ImageFormat::Png => insert_png_chunk(&source, b"caBX", &store),
+            ImageFormat::Jpeg => {
+                // `JP` + 实例编号 + 分段序号 + JUMBF
+                let mut segment = b"JP".to_vec();
+                segment.extend_from_slice(&1u16.to_be_bytes());
+                segment.extend_from_slice(&1u32.to_be_bytes());
+                segment.extend_from_slice(&store);
+                insert_jpeg_segment(&source, JPEG_APP11, &segment)
+            }
+            _ => append_webp_chunk(&source, b"C2PA", &store),
+        }
+    }
+
+    const PARAMETERS: &str =
+        "1girl, red hair\nNegative prompt: lowres\nSteps: 28, Sampler: Euler, Seed: 99";
+    const XMP: &str = r#"<x:xmpmeta xmlns:x="adobe:ns:meta/"/>"#;
+
+    fn keep_all() -> StripOptions {
+        StripOptions {
+            remove_text_chunks: false,
+            remove_exif: false,
+            remove_xmp: false,
+            remove_c2pa: false,
+            remove_stealth: false,
+            keep_prompt: false,
+        }
+    }
+
+    // 同时带有 stealth 数据、A1111 参数、XMP 文本块和 eXIf 块的 PNG
+    fn full_png() -> Vec<u8> {
+        let source = source_image(64, 64, ImageFormat::Png, true);
+        let stealth =
+            embed_stealth_metadata(&source, NAI_JSON.to_string(), StealthChannel::Alpha).unwrap();
+        let chunks = [
+            make_text_chunk("parameters", PARAMETERS),
+            make_text_chunk(XMP_KEYWORD, XMP),
+        ];
+        let png = rewrite_metadata(&stealth, &chunks).unwrap();
+        let exif = build_exif(None, Some("secret"), None).unwrap().unwrap();
+        insert_png_chunk(&png, b"eXIf", &exif)
+    }
+
+    // EXIF 中带有 A1111 参数，另有 APP1 XMP 段的 JPEG
+    fn full_jpeg() -> Vec<u8> {
+        let source = source_image(64, 64, ImageFormat::Jpeg, false);
+        let jpeg = rewrite_metadata(&source, &[make_text_chunk("parameters", PARAMETERS)]).unwrap();
+        insert_jpeg_segment(&jpeg, JPEG_APP1, &[XMP_HEADER, XMP.as_bytes()].concat())
+    }
+
+    fn has_exif_chunk(png: &[u8]) -> bool {
+        png_chunks(png).any(|(chunk_type, _)| &chunk_type == b"eXIf")
+    }
+
+    fn keywords(info: &ImageInfo) -> Vec<&str> {
+        info.text_chunks
+            .iter()
+            .map(|chunk| chunk.keyword.as_str())
+            .collect()
+    }
+
+    #[test]
+    fn text_chunks_are_removed_with_png_xmp() {
+        let options = StripOptions {
+            remove_text_chunks: true,
+            ..keep_all()
+        };
+        let output = strip_metadata(&full_png(), options).unwrap();
+
+        let info = read_image_info(&output).unwrap();
+        assert!(info.text_chunks.is_empty());
+        assert!(info.xmp_packet.is_none());
+        assert!(has_exif_chunk(&output));
+        assert_eq!(info.nai_metadata.unwrap().seed, Some(1234));
+    }
+
+    #[test]
+    fn exif_and_xmp_are_removed_separately() {
+        let png = full_png();
+        let remove_exif = StripOptions {
+            remove_exif: true,
+            ..keep_all()
+        };
+        let output = strip_metadata(&png, remove_exif.clone()).unwrap();
+        assert!(!has_exif_chunk(&output));
+        assert_eq!(
+            keywords(&read_image_info(&output).unwrap()),
+            ["parameters", XMP_KEYWORD]
+        );
+
+        let remove_xmp = StripOptions {
+            remove_xmp: true,
+            ..keep_all()
+        };
+        let output = strip_metadata(&png, remove_xmp.clone()).unwrap();
+        assert!(has_exif_chunk(&output));
+        assert_eq!(keywords(&read_image_info(&output).unwrap()), ["parameters"]);
+
+        let jpeg = full_jpeg();
+        let info = read_image_info(&strip_metadata(&jpeg, remove_exif).unwrap()).unwrap();
+        assert!(info.metadata_string.is_none());
+        assert_eq!(info.xmp_packet.as_deref(), Some(XMP));
+
+        let info = read_image_info(&strip_metadata(&jpeg, remove_xmp).unwrap()).unwrap();
+        assert!(info.xmp_packet.is_none());
+        assert_eq!(info.a1111_parameters.unwrap().seed, Some(99));
+    }
+
+    #[test]
+    fn stealth_data_is_removed_without_changing_colors() {
+        let png = full_png();
+        let options = StripOptions {
+            remove_stealth: true,
+            ..keep_all()
+        };
+        let output = strip_metadata(&png, options).unwrap();
+
+        let info = read_image_info(&output).unwrap();
+        assert!(info.nai_metadata.is_none());
+        assert_eq!(keywords(&info), ["parameters", XMP_KEYWORD]);
+        assert!(has_exif_chunk(&output));
+        let before = image::load_from_memory(&png).unwrap().to_rgb8();
+        let after = image::load_from_memory(&output).unwrap().to_rgb8();
+        assert_eq!(before, after);
+    }
+
+    #[test]
+    fn keep_prompt_writes_only_description() {
+        let options = StripOptions {
+            keep_prompt: true,
+            ..keep_all()
+        };
+        // 隐写数据中的 NAI 提示词优先，种子和反向提示词都不保留
+        let output = strip_metadata(&full_png(), options.clone()).unwrap();
+        let info = read_image_info(&output).unwrap();
+        let texts: Vec<_> = info
+            .text_chunks
+            .iter()
+            .map(|chunk| (chunk.keyword.as_str(), chunk.text.as_str()))
+            .collect();
+        // XMP 仍按 remove_xmp 处理
+        assert_eq!(texts, [(XMP_KEYWORD, XMP), ("Description", "1girl")]);
+        assert!(info.nai_metadata.is_none());
+        assert!(info.a1111_parameters.is_none());
+        assert!(!has_exif_chunk(&output));
+
+        let output = strip_metadata(&full_jpeg(), options).unwrap();
+        let info = read_image_info(&output).unwrap();
+        assert_eq!(info.metadata_string.as_deref(), Some("1girl, red hair"));
+        assert!(info.a1111_parameters.is_none());
+        assert_eq!(info.xmp_packet.as_deref(), Some(XMP));
+    }
+
+    #[test]
+    fn c2pa_is_kept_unless_selected() {
+        for format in [ImageFormat::Png, ImageFormat::Jpeg, ImageFormat::WebP] {
+            let input = with_c2pa(format);
+            assert_eq!(read_image_info(&input).unwrap().generator, Generator::C2pa);
+
+            let output = strip_metadata(
+                &input,
+                StripOptions {
+                    remove_exif: true,
+                    ..keep_all()
+                },
+            )
+            .unwrap();
+            assert_eq!(read_image_info(&output).unwrap().c2pa_manifests.len(), 1);
+
+            let output = strip_metadata(
+                &input,
+                StripOptions {
+                    remove_c2pa: true,
+                    ..keep_all()
+                },
+            )
+            .unwrap();
+            assert!(!has_c2pa_manifest(&output));
+            assert_eq!(
+                read_image_info(&output).unwrap().generator,
+                Generator::Unknown
+            );
+            image::load_from_memory(&output).unwrap();
+        }
+    }
+}
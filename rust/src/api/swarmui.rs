@@ -0,0 +1,141 @@
+use serde_json::{json, Value};
+
+use crate::api::a1111::A1111Parameters;
+use crate::api::metadata::PngTextChunk;
+use crate::fields::{get_parsed, get_string, get_u32, get_u64, settings, text_chunk};
+
+/// 识别 SwarmUI 写入的 `sui_image_params`，转换为与 A1111 相同的字段
+///
+/// PNG 中保存在 `parameters` 或 `sui_image_params` 文本块，JPEG / WebP 中保存在 EXIF 文本，
+/// 格式为 `{"sui_image_params": {...}, "sui_models": [...]}`
+pub(crate) fn parse_swarmui_parameters(
+    chunks: &[PngTextChunk],
+    metadata_string: Option<&str>,
+) -> Option<A1111Parameters> {
+    let chunk = |key: &str| text_chunk(chunks, key);
+    let parse = |text: &str| serde_json::from_str::<Value>(text.trim()).ok();
+
+    // 单独的 `sui_image_params` 文本块中可能只有参数本身
+    let standalone = chunk("sui_image_params")
+        .and_then(parse)
+        .filter(Value::is_object)
+        .map(|params| match params.get("sui_image_params") {
+            Some(_) => params,
+            None => json!({ "sui_image_params": params }),
+        });
+    let root = standalone
+        .into_iter()
+        .chain(chunk("parameters").and_then(parse))
+        .chain(metadata_string.and_then(parse))
+        .find(|root| root.get("sui_image_params").is_some_and(Value::is_object))?;
+    let params = root.get("sui_image_params")?.as_object()?;
+
+    // `sui_models` 记录了模型文件名和哈希，`param` 为对应的参数名
+    let model_hash = root
+        .get("sui_models")
+        .and_then(Value::as_array)
+        .into_iter()
+        .flatten()
+        .find(|model| model.get("param").and_then(Value::as_str) == Some("model"))
+        .and_then(|model| model.get("hash")?.as_str())
+        .map(str::to_string);
+
+    Some(A1111Parameters {
+        prompt: get_string(params, "prompt").unwrap_or_default(),
+        negative_prompt: get_string(params, "negativeprompt"),
+        steps: get_u32(params, "steps"),
+        sampler: get_string(params, "sampler"),
+        schedule_type: get_string(params, "scheduler"),
+        cfg_scale: get_parsed(params, "cfgscale"),
+        // 随机种子写作 -1，读取为 None
+        seed: get_u64(params, "seed"),
+        width: get_u32(params, "width"),
+        height: get_u32(params, "height"),
+        model: get_string(params, "model"),
+        model_hash,
+        denoising_strength: get_parsed(params, "initimagecreativity"),
+        settings: settings(params),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_fixtures::make_text_chunk;
+
+    // SwarmUI 0.9 写入 PNG `parameters` 的 JSON
+    const SWARMUI_JSON: &str = r#"{
+  "sui_image_params": {
+    "prompt": "a cat",
+    "negativeprompt": "lowres",
+    "model": "OfficialStableDiffusion/sd_xl_base_1.0",
+    "seed": 1460919163,
+    "steps": 28.0,
+    "cfgscale": 7.0,
+    "aspectratio": "1:1",
+    "width": 1024,
+    "height": 1024,
+    "sampler": "euler",
+    "scheduler": "normal",
+    "initimagecreativity": 0.6,
+    "swarm_version": "0.9.2.1",
+    "date": "2024-08-01",
+    "generation_time": "0.01 (prep) and 3.21 (gen) seconds"
+  },
+  "sui_models": [
+    {
+      "name": "sd_xl_base_1.0.safetensors",
+      "param": "model",
+      "hash": "0x31e35c80fc4829d14f90153f4c74cd59c90b779f6afe05a74cd6120b893f7e5b"
+    }
+  ]
+}"#;
+
+    #[test]
+    fn parameters_chunk_is_parsed() {
+        let chunks = [make_text_chunk("parameters", SWARMUI_JSON)];
+        let parameters = parse_swarmui_parameters(&chunks, None).unwrap();
+        assert_eq!(parameters.prompt, "a cat");
+        assert_eq!(parameters.negative_prompt.as_deref(), Some("lowres"));
+        // 浮点数写法的整数
+        assert_eq!(parameters.steps, Some(28));
+        assert_eq!(parameters.cfg_scale, Some(7.0));
+        assert_eq!(parameters.seed, Some(1460919163));
+        assert_eq!(
+            (parameters.width, parameters.height),
+            (Some(1024), Some(1024))
+        );
+        assert_eq!(parameters.sampler.as_deref(), Some("euler"));
+        assert_eq!(parameters.schedule_type.as_deref(), Some("normal"));
+        assert_eq!(
+            parameters.model.as_deref(),
+            Some("OfficialStableDiffusion/sd_xl_base_1.0")
+        );
+        assert!(parameters.model_hash.unwrap().starts_with("0x31e35c80"));
+        assert_eq!(parameters.denoising_strength, Some(0.6));
+        assert!(parameters
+            .settings
+            .contains(&("swarm_version".to_string(), "0.9.2.1".to_string())));
+    }
+
+    #[test]
+    fn standalone_params_and_string_numbers_are_parsed() {
+        let params = r#"{"prompt": "a cat", "steps": "30", "seed": "-1", "width": "512.0", "cfgscale": "5.5"}"#;
+        let chunks = [make_text_chunk("sui_image_params", params)];
+        let parameters = parse_swarmui_parameters(&chunks, None).unwrap();
+        assert_eq!(parameters.steps, Some(30));
+        assert_eq!(parameters.seed, None);
+        assert_eq!(parameters.width, Some(512));
+        assert_eq!(parameters.cfg_scale, Some(5.5));
+    }
+
+    #[test]
+    fn exif_text_is_parsed() {
+        let parameters = parse_swarmui_parameters(&[], Some(SWARMUI_JSON)).unwrap();
+        assert_eq!(parameters.seed, Some(1460919163));
+        assert_eq!(
+            parse_swarmui_parameters(&[], Some(r#"{"prompt": "a cat"}"#)),
+            None
+        );
+    }
+}
@@ -0,0 +1,583 @@
+use std::fs;
+use std::io::{Cursor, Write};
+use std::path::Path;
+
+use anyhow::{anyhow, Error};
+use flate2::write::ZlibEncoder;
+use flate2::{Compression, Crc};
+use image::ImageReader;
+
+use crate::api::metadata::{
+    png_chunks, webp_chunks, PngTextChunk, PngTextChunkType, PNG_SIGNATURE, TAG_XP_COMMENT,
+};
+
+/// 把文本写回图片文件，不重新编码像素，其他数据块保持原样
+///
+/// - PNG: 用 `chunks` 替换文件中全部 tEXt / zTXt / iTXt 块
+/// - JPEG / WebP: 写入 EXIF，关键字为 `ImageDescription` 的文本写入 ImageDescription，
+///   其他关键字 (例如 `parameters`) 写入 UserComment，两者各自最多一个
+///
+/// `chunks` 是写入后的完整文本列表，文件中原有但不在列表里的文本会被删除
+///
+/// 先写入同目录下的临时文件再替换原文件，失败时原文件不受影响
+pub fn write_metadata(path: String, chunks: Vec<PngTextChunk>) -> Result<(), Error> {
+    let input_bytes = fs::read(&path)?;
+    let output_bytes = rewrite_metadata(&input_bytes, &chunks)?;
+
+    let path = Path::new(&path);
+    let file_name = path
+        .file_name()
+        .ok_or_else(|| anyhow!("无效的文件路径 (Invalid file path)"))?;
+    let mut temp_name = file_name.to_os_string();
+    temp_name.push(".tmp");
+    let temp_path = path.with_file_name(temp_name);
+    fs::write(&temp_path, output_bytes)?;
+    if let Err(error) = fs::rename(&temp_path, path) {
+        let _ = fs::remove_file(&temp_path);
+        return Err(error.into());
+    }
+    Ok(())
+}
+
+/// 在内存中完成 [`write_metadata`] 的改写，返回新的文件内容
+pub(crate) fn rewrite_metadata(
+    input_bytes: &[u8],
+    chunks: &[PngTextChunk],
+) -> Result<Vec<u8>, Error> {
+    let kind =
+        infer::get(input_bytes).ok_or_else(|| anyhow!("无法识别的文件类型 (Unknown file type)"))?;
+    match kind.mime_type() {
+        "image/png" => rewrite_png_text_chunks(input_bytes, chunks),
+        "image/jpeg" => {
+            let (user_comment, image_description) = exif_texts(chunks)?;
+            let existing = jpeg_exif(input_bytes)?;
+            let exif = build_exif(existing, user_comment, image_description)?;
+            rewrite_jpeg_exif(input_bytes, exif.as_deref())
+        }
+        "image/webp" => {
+            let (user_comment, image_description) = exif_texts(chunks)?;
+            let existing = webp_chunks(input_bytes)
+                .find(|(fourcc, _)| fourcc == b"EXIF")
+                .map(|(_, data)| data.strip_prefix(b"Exif\0\0").unwrap_or(data));
+            let exif = build_exif(existing, user_comment, image_description)?;
+            rewrite_webp_exif(input_bytes, exif.as_deref())
+        }
+        mime_type => Err(anyhow!(
+            "不支持写入该文件类型 (Unsupported file type for writing): {mime_type}"
+        )),
+    }
+}
+
+// 重建 PNG: 删除原有文本块，新的文本块紧跟在 IHDR 之后，其余块按原顺序复制
+fn rewrite_png_text_chunks(input_bytes: &[u8], chunks: &[PngTextChunk]) -> Result<Vec<u8>, Error> {
+    let (source_chunks, trailing) = complete_png_chunks(input_bytes)?;
+    let mut output = PNG_SIGNATURE.to_vec();
+    for (chunk_type, data) in source_chunks {
+        if matches!(&chunk_type, b"tEXt" | b"zTXt" | b"iTXt") {
+            continue;
+        }
+        write_png_chunk(&mut output, &chunk_type, data);
+        if &chunk_type == b"IHDR" {
+            for chunk in chunks {
+                let (chunk_type, data) = encode_text_chunk(chunk)?;
+                write_png_chunk(&mut output, &chunk_type, &data);
+            }
+        }
+    }
+    // IEND 之后的附加数据原样保留
+    output.extend_from_slice(trailing);
+    Ok(output)
+}
+
+// PNG / WebP 数据块的类型和数据
+pub(crate) type Chunk<'a> = ([u8; 4], &'a [u8]);
+
+// 数据块列表和 IEND 之后的附加数据
+pub(crate) type PngChunks<'a> = (Vec<Chunk<'a>>, &'a [u8]);
+
+/// 读取 PNG 的全部数据块，没有以 IEND 结束时返回错误，避免截断原文件
+pub(crate) fn complete_png_chunks(input_bytes: &[u8]) -> Result<PngChunks<'_>, Error> {
+    let chunks: Vec<Chunk> = png_chunks(input_bytes).collect();
+    if chunks.last().map(|(chunk_type, _)| chunk_type) != Some(b"IEND") {
+        return Err(anyhow!("PNG 文件不完整 (Truncated PNG file)"));
+    }
+    let consumed = PNG_SIGNATURE.len()
+        + chunks
+            .iter()
+            .map(|(_, data)| data.len() + 12)
+            .sum::<usize>();
+    Ok((chunks, &input_bytes[consumed..]))
+}
+
+pub(crate) fn write_png_chunk(output: &mut Vec<u8>, chunk_type: &[u8; 4], data: &[u8]) {
+    let mut crc = Crc::new();
+    crc.update(chunk_type);
+    crc.update(data);
+    output.extend_from_slice(&(data.len() as u32).to_be_bytes());
+    output.extend_from_slice(chunk_type);
+    output.extend_from_slice(data);
+    output.extend_from_slice(&crc.sum().to_be_bytes());
+}
+
+/// 编码单个文本块
+///
+/// tEXt 和 zTXt 只能保存 Latin-1 文本，包含其他字符时改用 iTXt
+fn encode_text_chunk(chunk: &PngTextChunk) -> Result<([u8; 4], Vec<u8>), Error> {
+    let keyword = to_latin1(&chunk.keyword)
+        .filter(|keyword| (1..=79).contains(&keyword.len()) && !keyword.contains(&0))
+        .ok_or_else(|| anyhow!("无效的文本块关键字 (Invalid keyword): {}", chunk.keyword))?;
+    let mut data = keyword;
+    data.push(0);
+
+    let latin1_text = to_latin1(&chunk.text);
+    match (&chunk.chunk_type, latin1_text) {
+        (PngTextChunkType::Text, Some(text)) => {
+            data.extend_from_slice(&text);
+            Ok((*b"tEXt", data))
+        }
+        (PngTextChunkType::CompressedText, Some(text)) => {
+            // 压缩方式 0 (zlib)
+            data.push(0);
+            data.extend_from_slice(&deflate(&text)?);
+            Ok((*b"zTXt", data))
+        }
+        _ => {
+            // 不压缩，语言标签和翻译后的关键字可以为空
+            data.extend_from_slice(&[0, 0]);
+            data.extend_from_slice(chunk.language_tag.as_deref().unwrap_or("").as_bytes());
+            data.push(0);
+            data.extend_from_slice(chunk.translated_keyword.as_deref().unwrap_or("").as_bytes());
+            data.push(0);
+            data.extend_from_slice(chunk.text.as_bytes());
+            Ok((*b"iTXt", data))
+        }
+    }
+}
+
+fn to_latin1(text: &str) -> Option<Vec<u8>> {
+    text.chars().map(|c| u8::try_from(c).ok()).collect()
+}
+
+fn deflate(data: &[u8]) -> Result<Vec<u8>, Error> {
+    let mut encoder = ZlibEncoder::new(vec![], Compression::default());
+    encoder.write_all(data)?;
+    Ok(encoder.finish()?)
+}
+
+// 按关键字把文本块分配到 UserComment 和 ImageDescription
+fn exif_texts(chunks: &[PngTextChunk]) -> Result<(Option<&str>, Option<&str>), Error> {
+    let (descriptions, comments): (Vec<_>, Vec<_>) = chunks
+        .iter()
+        .partition(|chunk| chunk.keyword == "ImageDescription");
+    if descriptions.len() > 1 || comments.len() > 1 {
+        return Err(anyhow!(
+            "EXIF 只能保存一条 UserComment 和一条 ImageDescription (Too many text chunks for EXIF)"
+        ));
+    }
+    Ok((
+        comments.first().map(|chunk| chunk.text.as_str()),
+        descriptions.first().map(|chunk| chunk.text.as_str()),
+    ))
+}
+
+/// 读取原有 EXIF，替换 UserComment 和 ImageDescription 后重新编码为 TIFF 数据
+///
+/// 同样作为文本读取的 XPComment 一并删除，其他字段和 JPEG 缩略图原样保留。
+/// 没有剩下任何字段时返回 `None`，此时应删除整个 EXIF
+pub(crate) fn build_exif(
+    existing: Option<&[u8]>,
+    user_comment: Option<&str>,
+    image_description: Option<&str>,
+) -> Result<Option<Vec<u8>>, Error> {
+    let existing = existing.and_then(|tiff| exif::Reader::new().read_raw(tiff.to_vec()).ok());
+    let little_endian = existing.as_ref().is_some_and(|exif| exif.little_endian());
+
+    let mut fields = vec![];
+    if let Some(text) = user_comment {
+        fields.push(exif::Field {
+            tag: exif::Tag::UserComment,
+            ifd_num: exif::In::PRIMARY,
+            value: exif::Value::Undefined(encode_user_comment(text, little_endian), 0),
+        });
+    }
+    if let Some(text) = image_description {
+        fields.push(exif::Field {
+            tag: exif::Tag::ImageDescription,
+            ifd_num: exif::In::PRIMARY,
+            value: exif::Value::Ascii(vec![text.as_bytes().to_vec()]),
+        });
+    }
+
+    let mut writer = exif::experimental::Writer::new();
+    for field in &fields {
+        writer.push_field(field);
+    }
+    let mut field_count = fields.len();
+    let mut thumbnail = None;
+    if let Some(exif) = &existing {
+        for field in exif.fields() {
+            let replaced = field.ifd_num == exif::In::PRIMARY
+                && (matches!(
+                    field.tag,
+                    exif::Tag::UserComment | exif::Tag::ImageDescription
+                ) || field.tag == TAG_XP_COMMENT);
+            if !replaced {
+                writer.push_field(field);
+                field_count += 1;
+            }
+        }
+        thumbnail = exif_thumbnail(exif);
+    }
+    if let Some(jpeg) = thumbnail {
+        writer.set_jpeg(jpeg, exif::In::THUMBNAIL);
+    } else if field_count == 0 {
+        return Ok(None);
+    }
+
+    let mut output = Cursor::new(vec![]);
+    writer
+        .write(&mut output, little_endian)
+        .map_err(|error| anyhow!("无法编码 EXIF (Failed to encode EXIF): {error}"))?;
+    Ok(Some(output.into_inner()))
+}
+
+// 缩略图以偏移量保存在 TIFF 数据中，需要单独取出后交给 Writer 重新定位
+fn exif_thumbnail(exif: &exif::Exif) -> Option<&[u8]> {
+    let offset = exif
+        .get_field(exif::Tag::JPEGInterchangeFormat, exif::In::THUMBNAIL)?
+        .value
+        .get_uint(0)? as usize;
+    let length = exif
+        .get_field(exif::Tag::JPEGInterchangeFormatLength, exif::In::THUMBNAIL)?
+        .value
+        .get_uint(0)? as usize;
+    exif.buf().get(offset..offset.checked_add(length)?)
+}
+
+// 纯 ASCII 文本使用 ASCII 字符集，否则使用与 TIFF 字节序一致的 UTF-16
+fn encode_user_comment(text: &str, little_endian: bool) -> Vec<u8> {
+    if text.is_ascii() {
+        return [b"ASCII\0\0\0".as_slice(), text.as_bytes()].concat();
+    }
+    let mut bytes = b"UNICODE\0".to_vec();
+    for unit in text.encode_utf16() {
+        if little_endian {
+            bytes.extend_from_slice(&unit.to_le_bytes());
+        } else {
+            bytes.extend_from_slice(&unit.to_be_bytes());
+        }
+    }
+    bytes
+}
+
+const JPEG_SOI: u8 = 0xD8;
+const JPEG_SOS: u8 = 0xDA;
+const JPEG_APP0: u8 = 0xE0;
+pub(crate) const JPEG_APP1: u8 = 0xE1;
+pub(crate) const EXIF_HEADER: &[u8] = b"Exif\0\0";
+
+// 段标记、段数据 (不含长度字段) 和段结束的位置
+pub(crate) type JpegSegment<'a> = (u8, &'a [u8], usize);
+
+/// 遍历 JPEG 在 SOS 之前的段
+pub(crate) fn jpeg_segments(input_bytes: &[u8]) -> Result<Vec<JpegSegment<'_>>, Error> {
+    let truncated = || anyhow!("JPEG 文件不完整 (Truncated JPEG file)");
+    if input_bytes.get(0..2) != Some(&[0xFF, JPEG_SOI]) {
+        return Err(anyhow!("缺少 JPEG SOI 标记 (Missing JPEG SOI marker)"));
+    }
+    let mut segments = vec![];
+    let mut offset = 2;
+    loop {
+        let header = input_bytes.get(offset..offset + 4).ok_or_else(truncated)?;
+        if header[0] != 0xFF {
+            return Err(anyhow!("无效的 JPEG 段 (Invalid JPEG segment)"));
+        }
+        let marker = header[1];
+        // 长度字段包含自身的 2 个字节
+        let length = u16::from_be_bytes([header[2], header[3]]) as usize;
+        let end = offset + 2 + length;
+        let data = input_bytes
+            .get(offset + 4..end)
+            .filter(|_| length >= 2)
+            .ok_or_else(truncated)?;
+        segments.push((marker, data, end));
+        if marker == JPEG_SOS {
+            return Ok(segments);
+        }
+        offset = end;
+    }
+}
+
+fn jpeg_exif(input_bytes: &[u8]) -> Result<Option<&[u8]>, Error> {
+    Ok(jpeg_segments(input_bytes)?
+        .into_iter()
+        .find(|(marker, data, _)| *marker == JPEG_APP1 && data.starts_with(EXIF_HEADER))
+        .map(|(_, data, _)| &data[EXIF_HEADER.len()..]))
+}
+
+// 删除原有的 EXIF APP1 段，新的 EXIF (如果有) 放在 SOI 和 JFIF APP0 之后，压缩数据原样复制
+fn rewrite_jpeg_exif(input_bytes: &[u8], exif: Option<&[u8]>) -> Result<Vec<u8>, Error> {
+    let segment_length = exif
+        .map(|exif| {
+            u16::try_from(EXIF_HEADER.len() + exif.len() + 2)
+                .map_err(|_| anyhow!("EXIF 数据超过 64 KB (EXIF data is too large for JPEG)"))
+        })
+        .transpose()?;
+
+    let mut output = vec![0xFF, JPEG_SOI];
+    let mut start = 2;
+    let mut exif_written = exif.is_none();
+    for (marker, data, end) in jpeg_segments(input_bytes)? {
+        if let (false, Some(exif), Some(segment_length)) = (exif_written, exif, segment_length) {
+            if marker != JPEG_APP0 {
+                output.extend_from_slice(&[0xFF, JPEG_APP1]);
+                output.extend_from_slice(&segment_length.to_be_bytes());
+                output.extend_from_slice(EXIF_HEADER);
+                output.extend_from_slice(exif);
+                exif_written = true;
+            }
+        }
+        let is_exif = marker == JPEG_APP1 && data.starts_with(EXIF_HEADER);
+        if !is_exif {
+            output.extend_from_slice(&input_bytes[start..end]);
+        }
+        start = end;
+    }
+    output.extend_from_slice(&input_bytes[start..]);
+    Ok(output)
+}
+
+// VP8X 标志位
+const WEBP_FLAG_ALPHA: u8 = 0x10;
+pub(crate) const WEBP_FLAG_EXIF: u8 = 0x08;
+pub(crate) const WEBP_FLAG_XMP: u8 = 0x04;
+
+/// 替换 WebP 的 `EXIF` 块，并在 VP8X 中设置 EXIF 标志，`exif` 为 `None` 时删除 EXIF 块并清除标志
+///
+/// 简单格式 (只有 VP8 / VP8L 块) 的文件会补上 VP8X 块，EXIF 块按规范放在 `XMP ` 之前
+fn rewrite_webp_exif(input_bytes: &[u8], exif: Option<&[u8]>) -> Result<Vec<u8>, Error> {
+    let chunks: Vec<Chunk> = complete_webp_chunks(input_bytes)?
+        .into_iter()
+        .filter(|(fourcc, _)| fourcc != b"EXIF")
+        .collect();
+
+    let mut body = b"WEBP".to_vec();
+    let Some(exif) = exif else {
+        for (fourcc, data) in &chunks {
+            let mut data = data.to_vec();
+            if let (b"VP8X", Some(flags)) = (fourcc, data.first_mut()) {
+                *flags &= !WEBP_FLAG_EXIF;
+            }
+            write_webp_chunk(&mut body, fourcc, &data);
+        }
+        return Ok(riff_container(&body));
+    };
+    if chunks.first().map(|(fourcc, _)| fourcc) != Some(b"VP8X") {
+        let (width, height) = ImageReader::new(Cursor::new(input_bytes))
+            .with_guessed_format()?
+            .into_dimensions()?;
+        let mut flags = WEBP_FLAG_EXIF;
+        if chunks
+            .iter()
+            .any(|(fourcc, data)| fourcc == b"VP8L" && vp8l_has_alpha(data))
+        {
+            flags |= WEBP_FLAG_ALPHA;
+        }
+        let mut vp8x = vec![flags, 0, 0, 0];
+        vp8x.extend_from_slice(&(width - 1).to_le_bytes()[..3]);
+        vp8x.extend_from_slice(&(height - 1).to_le_bytes()[..3]);
+        write_webp_chunk(&mut body, b"VP8X", &vp8x);
+    }
+
+    let mut exif_written = false;
+    for (fourcc, data) in &chunks {
+        if fourcc == b"XMP " && !exif_written {
+            write_webp_chunk(&mut body, b"EXIF", exif);
+            exif_written = true;
+        }
+        if fourcc == b"VP8X" {
+            let mut vp8x = data.to_vec();
+            if let Some(flags) = vp8x.first_mut() {
+                *flags |= WEBP_FLAG_EXIF;
+            }
+            write_webp_chunk(&mut body, fourcc, &vp8x);
+        } else {
+            write_webp_chunk(&mut body, fourcc, data);
+        }
+    }
+    if !exif_written {
+        write_webp_chunk(&mut body, b"EXIF", exif);
+    }
+    Ok(riff_container(&body))
+}
+
+/// 读取 WebP 的全部数据块，块长度与 RIFF 头声明的大小不一致时返回错误，避免截断原文件
+pub(crate) fn complete_webp_chunks(input_bytes: &[u8]) -> Result<Vec<Chunk<'_>>, Error> {
+    let declared_size = input_bytes
+        .get(4..8)
+        .map(|size| u32::from_le_bytes([size[0], size[1], size[2], size[3]]) as usize);
+    let chunks: Vec<Chunk> = webp_chunks(input_bytes).collect();
+    let parsed_size = chunks
+        .iter()
+        .map(|(_, data)| 8 + data.len() + (data.len() & 1))
+        .sum::<usize>()
+        + 4;
+    if declared_size != Some(parsed_size) {
+        return Err(anyhow!("WebP 文件不完整 (Truncated WebP file)"));
+    }
+    Ok(chunks)
+}
+
+// `body` 以 `WEBP` 开头，外面再包上 RIFF 头
+pub(crate) fn riff_container(body: &[u8]) -> Vec<u8> {
+    let mut output = b"RIFF".to_vec();
+    output.extend_from_slice(&(body.len() as u32).to_le_bytes());
+    output.extend_from_slice(body);
+    output
+}
+
+pub(crate) fn write_webp_chunk(output: &mut Vec<u8>, fourcc: &[u8; 4], data: &[u8]) {
+    output.extend_from_slice(fourcc);
+    output.extend_from_slice(&(data.len() as u32).to_le_bytes());
+    output.extend_from_slice(data);
+    if data.len() & 1 == 1 {
+        output.push(0);
+    }
+}
+
+// VP8L 头: 1 字节签名，随后 14 位宽、14 位高和 1 位 alpha 标志
+fn vp8l_has_alpha(data: &[u8]) -> bool {
+    match data {
+        [0x2F, b0, b1, b2, b3, ..] => (u32::from_le_bytes([*b0, *b1, *b2, *b3]) >> 28) & 1 == 1,
+        _ => false,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use image::ImageFormat;
+
+    use super::*;
+    use crate::api::metadata::extract_metadata;
+    use crate::test_fixtures::{make_text_chunk, source_image};
+
+    const PARAMETERS: &str = "1girl, 中文\nSteps: 28, Sampler: Euler a, Seed: 1234";
+
+    fn assert_decodes_like(output: &[u8], source: &[u8]) {
+        let before = image::load_from_memory(source).unwrap();
+        let after = image::load_from_memory(output).unwrap();
+        assert_eq!(before.to_rgba8(), after.to_rgba8());
+    }
+
+    #[test]
+    fn png_text_chunks_round_trip() {
+        let source = source_image(16, 8, ImageFormat::Png, true);
+        let chunks = [
+            make_text_chunk("parameters", PARAMETERS),
+            make_text_chunk("Software", "metadata-gallery"),
+        ];
+        let output = rewrite_metadata(&source, &chunks).unwrap();
+
+        let info = extract_metadata(&output).unwrap();
+        let texts: Vec<(&str, &str)> = info
+            .text_chunks
+            .iter()
+            .map(|chunk| (chunk.keyword.as_str(), chunk.text.as_str()))
+            .collect();
+        assert_eq!(
+            texts,
+            [("parameters", PARAMETERS), ("Software", "metadata-gallery")]
+        );
+        // 非 Latin-1 文本改用 iTXt
+        assert!(matches!(
+            info.text_chunks[0].chunk_type,
+            PngTextChunkType::InternationalText
+        ));
+        assert_eq!(info.a1111_parameters.unwrap().seed, Some(1234));
+        assert_decodes_like(&output, &source);
+    }
+
+    #[test]
+    fn png_data_after_iend_is_kept() {
+        let source = source_image(16, 8, ImageFormat::Png, true);
+        // 看起来像数据块的附加数据不影响写入
+        let mut trailing = vec![];
+        write_png_chunk(&mut trailing, b"tEXt", b"hidden\0data");
+        let input = [source.as_slice(), &trailing].concat();
+
+        let output = rewrite_metadata(&input, &[make_text_chunk("Title", "image")]).unwrap();
+        assert!(output.ends_with(&trailing));
+        let info = extract_metadata(&output).unwrap();
+        assert_eq!(info.text_chunks.len(), 1);
+        assert_decodes_like(&output, &source);
+    }
+
+    #[test]
+    fn jpeg_exif_round_trips() {
+        let source = source_image(16, 8, ImageFormat::Jpeg, false);
+        let chunks = [
+            make_text_chunk("parameters", PARAMETERS),
+            make_text_chunk("ImageDescription", "description"),
+        ];
+        let output = rewrite_metadata(&source, &chunks).unwrap();
+
+        // UserComment 优先于 ImageDescription
+        let info = extract_metadata(&output).unwrap();
+        assert_eq!(info.metadata_string.as_deref(), Some(PARAMETERS));
+        assert_eq!(info.a1111_parameters.unwrap().seed, Some(1234));
+        image::load_from_memory(&output).unwrap();
+    }
+
+    #[test]
+    fn webp_exif_round_trips() {
+        // image 输出的是只有 VP8L 块的简单格式，写入时需要补上 VP8X
+        let source = source_image(16, 8, ImageFormat::WebP, true);
+        let output =
+            rewrite_metadata(&source, &[make_text_chunk("parameters", PARAMETERS)]).unwrap();
+
+        let info = extract_metadata(&output).unwrap();
+        assert_eq!(info.metadata_string.as_deref(), Some(PARAMETERS));
+        assert_decodes_like(&output, &source);
+
+        // 再次写入时替换已有的 EXIF 块
+        let output = rewrite_metadata(&output, &[make_text_chunk("parameters", "2girls")]).unwrap();
+        let info = extract_metadata(&output).unwrap();
+        assert_eq!(info.metadata_string.as_deref(), Some("2girls"));
+        assert_eq!(
+            webp_chunks(&output)
+                .filter(|(fourcc, _)| fourcc == b"EXIF")
+                .count(),
+            1
+        );
+        assert_decodes_like(&output, &source);
+    }
+
+    #[test]
+    fn removing_the_only_exif_text_drops_exif() {
+        for format in [ImageFormat::Jpeg, ImageFormat::WebP] {
+            let source = source_image(16, 8, format, false);
+            let with_exif =
+                rewrite_metadata(&source, &[make_text_chunk("parameters", PARAMETERS)]).unwrap();
+            let output = rewrite_metadata(&with_exif, &[]).unwrap();
+
+            let info = extract_metadata(&output).unwrap();
+            assert_eq!(info.metadata_string, None);
+            image::load_from_memory(&output).unwrap();
+        }
+        assert!(rewrite_metadata(&source_image(16, 8, ImageFormat::Jpeg, false), &[]).is_ok());
+    }
+
+    #[test]
+    fn webp_with_empty_vp8x_does_not_panic() {
+        let source = source_image(16, 8, ImageFormat::WebP, true);
+        let mut body = b"WEBP".to_vec();
+        write_webp_chunk(&mut body, b"VP8X", &[]);
+        for (fourcc, data) in webp_chunks(&source) {
+            write_webp_chunk(&mut body, &fourcc, data);
+        }
+        let _ = rewrite_metadata(
+            &riff_container(&body),
+            &[make_text_chunk("parameters", "1girl")],
+        );
+    }
+}
@@ -0,0 +1,403 @@
+use roxmltree::{Document, Node};
+
+/// XMP 数据包中的常用字段，以及全部属性的键值对
+#[derive(Debug, Clone, PartialEq)]
+pub struct XmpMetadata {
+    /// `dc:description`，多语言时优先使用 `x-default`
+    pub description: Option<String>,
+    /// `dc:subject` (关键字)
+    pub subject: Vec<String>,
+    /// `xmp:Rating`，-1 表示拒绝，0 表示未评级
+    pub rating: Option<f64>,
+    /// `exif:UserComment`
+    pub user_comment: Option<String>,
+    pub properties: Vec<XmpProperty>,
+}
+
+/// XMP 中的一个值，数组的每一项各占一个
+#[derive(Debug, Clone, PartialEq)]
+pub struct XmpProperty {
+    pub namespace: String,
+    /// 带前缀的名称，例如 `dc:subject`；结构体的字段以 `/` 连接，例如 `exif:Flash/exif:Fired`
+    pub key: String,
+    pub value: String,
+    /// 多语言数组 (`rdf:Alt`) 中的 `xml:lang`
+    pub language: Option<String>,
+}
+
+/// JPEG APP1 段中 XMP 数据包的标识，超过 64 KiB 的部分保存在扩展段中
+pub(crate) const XMP_HEADER: &[u8] = b"http://ns.adobe.com/xap/1.0/\0";
+pub(crate) const XMP_EXTENSION_HEADER: &[u8] = b"http://ns.adobe.com/xmp/extension/\0";
+/// PNG 中保存 XMP 的 iTXt 块的关键字
+pub(crate) const XMP_KEYWORD: &str = "XML:com.adobe.xmp";
+
+const RDF_NAMESPACE: &str = "http://www.w3.org/1999/02/22-rdf-syntax-ns#";
+const XML_NAMESPACE: &str = "http://www.w3.org/XML/1998/namespace";
+const DC_NAMESPACE: &str = "http://purl.org/dc/elements/1.1/";
+const XMP_NAMESPACE: &str = "http://ns.adobe.com/xap/1.0/";
+const EXIF_NAMESPACE: &str = "http://ns.adobe.com/exif/1.0/";
+
+// 结构体嵌套超过这个深度时忽略更深的字段，避免递归过深
+const MAX_STRUCT_DEPTH: usize = 16;
+// roxmltree 递归地解析嵌套元素，过深的数据包会导致栈溢出，正常的 XMP 不超过十几层
+const MAX_ELEMENT_DEPTH: usize = 64;
+
+// 数据包没有声明前缀时使用的常见前缀
+const KNOWN_PREFIXES: [(&str, &str); 9] = [
+    (DC_NAMESPACE, "dc"),
+    (XMP_NAMESPACE, "xmp"),
+    (EXIF_NAMESPACE, "exif"),
+    ("http://ns.adobe.com/tiff/1.0/", "tiff"),
+    ("http://ns.adobe.com/photoshop/1.0/", "photoshop"),
+    ("http://ns.adobe.com/xap/1.0/mm/", "xmpMM"),
+    ("http://ns.adobe.com/lightroom/1.0/", "lr"),
+    (
+        "http://iptc.org/std/Iptc4xmpCore/1.0/xmlns/",
+        "Iptc4xmpCore",
+    ),
+    ("http://iptc.org/std/Iptc4xmpExt/2008-02-29/", "Iptc4xmpExt"),
+];
+
+/// 解析 XMP 数据包，不是有效的 XML 或没有 `rdf:Description` 时返回 `None`
+pub(crate) fn parse_xmp(packet: &str) -> Option<XmpMetadata> {
+    // 部分工具会在数据包末尾补零或留下 BOM
+    let packet = packet.trim_matches(|c: char| c == '\0' || c == '\u{feff}' || c.is_whitespace());
+    if exceeds_element_depth(packet, MAX_ELEMENT_DEPTH) {
+        return None;
+    }
+    let document = Document::parse(packet).ok()?;
+
+    let mut properties = vec![];
+    let descriptions = document
+        .descendants()
+        .filter(|node| node.has_tag_name((RDF_NAMESPACE, "Description")))
+        // 嵌套在属性中的 rdf:Description 是结构体，在父属性中处理
+        .filter(|node| {
+            node.parent_element()
+                .is_some_and(|parent| parent.has_tag_name((RDF_NAMESPACE, "RDF")))
+        });
+    for description in descriptions {
+        read_fields(description, "", 0, &mut properties);
+    }
+    if properties.is_empty() {
+        return None;
+    }
+
+    // 多语言数组优先使用 `x-default`，其次是第一项
+    let localized = |namespace: &str, name: &str| {
+        let values: Vec<&XmpProperty> = top_level_values(&properties, namespace, name).collect();
+        values
+            .iter()
+            .find(|property| property.language.as_deref() == Some("x-default"))
+            .or(values.first())
+            .map(|property| property.value.clone())
+    };
+
+    let description = localized(DC_NAMESPACE, "description");
+    let subject = top_level_values(&properties, DC_NAMESPACE, "subject")
+        .map(|property| property.value.clone())
+        .collect();
+    let rating = top_level_values(&properties, XMP_NAMESPACE, "Rating")
+        .next()
+        .and_then(|property| property.value.trim().parse().ok());
+    let user_comment = localized(EXIF_NAMESPACE, "UserComment");
+    Some(XmpMetadata {
+        description,
+        subject,
+        rating,
+        user_comment,
+        properties,
+    })
+}
+
+// 解析前粗略地统计元素的嵌套层数，注释或属性值中的尖括号可能导致多算，此时按过深处理
+fn exceeds_element_depth(packet: &str, limit: usize) -> bool {
+    let bytes = packet.as_bytes();
+    let mut depth = 0usize;
+    for (index, byte) in bytes.iter().enumerate() {
+        match (byte, bytes.get(index + 1)) {
+            (b'<', Some(b'/')) => depth = depth.saturating_sub(1),
+            (b'<', Some(b'?' | b'!')) => {}
+            (b'<', _) => {
+                depth += 1;
+                if depth > limit {
+                    return true;
+                }
+            }
+            // 自闭合元素 `<a/>`
+            (b'/', Some(b'>')) => depth = depth.saturating_sub(1),
+            _ => {}
+        }
+    }
+    false
+}
+
+// 不属于结构体的同名属性
+fn top_level_values<'a>(
+    properties: &'a [XmpProperty],
+    namespace: &'a str,
+    name: &str,
+) -> impl Iterator<Item = &'a XmpProperty> {
+    let suffix = format!(":{name}");
+    properties.iter().filter(move |property| {
+        property.namespace == namespace
+            && property.key.ends_with(&suffix)
+            && !property.key.contains('/')
+    })
+}
+
+// 读取 rdf:Description (或结构体) 的属性和子元素
+fn read_fields(node: Node, path: &str, depth: usize, properties: &mut Vec<XmpProperty>) {
+    if depth > MAX_STRUCT_DEPTH {
+        return;
+    }
+    // 简写形式: `<rdf:Description xmp:Rating="5">`
+    for attribute in node.attributes() {
+        let Some(namespace) = attribute.namespace() else {
+            continue;
+        };
+        if namespace == RDF_NAMESPACE || namespace == XML_NAMESPACE {
+            continue;
+        }
+        properties.push(XmpProperty {
+            namespace: namespace.to_string(),
+            key: join_key(path, &qualified_name(node, namespace, attribute.name())),
+            value: attribute.value().to_string(),
+            language: None,
+        });
+    }
+    for child in node.children().filter(Node::is_element) {
+        let Some(namespace) = child.tag_name().namespace() else {
+            continue;
+        };
+        let key = join_key(
+            path,
+            &qualified_name(child, namespace, child.tag_name().name()),
+        );
+        read_value(child, namespace, &key, depth, properties);
+    }
+}
+
+fn read_value(
+    node: Node,
+    namespace: &str,
+    key: &str,
+    depth: usize,
+    properties: &mut Vec<XmpProperty>,
+) {
+    let property = |value: &str, language: Option<&str>| XmpProperty {
+        namespace: namespace.to_string(),
+        key: key.to_string(),
+        value: value.to_string(),
+        language: language.map(str::to_string),
+    };
+
+    // `<xmp:BaseURL rdf:resource="..."/>`
+    if let Some(resource) = node.attribute((RDF_NAMESPACE, "resource")) {
+        properties.push(property(resource, None));
+        return;
+    }
+    // `rdf:parseType="Resource"` 的结构体直接把字段写在属性元素中
+    if node.attribute((RDF_NAMESPACE, "parseType")) == Some("Resource") {
+        read_fields(node, key, depth + 1, properties);
+        return;
+    }
+
+    let Some(child) = node.children().find(Node::is_element) else {
+        properties.push(property(node.text().unwrap_or_default().trim(), None));
+        return;
+    };
+    let is_array = ["Alt", "Bag", "Seq"]
+        .iter()
+        .any(|name| child.has_tag_name((RDF_NAMESPACE, *name)));
+    if is_array {
+        let items = child
+            .children()
+            .filter(|item| item.has_tag_name((RDF_NAMESPACE, "li")));
+        for item in items {
+            match item.children().find(Node::is_element) {
+                Some(structure) if structure.has_tag_name((RDF_NAMESPACE, "Description")) => {
+                    read_fields(structure, key, depth + 1, properties)
+                }
+                _ if item.attribute((RDF_NAMESPACE, "parseType")) == Some("Resource") => {
+                    read_fields(item, key, depth + 1, properties)
+                }
+                _ => properties.push(property(
+                    item.text().unwrap_or_default().trim(),
+                    item.attribute((XML_NAMESPACE, "lang")),
+                )),
+            }
+        }
+    } else if child.has_tag_name((RDF_NAMESPACE, "Description")) {
+        read_fields(child, key, depth + 1, properties);
+    }
+}
+
+// 优先使用数据包中声明的前缀
+fn qualified_name(node: Node, namespace: &str, name: &str) -> String {
+    let prefix = node
+        .lookup_prefix(namespace)
+        .filter(|prefix| !prefix.is_empty())
+        .or_else(|| {
+            KNOWN_PREFIXES
+                .iter()
+                .find(|(uri, _)| *uri == namespace)
+                .map(|(_, prefix)| *prefix)
+        });
+    match prefix {
+        Some(prefix) => format!("{prefix}:{name}"),
+        None => format!("{namespace}{name}"),
+    }
+}
+
+fn join_key(path: &str, key: &str) -> String {
+    if path.is_empty() {
+        key.to_string()
+    } else {
+        format!("{path}/{key}")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn packet(description: &str) -> String {
+        format!(
+            r#"<x:xmpmeta xmlns:x="adobe:ns:meta/"><rdf:RDF xmlns:rdf="{RDF_NAMESPACE}" xmlns:dc="{DC_NAMESPACE}" xmlns:xmp="{XMP_NAMESPACE}" xmlns:exif="{EXIF_NAMESPACE}" xmlns:ex="http://example.com/ns/">{description}</rdf:RDF></x:xmpmeta>"#
+        )
+    }
+
+    fn values<'a>(metadata: &'a XmpMetadata, key: &str) -> Vec<&'a str> {
+        metadata
+            .properties
+            .iter()
+            .filter(|property| property.key == key)
+            .map(|property| property.value.as_str())
+            .collect()
+    }
+
+    #[test]
+    fn attribute_shorthand_is_read_as_properties() {
+        let xmp = packet(r#"<rdf:Description rdf:about="" xmp:Rating="4" ex:Tool="test"/>"#);
+        let metadata = parse_xmp(&xmp).unwrap();
+        assert_eq!(metadata.rating, Some(4.0));
+        assert_eq!(values(&metadata, "ex:Tool"), ["test"]);
+        // rdf:about 不是属性
+        assert_eq!(metadata.properties.len(), 2);
+    }
+
+    #[test]
+    fn alt_prefers_x_default() {
+        let xmp = packet(
+            r#"<rdf:Description><dc:description><rdf:Alt>
+                <rdf:li xml:lang="de">eine Katze</rdf:li>
+                <rdf:li xml:lang="x-default">a cat</rdf:li>
+            </rdf:Alt></dc:description>
+            <exif:UserComment><rdf:Alt><rdf:li xml:lang="ja">猫</rdf:li></rdf:Alt></exif:UserComment>
+            </rdf:Description>"#,
+        );
+        let metadata = parse_xmp(&xmp).unwrap();
+        assert_eq!(metadata.description.as_deref(), Some("a cat"));
+        // 没有 x-default 时使用第一项
+        assert_eq!(metadata.user_comment.as_deref(), Some("猫"));
+        let languages: Vec<_> = metadata
+            .properties
+            .iter()
+            .filter_map(|property| property.language.as_deref())
+            .collect();
+        assert_eq!(languages, ["de", "x-default", "ja"]);
+    }
+
+    #[test]
+    fn bag_and_seq_items_are_separate_values() {
+        let xmp = packet(
+            r#"<rdf:Description>
+                <dc:subject><rdf:Bag><rdf:li>cat</rdf:li><rdf:li>mat</rdf:li></rdf:Bag></dc:subject>
+                <dc:creator><rdf:Seq><rdf:li>first</rdf:li><rdf:li>second</rdf:li></rdf:Seq></dc:creator>
+            </rdf:Description>"#,
+        );
+        let metadata = parse_xmp(&xmp).unwrap();
+        assert_eq!(metadata.subject, ["cat", "mat"]);
+        assert_eq!(values(&metadata, "dc:creator"), ["first", "second"]);
+    }
+
+    #[test]
+    fn parse_type_resource_fields_are_joined_with_slash() {
+        let xmp = packet(
+            r#"<rdf:Description>
+                <exif:Flash rdf:parseType="Resource"><exif:Fired>False</exif:Fired><exif:Mode>2</exif:Mode></exif:Flash>
+                <ex:Items><rdf:Seq><rdf:li rdf:parseType="Resource"><ex:Name>a</ex:Name></rdf:li></rdf:Seq></ex:Items>
+                <ex:Nested><rdf:Description ex:Inner="b"/></ex:Nested>
+            </rdf:Description>"#,
+        );
+        let metadata = parse_xmp(&xmp).unwrap();
+        assert_eq!(values(&metadata, "exif:Flash/exif:Fired"), ["False"]);
+        assert_eq!(values(&metadata, "exif:Flash/exif:Mode"), ["2"]);
+        assert_eq!(values(&metadata, "ex:Items/ex:Name"), ["a"]);
+        assert_eq!(values(&metadata, "ex:Nested/ex:Inner"), ["b"]);
+    }
+
+    #[test]
+    fn struct_fields_are_not_top_level_values() {
+        let xmp = packet(
+            r#"<rdf:Description><ex:Struct rdf:parseType="Resource"><xmp:Rating>5</xmp:Rating></ex:Struct></rdf:Description>"#,
+        );
+        let metadata = parse_xmp(&xmp).unwrap();
+        assert_eq!(metadata.rating, None);
+        assert_eq!(values(&metadata, "ex:Struct/xmp:Rating"), ["5"]);
+    }
+
+    #[test]
+    fn malformed_or_empty_packets_return_none() {
+        assert_eq!(parse_xmp(""), None);
+        assert_eq!(parse_xmp("not xml"), None);
+        assert_eq!(parse_xmp(&packet("<rdf:Description>")), None);
+        // 没有任何属性
+        assert_eq!(parse_xmp(&packet("<rdf:Description/>")), None);
+    }
+
+    #[test]
+    fn trailing_padding_is_ignored() {
+        let xmp = format!(
+            "\u{feff}{}\n\0\0",
+            packet(r#"<rdf:Description xmp:Rating="1"/>"#)
+        );
+        assert_eq!(parse_xmp(&xmp).unwrap().rating, Some(1.0));
+    }
+
+    #[test]
+    fn deep_structs_are_truncated() {
+        let levels = MAX_STRUCT_DEPTH + 4;
+        let mut fields = r#"<rdf:Description xmp:Rating="3">"#.to_string();
+        for level in 0..levels {
+            fields.push_str(&format!(
+                r#"<ex:S rdf:parseType="Resource" ex:Level="{level}">"#
+            ));
+        }
+        fields.push_str(&"</ex:S>".repeat(levels));
+        fields.push_str("</rdf:Description>");
+        let metadata = parse_xmp(&packet(&fields)).unwrap();
+        assert_eq!(metadata.rating, Some(3.0));
+        let depth = metadata
+            .properties
+            .iter()
+            .filter(|property| property.key.ends_with("ex:Level"))
+            .count();
+        assert_eq!(depth, MAX_STRUCT_DEPTH);
+    }
+
+    #[test]
+    fn deeply_nested_elements_return_none() {
+        let depth = MAX_ELEMENT_DEPTH + 1;
+        let fields = format!(
+            r#"<rdf:Description xmp:Rating="3">{}{}</rdf:Description>"#,
+            "<ex:a>".repeat(depth),
+            "</ex:a>".repeat(depth)
+        );
+        assert_eq!(parse_xmp(&packet(&fields)), None);
+        // 远超 roxmltree 递归能力的嵌套也不会溢出栈
+        let fields = "<ex:a>".repeat(100_000);
+        assert_eq!(parse_xmp(&packet(&fields)), None);
+    }
+}
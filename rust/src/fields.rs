@@ -0,0 +1,66 @@
+use std::str::FromStr;
+
+use serde_json::{Map, Value};
+
+use crate::api::metadata::PngTextChunk;
+
+/// 按键名查找 PNG 文本块，同名的块只使用第一个
+pub(crate) fn text_chunk<'a>(chunks: &'a [PngTextChunk], keyword: &str) -> Option<&'a str> {
+    chunks
+        .iter()
+        .find(|chunk| chunk.keyword == keyword)
+        .map(|chunk| chunk.text.as_str())
+}
+
+pub(crate) fn get_string(object: &Map<String, Value>, key: &str) -> Option<String> {
+    object.get(key).and_then(Value::as_str).map(str::to_string)
+}
+
+pub(crate) fn get_f64(object: &Map<String, Value>, key: &str) -> Option<f64> {
+    object.get(key).and_then(Value::as_f64)
+}
+
+// 部分工具会把整数写成浮点数 (例如 `28.0`) 或字符串 (例如 `"28"`)
+pub(crate) fn get_u64(object: &Map<String, Value>, key: &str) -> Option<u64> {
+    let value = object.get(key)?;
+    let text = value.as_str().map(str::trim);
+    value.as_u64().or_else(|| text?.parse().ok()).or_else(|| {
+        value
+            .as_f64()
+            .or_else(|| text?.parse().ok())
+            .filter(|number| *number >= 0.0 && number.fract() == 0.0)
+            .map(|number| number as u64)
+    })
+}
+
+pub(crate) fn get_u32(object: &Map<String, Value>, key: &str) -> Option<u32> {
+    get_u64(object, key).and_then(|number| u32::try_from(number).ok())
+}
+
+// 数值有时会写成字符串 (例如 `"seed": "12345"`)，统一按字符串读取
+pub(crate) fn get_text(object: &Map<String, Value>, key: &str) -> Option<String> {
+    object
+        .get(key)
+        .filter(|value| !value.is_null())
+        .map(value_string)
+}
+
+pub(crate) fn get_parsed<T: FromStr>(object: &Map<String, Value>, key: &str) -> Option<T> {
+    get_text(object, key).and_then(|value| value.trim().parse().ok())
+}
+
+/// 把 JSON 对象的全部键值对转换为字符串，非字符串的值保留 JSON 写法
+pub(crate) fn settings(object: &Map<String, Value>) -> Vec<(String, String)> {
+    object
+        .iter()
+        .map(|(key, value)| (key.clone(), value_string(value)))
+        .collect()
+}
+
+/// 字符串去掉引号，其他类型按 JSON 格式输出
+pub(crate) fn value_string(value: &Value) -> String {
+    match value {
+        Value::String(text) => text.clone(),
+        other => other.to_string(),
+    }
+}
@@ -25,7 +25,6 @@
 
 // Section: imports
 
-use crate::api::metadata::*;
 use flutter_rust_bridge::for_generated::byteorder::{NativeEndian, ReadBytesExt, WriteBytesExt};
 use flutter_rust_bridge::for_generated::{transform_result_dco, Lifetimeable, Lockable};
 use flutter_rust_bridge::{Handler, IntoIntoDart};
@@ -38,7 +37,7 @@ flutter_rust_bridge::frb_generated_boilerplate!(
     default_rust_auto_opaque = RustAutoOpaqueMoi,
 );
 pub(crate) const FLUTTER_RUST_BRIDGE_CODEGEN_VERSION: &str = "2.10.0";
-pub(crate) const FLUTTER_RUST_BRIDGE_CODEGEN_CONTENT_HASH: i32 = 302778711;
+pub(crate) const FLUTTER_RUST_BRIDGE_CODEGEN_CONTENT_HASH: i32 = 306968300;
 
 // Section: executor
 
@@ -46,7 +45,7 @@ flutter_rust_bridge::frb_generated_default_handler!();
 
 // Section: wire_funcs
 
-fn wire__crate__api__metadata__DataReader_new_impl(
+fn wire__crate__api__metadata__embed_stealth_metadata_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -54,7 +53,7 @@ fn wire__crate__api__metadata__DataReader_new_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "DataReader_new",
+            debug_name: "embed_stealth_metadata",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -68,29 +67,35 @@ fn wire__crate__api__metadata__DataReader_new_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_data = <Vec<u8>>::sse_decode(&mut deserializer);
+            let api_input_bytes = <Vec<u8>>::sse_decode(&mut deserializer);
+            let api_metadata = <String>::sse_decode(&mut deserializer);
+            let api_channel = <crate::api::metadata::StealthChannel>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| {
-                transform_result_sse::<_, ()>((move || {
-                    let output_ok =
-                        Result::<_, ()>::Ok(crate::api::metadata::DataReader::new(api_data))?;
-                    Ok(output_ok)
-                })())
+                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
+                    (move || {
+                        let output_ok = crate::api::metadata::embed_stealth_metadata(
+                            &api_input_bytes,
+                            api_metadata,
+                            api_channel,
+                        )?;
+                        Ok(output_ok)
+                    })(),
+                )
             }
         },
     )
 }
-fn wire__crate__api__metadata__DataReader_read_bit_impl(
-    port_: flutter_rust_bridge::for_generated::MessagePort,
+fn wire__crate__api__metadata__extract_metadata_impl(
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
     data_len_: i32,
-) {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "DataReader_read_bit",
-            port: Some(port_),
-            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+            debug_name: "extract_metadata",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
         },
         move || {
             let message = unsafe {
@@ -102,46 +107,27 @@ fn wire__crate__api__metadata__DataReader_read_bit_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_that = <RustOpaqueMoi<
-                flutter_rust_bridge::for_generated::RustAutoOpaqueInner<DataReader>,
-            >>::sse_decode(&mut deserializer);
+            let api_input_bytes = <Vec<u8>>::sse_decode(&mut deserializer);
             deserializer.end();
-            move |context| {
-                transform_result_sse::<_, ()>((move || {
-                    let mut api_that_guard = None;
-                    let decode_indices_ =
-                        flutter_rust_bridge::for_generated::lockable_compute_decode_order(vec![
-                            flutter_rust_bridge::for_generated::LockableOrderInfo::new(
-                                &api_that, 0, true,
-                            ),
-                        ]);
-                    for i in decode_indices_ {
-                        match i {
-                            0 => api_that_guard = Some(api_that.lockable_decode_sync_ref_mut()),
-                            _ => unreachable!(),
-                        }
-                    }
-                    let mut api_that_guard = api_that_guard.unwrap();
-                    let output_ok = Result::<_, ()>::Ok(
-                        crate::api::metadata::DataReader::read_bit(&mut *api_that_guard),
-                    )?;
+            transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
+                (move || {
+                    let output_ok = crate::api::metadata::extract_metadata(&api_input_bytes)?;
                     Ok(output_ok)
-                })())
-            }
+                })(),
+            )
         },
     )
 }
-fn wire__crate__api__metadata__DataReader_read_byte_impl(
-    port_: flutter_rust_bridge::for_generated::MessagePort,
+fn wire__crate__api__metadata__verify_nai_signature_impl(
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
     data_len_: i32,
-) {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "DataReader_read_byte",
-            port: Some(port_),
-            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+            debug_name: "verify_nai_signature",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
         },
         move || {
             let message = unsafe {
@@ -153,36 +139,18 @@ fn wire__crate__api__metadata__DataReader_read_byte_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_that = <RustOpaqueMoi<
-                flutter_rust_bridge::for_generated::RustAutoOpaqueInner<DataReader>,
-            >>::sse_decode(&mut deserializer);
+            let api_input_bytes = <Vec<u8>>::sse_decode(&mut deserializer);
             deserializer.end();
-            move |context| {
-                transform_result_sse::<_, ()>((move || {
-                    let mut api_that_guard = None;
-                    let decode_indices_ =
-                        flutter_rust_bridge::for_generated::lockable_compute_decode_order(vec![
-                            flutter_rust_bridge::for_generated::LockableOrderInfo::new(
-                                &api_that, 0, true,
-                            ),
-                        ]);
-                    for i in decode_indices_ {
-                        match i {
-                            0 => api_that_guard = Some(api_that.lockable_decode_sync_ref_mut()),
-                            _ => unreachable!(),
-                        }
-                    }
-                    let mut api_that_guard = api_that_guard.unwrap();
-                    let output_ok = Result::<_, ()>::Ok(
-                        crate::api::metadata::DataReader::read_byte(&mut *api_that_guard),
-                    )?;
+            transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
+                (move || {
+                    let output_ok = crate::api::metadata::verify_nai_signature(&api_input_bytes)?;
                     Ok(output_ok)
-                })())
-            }
+                })(),
+            )
         },
     )
 }
-fn wire__crate__api__metadata__DataReader_read_bytes_impl(
+fn wire__crate__api__models__index_model_folder_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -190,7 +158,7 @@ fn wire__crate__api__metadata__DataReader_read_bytes_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "DataReader_read_bytes",
+            debug_name: "index_model_folder",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -204,47 +172,34 @@ fn wire__crate__api__metadata__DataReader_read_bytes_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_that = <RustOpaqueMoi<
-                flutter_rust_bridge::for_generated::RustAutoOpaqueInner<DataReader>,
-            >>::sse_decode(&mut deserializer);
-            let api_n = <usize>::sse_decode(&mut deserializer);
+            let api_folder_path = <String>::sse_decode(&mut deserializer);
+            let api_cached_models =
+                <Vec<crate::api::models::ModelFile>>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| {
-                transform_result_sse::<_, ()>((move || {
-                    let mut api_that_guard = None;
-                    let decode_indices_ =
-                        flutter_rust_bridge::for_generated::lockable_compute_decode_order(vec![
-                            flutter_rust_bridge::for_generated::LockableOrderInfo::new(
-                                &api_that, 0, true,
-                            ),
-                        ]);
-                    for i in decode_indices_ {
-                        match i {
-                            0 => api_that_guard = Some(api_that.lockable_decode_sync_ref_mut()),
-                            _ => unreachable!(),
-                        }
-                    }
-                    let mut api_that_guard = api_that_guard.unwrap();
-                    let output_ok = Result::<_, ()>::Ok(
-                        crate::api::metadata::DataReader::read_bytes(&mut *api_that_guard, api_n),
-                    )?;
-                    Ok(output_ok)
-                })())
+                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
+                    (move || {
+                        let output_ok = crate::api::models::index_model_folder(
+                            api_folder_path,
+                            api_cached_models,
+                        )?;
+                        Ok(output_ok)
+                    })(),
+                )
             }
         },
     )
 }
-fn wire__crate__api__metadata__DataReader_read_int32_impl(
-    port_: flutter_rust_bridge::for_generated::MessagePort,
+fn wire__crate__api__models__resolve_model_hash_impl(
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
     data_len_: i32,
-) {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "DataReader_read_int32",
-            port: Some(port_),
-            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+            debug_name: "resolve_model_hash",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
         },
         move || {
             let message = unsafe {
@@ -256,43 +211,27 @@ fn wire__crate__api__metadata__DataReader_read_int32_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_that = <RustOpaqueMoi<
-                flutter_rust_bridge::for_generated::RustAutoOpaqueInner<DataReader>,
-            >>::sse_decode(&mut deserializer);
+            let api_model_hash = <String>::sse_decode(&mut deserializer);
+            let api_models = <Vec<crate::api::models::ModelFile>>::sse_decode(&mut deserializer);
             deserializer.end();
-            move |context| {
-                transform_result_sse::<_, ()>((move || {
-                    let mut api_that_guard = None;
-                    let decode_indices_ =
-                        flutter_rust_bridge::for_generated::lockable_compute_decode_order(vec![
-                            flutter_rust_bridge::for_generated::LockableOrderInfo::new(
-                                &api_that, 0, true,
-                            ),
-                        ]);
-                    for i in decode_indices_ {
-                        match i {
-                            0 => api_that_guard = Some(api_that.lockable_decode_sync_ref_mut()),
-                            _ => unreachable!(),
-                        }
-                    }
-                    let mut api_that_guard = api_that_guard.unwrap();
-                    let output_ok = Result::<_, ()>::Ok(
-                        crate::api::metadata::DataReader::read_int32(&mut *api_that_guard),
-                    )?;
-                    Ok(output_ok)
-                })())
-            }
+            transform_result_sse::<_, ()>((move || {
+                let output_ok = Result::<_, ()>::Ok(crate::api::models::resolve_model_hash(
+                    api_model_hash,
+                    api_models,
+                ))?;
+                Ok(output_ok)
+            })())
         },
     )
 }
-fn wire__crate__api__metadata__extract_metadata_impl(
+fn wire__crate__api__prompt__convert_prompt_impl(
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
     data_len_: i32,
 ) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "extract_metadata",
+            debug_name: "convert_prompt",
             port: None,
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
         },
@@ -306,14 +245,80 @@ fn wire__crate__api__metadata__extract_metadata_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_input_bytes = <Vec<u8>>::sse_decode(&mut deserializer);
+            let api_prompt = <String>::sse_decode(&mut deserializer);
+            let api_from = <crate::api::prompt::PromptDialect>::sse_decode(&mut deserializer);
+            let api_to = <crate::api::prompt::PromptDialect>::sse_decode(&mut deserializer);
             deserializer.end();
-            transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
-                (move || {
-                    let output_ok = crate::api::metadata::extract_metadata(&api_input_bytes)?;
-                    Ok(output_ok)
-                })(),
-            )
+            transform_result_sse::<_, ()>((move || {
+                let output_ok = Result::<_, ()>::Ok(crate::api::prompt::convert_prompt(
+                    api_prompt, api_from, api_to,
+                ))?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__prompt__parse_prompt_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "parse_prompt",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_prompt = <String>::sse_decode(&mut deserializer);
+            let api_dialect = <crate::api::prompt::PromptDialect>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse::<_, ()>((move || {
+                let output_ok =
+                    Result::<_, ()>::Ok(crate::api::prompt::parse_prompt(api_prompt, api_dialect))?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__prompt__render_prompt_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "render_prompt",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_tags = <Vec<crate::api::prompt::PromptTag>>::sse_decode(&mut deserializer);
+            let api_dialect = <crate::api::prompt::PromptDialect>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse::<_, ()>((move || {
+                let output_ok =
+                    Result::<_, ()>::Ok(crate::api::prompt::render_prompt(api_tags, api_dialect))?;
+                Ok(output_ok)
+            })())
         },
     )
 }
@@ -381,6 +386,80 @@ fn wire__crate__api__simple__init_app_impl(
         },
     )
 }
+fn wire__crate__api__strip__strip_metadata_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "strip_metadata",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_input_bytes = <Vec<u8>>::sse_decode(&mut deserializer);
+            let api_options = <crate::api::strip::StripOptions>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
+                    (move || {
+                        let output_ok =
+                            crate::api::strip::strip_metadata(&api_input_bytes, api_options)?;
+                        Ok(output_ok)
+                    })(),
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__writer__write_metadata_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "write_metadata",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_path = <String>::sse_decode(&mut deserializer);
+            let api_chunks =
+                <Vec<crate::api::metadata::PngTextChunk>>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
+                    (move || {
+                        let output_ok = crate::api::writer::write_metadata(api_path, api_chunks)?;
+                        Ok(output_ok)
+                    })(),
+                )
+            }
+        },
+    )
+}
 fn wire__crate__api__scan__scan_folder_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
@@ -429,10 +508,6 @@ fn wire__crate__api__scan__scan_folder_impl(
 
 // Section: related_funcs
 
-flutter_rust_bridge::frb_generated_moi_arc_impl_value!(
-    flutter_rust_bridge::for_generated::RustAutoOpaqueInner<DataReader>
-);
-
 // Section: dart2rust
 
 impl SseDecode for flutter_rust_bridge::for_generated::anyhow::Error {
@@ -443,16 +518,6 @@ impl SseDecode for flutter_rust_bridge::for_generated::anyhow::Error {
     }
 }
 
-impl SseDecode for DataReader {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut inner = <RustOpaqueMoi<
-            flutter_rust_bridge::for_generated::RustAutoOpaqueInner<DataReader>,
-        >>::sse_decode(deserializer);
-        return flutter_rust_bridge::for_generated::rust_auto_opaque_decode_owned(inner);
-    }
-}
-
 impl SseDecode for std::collections::HashMap<String, u64> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
@@ -461,16 +526,6 @@ impl SseDecode for std::collections::HashMap<String, u64> {
     }
 }
 
-impl SseDecode
-    for RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<DataReader>>
-{
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut inner = <usize>::sse_decode(deserializer);
-        return decode_rust_opaque_moi(inner);
-    }
-}
-
 impl SseDecode
     for StreamSink<crate::api::scan::ScanProgress, flutter_rust_bridge::for_generated::SseCodec>
 {
@@ -489,250 +544,1221 @@ impl SseDecode for String {
     }
 }
 
-impl SseDecode for f64 {
+impl SseDecode for crate::api::a1111::A1111Parameters {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        deserializer.cursor.read_f64::<NativeEndian>().unwrap()
+        let mut var_prompt = <String>::sse_decode(deserializer);
+        let mut var_negativePrompt = <Option<String>>::sse_decode(deserializer);
+        let mut var_steps = <Option<u32>>::sse_decode(deserializer);
+        let mut var_sampler = <Option<String>>::sse_decode(deserializer);
+        let mut var_scheduleType = <Option<String>>::sse_decode(deserializer);
+        let mut var_cfgScale = <Option<f64>>::sse_decode(deserializer);
+        let mut var_seed = <Option<u64>>::sse_decode(deserializer);
+        let mut var_width = <Option<u32>>::sse_decode(deserializer);
+        let mut var_height = <Option<u32>>::sse_decode(deserializer);
+        let mut var_model = <Option<String>>::sse_decode(deserializer);
+        let mut var_modelHash = <Option<String>>::sse_decode(deserializer);
+        let mut var_denoisingStrength = <Option<f64>>::sse_decode(deserializer);
+        let mut var_settings = <Vec<(String, String)>>::sse_decode(deserializer);
+        return crate::api::a1111::A1111Parameters {
+            prompt: var_prompt,
+            negative_prompt: var_negativePrompt,
+            steps: var_steps,
+            sampler: var_sampler,
+            schedule_type: var_scheduleType,
+            cfg_scale: var_cfgScale,
+            seed: var_seed,
+            width: var_width,
+            height: var_height,
+            model: var_model,
+            model_hash: var_modelHash,
+            denoising_strength: var_denoisingStrength,
+            settings: var_settings,
+        };
     }
 }
 
-impl SseDecode for crate::api::scan::FolderScanResult {
+impl SseDecode for bool {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut var_folderPath = <String>::sse_decode(deserializer);
-        let mut var_scanTimestamp = <u64>::sse_decode(deserializer);
-        let mut var_totalImageCount = <u32>::sse_decode(deserializer);
-        return crate::api::scan::FolderScanResult {
-            folder_path: var_folderPath,
-            scan_timestamp: var_scanTimestamp,
-            total_image_count: var_totalImageCount,
-        };
+        deserializer.cursor.read_u8().unwrap() != 0
     }
 }
 
-impl SseDecode for i32 {
+impl SseDecode for crate::api::c2pa::C2paAction {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        deserializer.cursor.read_i32::<NativeEndian>().unwrap()
+        let mut var_action = <String>::sse_decode(deserializer);
+        let mut var_softwareAgent = <Option<String>>::sse_decode(deserializer);
+        let mut var_digitalSourceType = <Option<String>>::sse_decode(deserializer);
+        let mut var_when = <Option<String>>::sse_decode(deserializer);
+        return crate::api::c2pa::C2paAction {
+            action: var_action,
+            software_agent: var_softwareAgent,
+            digital_source_type: var_digitalSourceType,
+            when: var_when,
+        };
     }
 }
 
-impl SseDecode for crate::api::metadata::ImageInfo {
+impl SseDecode for crate::api::c2pa::C2paIngredient {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut var_aspectRatio = <f64>::sse_decode(deserializer);
-        let mut var_metadataString = <Option<String>>::sse_decode(deserializer);
-        return crate::api::metadata::ImageInfo {
-            aspect_ratio: var_aspectRatio,
-            metadata_string: var_metadataString,
+        let mut var_title = <Option<String>>::sse_decode(deserializer);
+        let mut var_format = <Option<String>>::sse_decode(deserializer);
+        let mut var_relationship = <Option<String>>::sse_decode(deserializer);
+        return crate::api::c2pa::C2paIngredient {
+            title: var_title,
+            format: var_format,
+            relationship: var_relationship,
         };
     }
 }
 
-impl SseDecode for crate::api::scan::ImageScanResult {
+impl SseDecode for crate::api::c2pa::C2paManifest {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut var_filePath = <String>::sse_decode(deserializer);
-        let mut var_fileLastModified = <u64>::sse_decode(deserializer);
-        let mut var_imageAspectRatio = <Option<f64>>::sse_decode(deserializer);
-        let mut var_metadataText = <Option<String>>::sse_decode(deserializer);
-        return crate::api::scan::ImageScanResult {
-            file_path: var_filePath,
-            file_last_modified: var_fileLastModified,
-            image_aspect_ratio: var_imageAspectRatio,
-            metadata_text: var_metadataText,
+        let mut var_label = <String>::sse_decode(deserializer);
+        let mut var_claimGenerator = <Option<String>>::sse_decode(deserializer);
+        let mut var_title = <Option<String>>::sse_decode(deserializer);
+        let mut var_format = <Option<String>>::sse_decode(deserializer);
+        let mut var_actions = <Vec<crate::api::c2pa::C2paAction>>::sse_decode(deserializer);
+        let mut var_ingredients = <Vec<crate::api::c2pa::C2paIngredient>>::sse_decode(deserializer);
+        let mut var_assertionLabels = <Vec<String>>::sse_decode(deserializer);
+        return crate::api::c2pa::C2paManifest {
+            label: var_label,
+            claim_generator: var_claimGenerator,
+            title: var_title,
+            format: var_format,
+            actions: var_actions,
+            ingredients: var_ingredients,
+            assertion_labels: var_assertionLabels,
         };
     }
 }
 
-impl SseDecode for Vec<crate::api::scan::ImageScanResult> {
+impl SseDecode for crate::api::comfyui::ComfyUiLora {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_name = <String>::sse_decode(deserializer);
+        let mut var_strengthModel = <Option<f64>>::sse_decode(deserializer);
+        let mut var_strengthClip = <Option<f64>>::sse_decode(deserializer);
+        return crate::api::comfyui::ComfyUiLora {
+            name: var_name,
+            strength_model: var_strengthModel,
+            strength_clip: var_strengthClip,
+        };
+    }
+}
+
+impl SseDecode for crate::api::comfyui::ComfyUiMetadata {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_promptGraph = <Option<String>>::sse_decode(deserializer);
+        let mut var_workflow = <Option<String>>::sse_decode(deserializer);
+        let mut var_summary =
+            <Option<crate::api::comfyui::ComfyUiSummary>>::sse_decode(deserializer);
+        return crate::api::comfyui::ComfyUiMetadata {
+            prompt_graph: var_promptGraph,
+            workflow: var_workflow,
+            summary: var_summary,
+        };
+    }
+}
+
+impl SseDecode for crate::api::comfyui::ComfyUiSummary {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_positivePrompt = <Option<String>>::sse_decode(deserializer);
+        let mut var_negativePrompt = <Option<String>>::sse_decode(deserializer);
+        let mut var_seed = <Option<u64>>::sse_decode(deserializer);
+        let mut var_steps = <Option<u32>>::sse_decode(deserializer);
+        let mut var_cfg = <Option<f64>>::sse_decode(deserializer);
+        let mut var_samplerName = <Option<String>>::sse_decode(deserializer);
+        let mut var_scheduler = <Option<String>>::sse_decode(deserializer);
+        let mut var_denoise = <Option<f64>>::sse_decode(deserializer);
+        let mut var_checkpoint = <Option<String>>::sse_decode(deserializer);
+        let mut var_loras = <Vec<crate::api::comfyui::ComfyUiLora>>::sse_decode(deserializer);
+        return crate::api::comfyui::ComfyUiSummary {
+            positive_prompt: var_positivePrompt,
+            negative_prompt: var_negativePrompt,
+            seed: var_seed,
+            steps: var_steps,
+            cfg: var_cfg,
+            sampler_name: var_samplerName,
+            scheduler: var_scheduler,
+            denoise: var_denoise,
+            checkpoint: var_checkpoint,
+            loras: var_loras,
+        };
+    }
+}
+
+impl SseDecode for f64 {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        deserializer.cursor.read_f64::<NativeEndian>().unwrap()
+    }
+}
+
+impl SseDecode for crate::api::scan::FolderScanResult {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_folderPath = <String>::sse_decode(deserializer);
+        let mut var_scanTimestamp = <u64>::sse_decode(deserializer);
+        let mut var_totalImageCount = <u32>::sse_decode(deserializer);
+        return crate::api::scan::FolderScanResult {
+            folder_path: var_folderPath,
+            scan_timestamp: var_scanTimestamp,
+            total_image_count: var_totalImageCount,
+        };
+    }
+}
+
+impl SseDecode for crate::api::generator::Generator {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <i32>::sse_decode(deserializer);
+        return match inner {
+            0 => crate::api::generator::Generator::NovelAi,
+            1 => crate::api::generator::Generator::A1111,
+            2 => crate::api::generator::Generator::ComfyUi,
+            3 => crate::api::generator::Generator::InvokeAi,
+            4 => crate::api::generator::Generator::Fooocus,
+            5 => crate::api::generator::Generator::SwarmUi,
+            6 => crate::api::generator::Generator::Midjourney,
+            7 => crate::api::generator::Generator::C2pa,
+            8 => crate::api::generator::Generator::Unknown,
+            _ => unreachable!("Invalid variant for Generator: {}", inner),
+        };
+    }
+}
+
+impl SseDecode for i32 {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        deserializer.cursor.read_i32::<NativeEndian>().unwrap()
+    }
+}
+
+impl SseDecode for crate::api::metadata::ImageInfo {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_aspectRatio = <f64>::sse_decode(deserializer);
+        let mut var_generator = <crate::api::generator::Generator>::sse_decode(deserializer);
+        let mut var_metadataString = <Option<String>>::sse_decode(deserializer);
+        let mut var_naiMetadata = <Option<crate::api::nai::NaiMetadata>>::sse_decode(deserializer);
+        let mut var_a1111Parameters =
+            <Option<crate::api::a1111::A1111Parameters>>::sse_decode(deserializer);
+        let mut var_fooocusParameters =
+            <Option<crate::api::a1111::A1111Parameters>>::sse_decode(deserializer);
+        let mut var_swarmuiParameters =
+            <Option<crate::api::a1111::A1111Parameters>>::sse_decode(deserializer);
+        let mut var_comfyui =
+            <Option<crate::api::comfyui::ComfyUiMetadata>>::sse_decode(deserializer);
+        let mut var_invokeai =
+            <Option<crate::api::invokeai::InvokeAiMetadata>>::sse_decode(deserializer);
+        let mut var_networks =
+            <Vec<crate::api::networks::NetworkReference>>::sse_decode(deserializer);
+        let mut var_textChunks =
+            <Vec<crate::api::metadata::PngTextChunk>>::sse_decode(deserializer);
+        let mut var_xmpPacket = <Option<String>>::sse_decode(deserializer);
+        let mut var_xmp = <Option<crate::api::xmp::XmpMetadata>>::sse_decode(deserializer);
+        let mut var_c2paManifests = <Vec<crate::api::c2pa::C2paManifest>>::sse_decode(deserializer);
+        let mut var_stealthErrorKind =
+            <Option<crate::api::error::MetadataErrorKind>>::sse_decode(deserializer);
+        let mut var_stealthErrorMessage = <Option<String>>::sse_decode(deserializer);
+        return crate::api::metadata::ImageInfo {
+            aspect_ratio: var_aspectRatio,
+            generator: var_generator,
+            metadata_string: var_metadataString,
+            nai_metadata: var_naiMetadata,
+            a1111_parameters: var_a1111Parameters,
+            fooocus_parameters: var_fooocusParameters,
+            swarmui_parameters: var_swarmuiParameters,
+            comfyui: var_comfyui,
+            invokeai: var_invokeai,
+            networks: var_networks,
+            text_chunks: var_textChunks,
+            xmp_packet: var_xmpPacket,
+            xmp: var_xmp,
+            c2pa_manifests: var_c2paManifests,
+            stealth_error_kind: var_stealthErrorKind,
+            stealth_error_message: var_stealthErrorMessage,
+        };
+    }
+}
+
+impl SseDecode for crate::api::scan::ImageScanResult {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_filePath = <String>::sse_decode(deserializer);
+        let mut var_fileLastModified = <u64>::sse_decode(deserializer);
+        let mut var_imageAspectRatio = <Option<f64>>::sse_decode(deserializer);
+        let mut var_metadataText = <Option<String>>::sse_decode(deserializer);
+        let mut var_errorKind =
+            <Option<crate::api::error::MetadataErrorKind>>::sse_decode(deserializer);
+        let mut var_errorMessage = <Option<String>>::sse_decode(deserializer);
+        return crate::api::scan::ImageScanResult {
+            file_path: var_filePath,
+            file_last_modified: var_fileLastModified,
+            image_aspect_ratio: var_imageAspectRatio,
+            metadata_text: var_metadataText,
+            error_kind: var_errorKind,
+            error_message: var_errorMessage,
+        };
+    }
+}
+
+impl SseDecode for crate::api::invokeai::InvokeAiControlLayer {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_adapterType = <String>::sse_decode(deserializer);
+        let mut var_model = <Option<String>>::sse_decode(deserializer);
+        let mut var_weight = <Option<f64>>::sse_decode(deserializer);
+        let mut var_beginStepPercent = <Option<f64>>::sse_decode(deserializer);
+        let mut var_endStepPercent = <Option<f64>>::sse_decode(deserializer);
+        let mut var_isEnabled = <bool>::sse_decode(deserializer);
+        return crate::api::invokeai::InvokeAiControlLayer {
+            adapter_type: var_adapterType,
+            model: var_model,
+            weight: var_weight,
+            begin_step_percent: var_beginStepPercent,
+            end_step_percent: var_endStepPercent,
+            is_enabled: var_isEnabled,
+        };
+    }
+}
+
+impl SseDecode for crate::api::invokeai::InvokeAiLora {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_name = <String>::sse_decode(deserializer);
+        let mut var_weight = <Option<f64>>::sse_decode(deserializer);
+        return crate::api::invokeai::InvokeAiLora {
+            name: var_name,
+            weight: var_weight,
+        };
+    }
+}
+
+impl SseDecode for crate::api::invokeai::InvokeAiMetadata {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_metadata = <Option<String>>::sse_decode(deserializer);
+        let mut var_graph = <Option<String>>::sse_decode(deserializer);
+        let mut var_appVersion = <Option<String>>::sse_decode(deserializer);
+        let mut var_generationMode = <Option<String>>::sse_decode(deserializer);
+        let mut var_positivePrompt = <Option<String>>::sse_decode(deserializer);
+        let mut var_negativePrompt = <Option<String>>::sse_decode(deserializer);
+        let mut var_positiveStylePrompt = <Option<String>>::sse_decode(deserializer);
+        let mut var_negativeStylePrompt = <Option<String>>::sse_decode(deserializer);
+        let mut var_seed = <Option<u64>>::sse_decode(deserializer);
+        let mut var_steps = <Option<u32>>::sse_decode(deserializer);
+        let mut var_cfgScale = <Option<f64>>::sse_decode(deserializer);
+        let mut var_cfgRescaleMultiplier = <Option<f64>>::sse_decode(deserializer);
+        let mut var_scheduler = <Option<String>>::sse_decode(deserializer);
+        let mut var_width = <Option<u32>>::sse_decode(deserializer);
+        let mut var_height = <Option<u32>>::sse_decode(deserializer);
+        let mut var_model = <Option<String>>::sse_decode(deserializer);
+        let mut var_modelHash = <Option<String>>::sse_decode(deserializer);
+        let mut var_vae = <Option<String>>::sse_decode(deserializer);
+        let mut var_loras = <Vec<crate::api::invokeai::InvokeAiLora>>::sse_decode(deserializer);
+        let mut var_regionalPrompts =
+            <Vec<crate::api::invokeai::InvokeAiRegionalPrompt>>::sse_decode(deserializer);
+        let mut var_controlLayers =
+            <Vec<crate::api::invokeai::InvokeAiControlLayer>>::sse_decode(deserializer);
+        return crate::api::invokeai::InvokeAiMetadata {
+            metadata: var_metadata,
+            graph: var_graph,
+            app_version: var_appVersion,
+            generation_mode: var_generationMode,
+            positive_prompt: var_positivePrompt,
+            negative_prompt: var_negativePrompt,
+            positive_style_prompt: var_positiveStylePrompt,
+            negative_style_prompt: var_negativeStylePrompt,
+            seed: var_seed,
+            steps: var_steps,
+            cfg_scale: var_cfgScale,
+            cfg_rescale_multiplier: var_cfgRescaleMultiplier,
+            scheduler: var_scheduler,
+            width: var_width,
+            height: var_height,
+            model: var_model,
+            model_hash: var_modelHash,
+            vae: var_vae,
+            loras: var_loras,
+            regional_prompts: var_regionalPrompts,
+            control_layers: var_controlLayers,
+        };
+    }
+}
+
+impl SseDecode for crate::api::invokeai::InvokeAiRegionalPrompt {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_name = <Option<String>>::sse_decode(deserializer);
+        let mut var_positivePrompt = <Option<String>>::sse_decode(deserializer);
+        let mut var_negativePrompt = <Option<String>>::sse_decode(deserializer);
+        let mut var_isEnabled = <bool>::sse_decode(deserializer);
+        return crate::api::invokeai::InvokeAiRegionalPrompt {
+            name: var_name,
+            positive_prompt: var_positivePrompt,
+            negative_prompt: var_negativePrompt,
+            is_enabled: var_isEnabled,
+        };
+    }
+}
+
+impl SseDecode for Vec<String> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
         let mut len_ = <i32>::sse_decode(deserializer);
         let mut ans_ = vec![];
         for idx_ in 0..len_ {
-            ans_.push(<crate::api::scan::ImageScanResult>::sse_decode(
-                deserializer,
-            ));
+            ans_.push(<String>::sse_decode(deserializer));
         }
         return ans_;
     }
 }
 
-impl SseDecode for Vec<u8> {
+impl SseDecode for Vec<crate::api::c2pa::C2paAction> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
         let mut len_ = <i32>::sse_decode(deserializer);
         let mut ans_ = vec![];
         for idx_ in 0..len_ {
-            ans_.push(<u8>::sse_decode(deserializer));
+            ans_.push(<crate::api::c2pa::C2paAction>::sse_decode(deserializer));
         }
         return ans_;
     }
 }
 
-impl SseDecode for Vec<(String, u64)> {
+impl SseDecode for Vec<crate::api::c2pa::C2paIngredient> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
         let mut len_ = <i32>::sse_decode(deserializer);
         let mut ans_ = vec![];
         for idx_ in 0..len_ {
-            ans_.push(<(String, u64)>::sse_decode(deserializer));
+            ans_.push(<crate::api::c2pa::C2paIngredient>::sse_decode(deserializer));
         }
         return ans_;
     }
 }
 
-impl SseDecode for Option<String> {
+impl SseDecode for Vec<crate::api::c2pa::C2paManifest> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        if (<bool>::sse_decode(deserializer)) {
-            return Some(<String>::sse_decode(deserializer));
-        } else {
-            return None;
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::c2pa::C2paManifest>::sse_decode(deserializer));
         }
+        return ans_;
     }
 }
 
-impl SseDecode for Option<f64> {
+impl SseDecode for Vec<crate::api::comfyui::ComfyUiLora> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        if (<bool>::sse_decode(deserializer)) {
-            return Some(<f64>::sse_decode(deserializer));
-        } else {
-            return None;
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::comfyui::ComfyUiLora>::sse_decode(deserializer));
         }
+        return ans_;
     }
 }
 
-impl SseDecode for Option<crate::api::scan::FolderScanResult> {
+impl SseDecode for Vec<crate::api::scan::ImageScanResult> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        if (<bool>::sse_decode(deserializer)) {
-            return Some(<crate::api::scan::FolderScanResult>::sse_decode(
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::scan::ImageScanResult>::sse_decode(
                 deserializer,
             ));
-        } else {
-            return None;
         }
+        return ans_;
     }
 }
 
-impl SseDecode for Option<Vec<crate::api::scan::ImageScanResult>> {
+impl SseDecode for Vec<crate::api::invokeai::InvokeAiControlLayer> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        if (<bool>::sse_decode(deserializer)) {
-            return Some(<Vec<crate::api::scan::ImageScanResult>>::sse_decode(
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::invokeai::InvokeAiControlLayer>::sse_decode(
                 deserializer,
             ));
-        } else {
-            return None;
         }
+        return ans_;
     }
 }
 
-impl SseDecode for (String, u64) {
+impl SseDecode for Vec<crate::api::invokeai::InvokeAiLora> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut var_field0 = <String>::sse_decode(deserializer);
-        let mut var_field1 = <u64>::sse_decode(deserializer);
-        return (var_field0, var_field1);
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::invokeai::InvokeAiLora>::sse_decode(
+                deserializer,
+            ));
+        }
+        return ans_;
     }
 }
 
-impl SseDecode for crate::api::scan::ScanProgress {
+impl SseDecode for Vec<crate::api::invokeai::InvokeAiRegionalPrompt> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut var_totalToProcess = <u32>::sse_decode(deserializer);
-        let mut var_processed = <u32>::sse_decode(deserializer);
-        let mut var_imageScanResults =
-            <Option<Vec<crate::api::scan::ImageScanResult>>>::sse_decode(deserializer);
-        let mut var_folderScanResult =
-            <Option<crate::api::scan::FolderScanResult>>::sse_decode(deserializer);
-        return crate::api::scan::ScanProgress {
-            total_to_process: var_totalToProcess,
-            processed: var_processed,
-            image_scan_results: var_imageScanResults,
-            folder_scan_result: var_folderScanResult,
-        };
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::invokeai::InvokeAiRegionalPrompt>::sse_decode(
+                deserializer,
+            ));
+        }
+        return ans_;
     }
 }
 
-impl SseDecode for u32 {
+impl SseDecode for Vec<crate::api::models::ModelFile> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        deserializer.cursor.read_u32::<NativeEndian>().unwrap()
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::models::ModelFile>::sse_decode(deserializer));
+        }
+        return ans_;
     }
 }
 
-impl SseDecode for u64 {
+impl SseDecode for Vec<crate::api::nai::NaiCharCaption> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        deserializer.cursor.read_u64::<NativeEndian>().unwrap()
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::nai::NaiCharCaption>::sse_decode(deserializer));
+        }
+        return ans_;
     }
 }
 
-impl SseDecode for u8 {
+impl SseDecode for Vec<crate::api::nai::NaiCoordinate> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        deserializer.cursor.read_u8().unwrap()
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::nai::NaiCoordinate>::sse_decode(deserializer));
+        }
+        return ans_;
     }
 }
 
-impl SseDecode for () {
+impl SseDecode for Vec<crate::api::networks::NetworkReference> {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {}
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::networks::NetworkReference>::sse_decode(
+                deserializer,
+            ));
+        }
+        return ans_;
+    }
 }
 
-impl SseDecode for usize {
+impl SseDecode for Vec<crate::api::metadata::PngTextChunk> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        deserializer.cursor.read_u64::<NativeEndian>().unwrap() as _
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::metadata::PngTextChunk>::sse_decode(
+                deserializer,
+            ));
+        }
+        return ans_;
     }
 }
 
-impl SseDecode for bool {
+impl SseDecode for Vec<f64> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        deserializer.cursor.read_u8().unwrap() != 0
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<f64>::sse_decode(deserializer));
+        }
+        return ans_;
     }
 }
 
-fn pde_ffi_dispatcher_primary_impl(
-    func_id: i32,
-    port: flutter_rust_bridge::for_generated::MessagePort,
-    ptr: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
-    rust_vec_len: i32,
-    data_len: i32,
-) {
-    // Codec=Pde (Serialization + dispatch), see doc to use other codecs
-    match func_id {
-        1 => wire__crate__api__metadata__DataReader_new_impl(port, ptr, rust_vec_len, data_len),
-        2 => {
-            wire__crate__api__metadata__DataReader_read_bit_impl(port, ptr, rust_vec_len, data_len)
-        }
-        3 => {
-            wire__crate__api__metadata__DataReader_read_byte_impl(port, ptr, rust_vec_len, data_len)
+impl SseDecode for Vec<u8> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<u8>::sse_decode(deserializer));
         }
-        4 => wire__crate__api__metadata__DataReader_read_bytes_impl(
-            port,
-            ptr,
-            rust_vec_len,
-            data_len,
-        ),
-        5 => wire__crate__api__metadata__DataReader_read_int32_impl(
-            port,
-            ptr,
-            rust_vec_len,
-            data_len,
-        ),
-        8 => wire__crate__api__simple__init_app_impl(port, ptr, rust_vec_len, data_len),
-        9 => wire__crate__api__scan__scan_folder_impl(port, ptr, rust_vec_len, data_len),
-        _ => unreachable!(),
+        return ans_;
     }
 }
 
-fn pde_ffi_dispatcher_sync_impl(
+impl SseDecode for Vec<crate::api::prompt::PromptTag> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::prompt::PromptTag>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<(String, String)> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<(String, String)>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<(String, u64)> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<(String, u64)>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::xmp::XmpProperty> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::xmp::XmpProperty>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for crate::api::error::MetadataErrorKind {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <i32>::sse_decode(deserializer);
+        return match inner {
+            0 => crate::api::error::MetadataErrorKind::UnsupportedFormat,
+            1 => crate::api::error::MetadataErrorKind::DecodeFailed,
+            2 => crate::api::error::MetadataErrorKind::InvalidDimensions,
+            3 => crate::api::error::MetadataErrorKind::TruncatedStealthPayload,
+            4 => crate::api::error::MetadataErrorKind::OversizedStealthPayload,
+            5 => crate::api::error::MetadataErrorKind::InvalidStealthPayload,
+            6 => crate::api::error::MetadataErrorKind::NotFound,
+            7 => crate::api::error::MetadataErrorKind::PermissionDenied,
+            8 => crate::api::error::MetadataErrorKind::Io,
+            _ => unreachable!("Invalid variant for MetadataErrorKind: {}", inner),
+        };
+    }
+}
+
+impl SseDecode for crate::api::models::ModelFile {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_filePath = <String>::sse_decode(deserializer);
+        let mut var_fileSize = <u64>::sse_decode(deserializer);
+        let mut var_fileLastModified = <u64>::sse_decode(deserializer);
+        let mut var_sha256 = <String>::sse_decode(deserializer);
+        let mut var_autoV1 = <String>::sse_decode(deserializer);
+        let mut var_autoV2 = <String>::sse_decode(deserializer);
+        return crate::api::models::ModelFile {
+            file_path: var_filePath,
+            file_size: var_fileSize,
+            file_last_modified: var_fileLastModified,
+            sha256: var_sha256,
+            auto_v1: var_autoV1,
+            auto_v2: var_autoV2,
+        };
+    }
+}
+
+impl SseDecode for crate::api::nai::NaiCharCaption {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_charCaption = <String>::sse_decode(deserializer);
+        let mut var_centers = <Vec<crate::api::nai::NaiCoordinate>>::sse_decode(deserializer);
+        return crate::api::nai::NaiCharCaption {
+            char_caption: var_charCaption,
+            centers: var_centers,
+        };
+    }
+}
+
+impl SseDecode for crate::api::nai::NaiCoordinate {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_x = <f64>::sse_decode(deserializer);
+        let mut var_y = <f64>::sse_decode(deserializer);
+        return crate::api::nai::NaiCoordinate { x: var_x, y: var_y };
+    }
+}
+
+impl SseDecode for crate::api::nai::NaiMetadata {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_title = <Option<String>>::sse_decode(deserializer);
+        let mut var_description = <Option<String>>::sse_decode(deserializer);
+        let mut var_software = <Option<String>>::sse_decode(deserializer);
+        let mut var_source = <Option<String>>::sse_decode(deserializer);
+        let mut var_generationTime = <Option<f64>>::sse_decode(deserializer);
+        let mut var_prompt = <Option<String>>::sse_decode(deserializer);
+        let mut var_negativePrompt = <Option<String>>::sse_decode(deserializer);
+        let mut var_seed = <Option<u64>>::sse_decode(deserializer);
+        let mut var_steps = <Option<u32>>::sse_decode(deserializer);
+        let mut var_sampler = <Option<String>>::sse_decode(deserializer);
+        let mut var_noiseSchedule = <Option<String>>::sse_decode(deserializer);
+        let mut var_scale = <Option<f64>>::sse_decode(deserializer);
+        let mut var_cfgRescale = <Option<f64>>::sse_decode(deserializer);
+        let mut var_uncondScale = <Option<f64>>::sse_decode(deserializer);
+        let mut var_width = <Option<u32>>::sse_decode(deserializer);
+        let mut var_height = <Option<u32>>::sse_decode(deserializer);
+        let mut var_nSamples = <Option<u32>>::sse_decode(deserializer);
+        let mut var_sm = <Option<bool>>::sse_decode(deserializer);
+        let mut var_smDyn = <Option<bool>>::sse_decode(deserializer);
+        let mut var_requestType = <Option<String>>::sse_decode(deserializer);
+        let mut var_signedHash = <Option<String>>::sse_decode(deserializer);
+        let mut var_v4Prompt = <Option<crate::api::nai::NaiV4Prompt>>::sse_decode(deserializer);
+        let mut var_v4NegativePrompt =
+            <Option<crate::api::nai::NaiV4Prompt>>::sse_decode(deserializer);
+        let mut var_referenceInformationExtracted = <Vec<f64>>::sse_decode(deserializer);
+        let mut var_referenceStrength = <Vec<f64>>::sse_decode(deserializer);
+        return crate::api::nai::NaiMetadata {
+            title: var_title,
+            description: var_description,
+            software: var_software,
+            source: var_source,
+            generation_time: var_generationTime,
+            prompt: var_prompt,
+            negative_prompt: var_negativePrompt,
+            seed: var_seed,
+            steps: var_steps,
+            sampler: var_sampler,
+            noise_schedule: var_noiseSchedule,
+            scale: var_scale,
+            cfg_rescale: var_cfgRescale,
+            uncond_scale: var_uncondScale,
+            width: var_width,
+            height: var_height,
+            n_samples: var_nSamples,
+            sm: var_sm,
+            sm_dyn: var_smDyn,
+            request_type: var_requestType,
+            signed_hash: var_signedHash,
+            v4_prompt: var_v4Prompt,
+            v4_negative_prompt: var_v4NegativePrompt,
+            reference_information_extracted: var_referenceInformationExtracted,
+            reference_strength: var_referenceStrength,
+        };
+    }
+}
+
+impl SseDecode for crate::api::metadata::NaiSignatureStatus {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <i32>::sse_decode(deserializer);
+        return match inner {
+            0 => crate::api::metadata::NaiSignatureStatus::Valid,
+            1 => crate::api::metadata::NaiSignatureStatus::Tampered,
+            2 => crate::api::metadata::NaiSignatureStatus::Unsigned,
+            _ => unreachable!("Invalid variant for NaiSignatureStatus: {}", inner),
+        };
+    }
+}
+
+impl SseDecode for crate::api::nai::NaiV4Prompt {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_baseCaption = <String>::sse_decode(deserializer);
+        let mut var_charCaptions = <Vec<crate::api::nai::NaiCharCaption>>::sse_decode(deserializer);
+        let mut var_useCoords = <Option<bool>>::sse_decode(deserializer);
+        let mut var_useOrder = <Option<bool>>::sse_decode(deserializer);
+        return crate::api::nai::NaiV4Prompt {
+            base_caption: var_baseCaption,
+            char_captions: var_charCaptions,
+            use_coords: var_useCoords,
+            use_order: var_useOrder,
+        };
+    }
+}
+
+impl SseDecode for crate::api::networks::NetworkKind {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <i32>::sse_decode(deserializer);
+        return match inner {
+            0 => crate::api::networks::NetworkKind::Lora,
+            1 => crate::api::networks::NetworkKind::Embedding,
+            2 => crate::api::networks::NetworkKind::Hypernetwork,
+            _ => unreachable!("Invalid variant for NetworkKind: {}", inner),
+        };
+    }
+}
+
+impl SseDecode for crate::api::networks::NetworkReference {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_kind = <crate::api::networks::NetworkKind>::sse_decode(deserializer);
+        let mut var_name = <String>::sse_decode(deserializer);
+        let mut var_weight = <Option<f64>>::sse_decode(deserializer);
+        let mut var_clipWeight = <Option<f64>>::sse_decode(deserializer);
+        let mut var_hash = <Option<String>>::sse_decode(deserializer);
+        return crate::api::networks::NetworkReference {
+            kind: var_kind,
+            name: var_name,
+            weight: var_weight,
+            clip_weight: var_clipWeight,
+            hash: var_hash,
+        };
+    }
+}
+
+impl SseDecode for Option<String> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<String>::sse_decode(deserializer));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<crate::api::a1111::A1111Parameters> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<crate::api::a1111::A1111Parameters>::sse_decode(
+                deserializer,
+            ));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<bool> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<bool>::sse_decode(deserializer));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<crate::api::comfyui::ComfyUiMetadata> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<crate::api::comfyui::ComfyUiMetadata>::sse_decode(
+                deserializer,
+            ));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<crate::api::comfyui::ComfyUiSummary> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<crate::api::comfyui::ComfyUiSummary>::sse_decode(
+                deserializer,
+            ));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<f64> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<f64>::sse_decode(deserializer));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<crate::api::scan::FolderScanResult> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<crate::api::scan::FolderScanResult>::sse_decode(
+                deserializer,
+            ));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<crate::api::invokeai::InvokeAiMetadata> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<crate::api::invokeai::InvokeAiMetadata>::sse_decode(
+                deserializer,
+            ));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<crate::api::error::MetadataErrorKind> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<crate::api::error::MetadataErrorKind>::sse_decode(
+                deserializer,
+            ));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<crate::api::models::ModelFile> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<crate::api::models::ModelFile>::sse_decode(deserializer));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<crate::api::nai::NaiMetadata> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<crate::api::nai::NaiMetadata>::sse_decode(deserializer));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<crate::api::nai::NaiV4Prompt> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<crate::api::nai::NaiV4Prompt>::sse_decode(deserializer));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<u32> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<u32>::sse_decode(deserializer));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<u64> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<u64>::sse_decode(deserializer));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<crate::api::xmp::XmpMetadata> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<crate::api::xmp::XmpMetadata>::sse_decode(deserializer));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<Vec<crate::api::scan::ImageScanResult>> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<Vec<crate::api::scan::ImageScanResult>>::sse_decode(
+                deserializer,
+            ));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for crate::api::metadata::PngTextChunk {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_chunkType = <crate::api::metadata::PngTextChunkType>::sse_decode(deserializer);
+        let mut var_keyword = <String>::sse_decode(deserializer);
+        let mut var_text = <String>::sse_decode(deserializer);
+        let mut var_languageTag = <Option<String>>::sse_decode(deserializer);
+        let mut var_translatedKeyword = <Option<String>>::sse_decode(deserializer);
+        return crate::api::metadata::PngTextChunk {
+            chunk_type: var_chunkType,
+            keyword: var_keyword,
+            text: var_text,
+            language_tag: var_languageTag,
+            translated_keyword: var_translatedKeyword,
+        };
+    }
+}
+
+impl SseDecode for crate::api::metadata::PngTextChunkType {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <i32>::sse_decode(deserializer);
+        return match inner {
+            0 => crate::api::metadata::PngTextChunkType::Text,
+            1 => crate::api::metadata::PngTextChunkType::CompressedText,
+            2 => crate::api::metadata::PngTextChunkType::InternationalText,
+            _ => unreachable!("Invalid variant for PngTextChunkType: {}", inner),
+        };
+    }
+}
+
+impl SseDecode for crate::api::prompt::PromptDialect {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <i32>::sse_decode(deserializer);
+        return match inner {
+            0 => crate::api::prompt::PromptDialect::NovelAi,
+            1 => crate::api::prompt::PromptDialect::A1111,
+            _ => unreachable!("Invalid variant for PromptDialect: {}", inner),
+        };
+    }
+}
+
+impl SseDecode for crate::api::prompt::PromptTag {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_text = <String>::sse_decode(deserializer);
+        let mut var_weight = <f64>::sse_decode(deserializer);
+        let mut var_kind = <crate::api::prompt::PromptTagKind>::sse_decode(deserializer);
+        let mut var_startsAt = <Option<f64>>::sse_decode(deserializer);
+        let mut var_endsAt = <Option<f64>>::sse_decode(deserializer);
+        return crate::api::prompt::PromptTag {
+            text: var_text,
+            weight: var_weight,
+            kind: var_kind,
+            starts_at: var_startsAt,
+            ends_at: var_endsAt,
+        };
+    }
+}
+
+impl SseDecode for crate::api::prompt::PromptTagKind {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <i32>::sse_decode(deserializer);
+        return match inner {
+            0 => crate::api::prompt::PromptTagKind::Tag,
+            1 => crate::api::prompt::PromptTagKind::Lora,
+            2 => crate::api::prompt::PromptTagKind::Hypernetwork,
+            3 => crate::api::prompt::PromptTagKind::Break,
+            _ => unreachable!("Invalid variant for PromptTagKind: {}", inner),
+        };
+    }
+}
+
+impl SseDecode for (String, String) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_field0 = <String>::sse_decode(deserializer);
+        let mut var_field1 = <String>::sse_decode(deserializer);
+        return (var_field0, var_field1);
+    }
+}
+
+impl SseDecode for (String, u64) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_field0 = <String>::sse_decode(deserializer);
+        let mut var_field1 = <u64>::sse_decode(deserializer);
+        return (var_field0, var_field1);
+    }
+}
+
+impl SseDecode for crate::api::scan::ScanProgress {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_totalToProcess = <u32>::sse_decode(deserializer);
+        let mut var_processed = <u32>::sse_decode(deserializer);
+        let mut var_imageScanResults =
+            <Option<Vec<crate::api::scan::ImageScanResult>>>::sse_decode(deserializer);
+        let mut var_folderScanResult =
+            <Option<crate::api::scan::FolderScanResult>>::sse_decode(deserializer);
+        return crate::api::scan::ScanProgress {
+            total_to_process: var_totalToProcess,
+            processed: var_processed,
+            image_scan_results: var_imageScanResults,
+            folder_scan_result: var_folderScanResult,
+        };
+    }
+}
+
+impl SseDecode for crate::api::metadata::StealthChannel {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <i32>::sse_decode(deserializer);
+        return match inner {
+            0 => crate::api::metadata::StealthChannel::Alpha,
+            1 => crate::api::metadata::StealthChannel::Rgb,
+            _ => unreachable!("Invalid variant for StealthChannel: {}", inner),
+        };
+    }
+}
+
+impl SseDecode for crate::api::strip::StripOptions {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_removeTextChunks = <bool>::sse_decode(deserializer);
+        let mut var_removeExif = <bool>::sse_decode(deserializer);
+        let mut var_removeXmp = <bool>::sse_decode(deserializer);
+        let mut var_removeC2pa = <bool>::sse_decode(deserializer);
+        let mut var_removeStealth = <bool>::sse_decode(deserializer);
+        let mut var_keepPrompt = <bool>::sse_decode(deserializer);
+        return crate::api::strip::StripOptions {
+            remove_text_chunks: var_removeTextChunks,
+            remove_exif: var_removeExif,
+            remove_xmp: var_removeXmp,
+            remove_c2pa: var_removeC2pa,
+            remove_stealth: var_removeStealth,
+            keep_prompt: var_keepPrompt,
+        };
+    }
+}
+
+impl SseDecode for u32 {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        deserializer.cursor.read_u32::<NativeEndian>().unwrap()
+    }
+}
+
+impl SseDecode for u64 {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        deserializer.cursor.read_u64::<NativeEndian>().unwrap()
+    }
+}
+
+impl SseDecode for u8 {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        deserializer.cursor.read_u8().unwrap()
+    }
+}
+
+impl SseDecode for () {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {}
+}
+
+impl SseDecode for crate::api::xmp::XmpMetadata {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_description = <Option<String>>::sse_decode(deserializer);
+        let mut var_subject = <Vec<String>>::sse_decode(deserializer);
+        let mut var_rating = <Option<f64>>::sse_decode(deserializer);
+        let mut var_userComment = <Option<String>>::sse_decode(deserializer);
+        let mut var_properties = <Vec<crate::api::xmp::XmpProperty>>::sse_decode(deserializer);
+        return crate::api::xmp::XmpMetadata {
+            description: var_description,
+            subject: var_subject,
+            rating: var_rating,
+            user_comment: var_userComment,
+            properties: var_properties,
+        };
+    }
+}
+
+impl SseDecode for crate::api::xmp::XmpProperty {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_namespace = <String>::sse_decode(deserializer);
+        let mut var_key = <String>::sse_decode(deserializer);
+        let mut var_value = <String>::sse_decode(deserializer);
+        let mut var_language = <Option<String>>::sse_decode(deserializer);
+        return crate::api::xmp::XmpProperty {
+            namespace: var_namespace,
+            key: var_key,
+            value: var_value,
+            language: var_language,
+        };
+    }
+}
+
+fn pde_ffi_dispatcher_primary_impl(
+    func_id: i32,
+    port: flutter_rust_bridge::for_generated::MessagePort,
+    ptr: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len: i32,
+    data_len: i32,
+) {
+    // Codec=Pde (Serialization + dispatch), see doc to use other codecs
+    match func_id {
+        1 => wire__crate__api__metadata__embed_stealth_metadata_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        4 => wire__crate__api__models__index_model_folder_impl(port, ptr, rust_vec_len, data_len),
+        10 => wire__crate__api__simple__init_app_impl(port, ptr, rust_vec_len, data_len),
+        11 => wire__crate__api__strip__strip_metadata_impl(port, ptr, rust_vec_len, data_len),
+        12 => wire__crate__api__writer__write_metadata_impl(port, ptr, rust_vec_len, data_len),
+        13 => wire__crate__api__scan__scan_folder_impl(port, ptr, rust_vec_len, data_len),
+        _ => unreachable!(),
+    }
+}
+
+fn pde_ffi_dispatcher_sync_impl(
     func_id: i32,
     ptr: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len: i32,
@@ -740,264 +1766,1613 @@ fn pde_ffi_dispatcher_sync_impl(
 ) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
     // Codec=Pde (Serialization + dispatch), see doc to use other codecs
     match func_id {
-        6 => wire__crate__api__metadata__extract_metadata_impl(ptr, rust_vec_len, data_len),
-        7 => wire__crate__api__simple__greet_impl(ptr, rust_vec_len, data_len),
+        2 => wire__crate__api__metadata__extract_metadata_impl(ptr, rust_vec_len, data_len),
+        3 => wire__crate__api__metadata__verify_nai_signature_impl(ptr, rust_vec_len, data_len),
+        5 => wire__crate__api__models__resolve_model_hash_impl(ptr, rust_vec_len, data_len),
+        6 => wire__crate__api__prompt__convert_prompt_impl(ptr, rust_vec_len, data_len),
+        7 => wire__crate__api__prompt__parse_prompt_impl(ptr, rust_vec_len, data_len),
+        8 => wire__crate__api__prompt__render_prompt_impl(ptr, rust_vec_len, data_len),
+        9 => wire__crate__api__simple__greet_impl(ptr, rust_vec_len, data_len),
         _ => unreachable!(),
     }
 }
 
-// Section: rust2dart
+// Section: rust2dart
+
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::a1111::A1111Parameters {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.prompt.into_into_dart().into_dart(),
+            self.negative_prompt.into_into_dart().into_dart(),
+            self.steps.into_into_dart().into_dart(),
+            self.sampler.into_into_dart().into_dart(),
+            self.schedule_type.into_into_dart().into_dart(),
+            self.cfg_scale.into_into_dart().into_dart(),
+            self.seed.into_into_dart().into_dart(),
+            self.width.into_into_dart().into_dart(),
+            self.height.into_into_dart().into_dart(),
+            self.model.into_into_dart().into_dart(),
+            self.model_hash.into_into_dart().into_dart(),
+            self.denoising_strength.into_into_dart().into_dart(),
+            self.settings.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::a1111::A1111Parameters
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::a1111::A1111Parameters>
+    for crate::api::a1111::A1111Parameters
+{
+    fn into_into_dart(self) -> crate::api::a1111::A1111Parameters {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::c2pa::C2paAction {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.action.into_into_dart().into_dart(),
+            self.software_agent.into_into_dart().into_dart(),
+            self.digital_source_type.into_into_dart().into_dart(),
+            self.when.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::api::c2pa::C2paAction {}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::c2pa::C2paAction>
+    for crate::api::c2pa::C2paAction
+{
+    fn into_into_dart(self) -> crate::api::c2pa::C2paAction {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::c2pa::C2paIngredient {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.title.into_into_dart().into_dart(),
+            self.format.into_into_dart().into_dart(),
+            self.relationship.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::c2pa::C2paIngredient
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::c2pa::C2paIngredient>
+    for crate::api::c2pa::C2paIngredient
+{
+    fn into_into_dart(self) -> crate::api::c2pa::C2paIngredient {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::c2pa::C2paManifest {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.label.into_into_dart().into_dart(),
+            self.claim_generator.into_into_dart().into_dart(),
+            self.title.into_into_dart().into_dart(),
+            self.format.into_into_dart().into_dart(),
+            self.actions.into_into_dart().into_dart(),
+            self.ingredients.into_into_dart().into_dart(),
+            self.assertion_labels.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::c2pa::C2paManifest
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::c2pa::C2paManifest>
+    for crate::api::c2pa::C2paManifest
+{
+    fn into_into_dart(self) -> crate::api::c2pa::C2paManifest {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::comfyui::ComfyUiLora {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.name.into_into_dart().into_dart(),
+            self.strength_model.into_into_dart().into_dart(),
+            self.strength_clip.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::comfyui::ComfyUiLora
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::comfyui::ComfyUiLora>
+    for crate::api::comfyui::ComfyUiLora
+{
+    fn into_into_dart(self) -> crate::api::comfyui::ComfyUiLora {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::comfyui::ComfyUiMetadata {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.prompt_graph.into_into_dart().into_dart(),
+            self.workflow.into_into_dart().into_dart(),
+            self.summary.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::comfyui::ComfyUiMetadata
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::comfyui::ComfyUiMetadata>
+    for crate::api::comfyui::ComfyUiMetadata
+{
+    fn into_into_dart(self) -> crate::api::comfyui::ComfyUiMetadata {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::comfyui::ComfyUiSummary {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.positive_prompt.into_into_dart().into_dart(),
+            self.negative_prompt.into_into_dart().into_dart(),
+            self.seed.into_into_dart().into_dart(),
+            self.steps.into_into_dart().into_dart(),
+            self.cfg.into_into_dart().into_dart(),
+            self.sampler_name.into_into_dart().into_dart(),
+            self.scheduler.into_into_dart().into_dart(),
+            self.denoise.into_into_dart().into_dart(),
+            self.checkpoint.into_into_dart().into_dart(),
+            self.loras.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::comfyui::ComfyUiSummary
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::comfyui::ComfyUiSummary>
+    for crate::api::comfyui::ComfyUiSummary
+{
+    fn into_into_dart(self) -> crate::api::comfyui::ComfyUiSummary {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::scan::FolderScanResult {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.folder_path.into_into_dart().into_dart(),
+            self.scan_timestamp.into_into_dart().into_dart(),
+            self.total_image_count.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::scan::FolderScanResult
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::scan::FolderScanResult>
+    for crate::api::scan::FolderScanResult
+{
+    fn into_into_dart(self) -> crate::api::scan::FolderScanResult {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::generator::Generator {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self {
+            Self::NovelAi => 0.into_dart(),
+            Self::A1111 => 1.into_dart(),
+            Self::ComfyUi => 2.into_dart(),
+            Self::InvokeAi => 3.into_dart(),
+            Self::Fooocus => 4.into_dart(),
+            Self::SwarmUi => 5.into_dart(),
+            Self::Midjourney => 6.into_dart(),
+            Self::C2pa => 7.into_dart(),
+            Self::Unknown => 8.into_dart(),
+            _ => unreachable!(),
+        }
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::generator::Generator
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::generator::Generator>
+    for crate::api::generator::Generator
+{
+    fn into_into_dart(self) -> crate::api::generator::Generator {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::metadata::ImageInfo {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.aspect_ratio.into_into_dart().into_dart(),
+            self.generator.into_into_dart().into_dart(),
+            self.metadata_string.into_into_dart().into_dart(),
+            self.nai_metadata.into_into_dart().into_dart(),
+            self.a1111_parameters.into_into_dart().into_dart(),
+            self.fooocus_parameters.into_into_dart().into_dart(),
+            self.swarmui_parameters.into_into_dart().into_dart(),
+            self.comfyui.into_into_dart().into_dart(),
+            self.invokeai.into_into_dart().into_dart(),
+            self.networks.into_into_dart().into_dart(),
+            self.text_chunks.into_into_dart().into_dart(),
+            self.xmp_packet.into_into_dart().into_dart(),
+            self.xmp.into_into_dart().into_dart(),
+            self.c2pa_manifests.into_into_dart().into_dart(),
+            self.stealth_error_kind.into_into_dart().into_dart(),
+            self.stealth_error_message.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::metadata::ImageInfo
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::metadata::ImageInfo>
+    for crate::api::metadata::ImageInfo
+{
+    fn into_into_dart(self) -> crate::api::metadata::ImageInfo {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::scan::ImageScanResult {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.file_path.into_into_dart().into_dart(),
+            self.file_last_modified.into_into_dart().into_dart(),
+            self.image_aspect_ratio.into_into_dart().into_dart(),
+            self.metadata_text.into_into_dart().into_dart(),
+            self.error_kind.into_into_dart().into_dart(),
+            self.error_message.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::scan::ImageScanResult
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::scan::ImageScanResult>
+    for crate::api::scan::ImageScanResult
+{
+    fn into_into_dart(self) -> crate::api::scan::ImageScanResult {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::invokeai::InvokeAiControlLayer {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.adapter_type.into_into_dart().into_dart(),
+            self.model.into_into_dart().into_dart(),
+            self.weight.into_into_dart().into_dart(),
+            self.begin_step_percent.into_into_dart().into_dart(),
+            self.end_step_percent.into_into_dart().into_dart(),
+            self.is_enabled.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::invokeai::InvokeAiControlLayer
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::invokeai::InvokeAiControlLayer>
+    for crate::api::invokeai::InvokeAiControlLayer
+{
+    fn into_into_dart(self) -> crate::api::invokeai::InvokeAiControlLayer {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::invokeai::InvokeAiLora {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.name.into_into_dart().into_dart(),
+            self.weight.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::invokeai::InvokeAiLora
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::invokeai::InvokeAiLora>
+    for crate::api::invokeai::InvokeAiLora
+{
+    fn into_into_dart(self) -> crate::api::invokeai::InvokeAiLora {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::invokeai::InvokeAiMetadata {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.metadata.into_into_dart().into_dart(),
+            self.graph.into_into_dart().into_dart(),
+            self.app_version.into_into_dart().into_dart(),
+            self.generation_mode.into_into_dart().into_dart(),
+            self.positive_prompt.into_into_dart().into_dart(),
+            self.negative_prompt.into_into_dart().into_dart(),
+            self.positive_style_prompt.into_into_dart().into_dart(),
+            self.negative_style_prompt.into_into_dart().into_dart(),
+            self.seed.into_into_dart().into_dart(),
+            self.steps.into_into_dart().into_dart(),
+            self.cfg_scale.into_into_dart().into_dart(),
+            self.cfg_rescale_multiplier.into_into_dart().into_dart(),
+            self.scheduler.into_into_dart().into_dart(),
+            self.width.into_into_dart().into_dart(),
+            self.height.into_into_dart().into_dart(),
+            self.model.into_into_dart().into_dart(),
+            self.model_hash.into_into_dart().into_dart(),
+            self.vae.into_into_dart().into_dart(),
+            self.loras.into_into_dart().into_dart(),
+            self.regional_prompts.into_into_dart().into_dart(),
+            self.control_layers.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::invokeai::InvokeAiMetadata
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::invokeai::InvokeAiMetadata>
+    for crate::api::invokeai::InvokeAiMetadata
+{
+    fn into_into_dart(self) -> crate::api::invokeai::InvokeAiMetadata {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::invokeai::InvokeAiRegionalPrompt {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.name.into_into_dart().into_dart(),
+            self.positive_prompt.into_into_dart().into_dart(),
+            self.negative_prompt.into_into_dart().into_dart(),
+            self.is_enabled.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::invokeai::InvokeAiRegionalPrompt
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::invokeai::InvokeAiRegionalPrompt>
+    for crate::api::invokeai::InvokeAiRegionalPrompt
+{
+    fn into_into_dart(self) -> crate::api::invokeai::InvokeAiRegionalPrompt {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::error::MetadataErrorKind {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self {
+            Self::UnsupportedFormat => 0.into_dart(),
+            Self::DecodeFailed => 1.into_dart(),
+            Self::InvalidDimensions => 2.into_dart(),
+            Self::TruncatedStealthPayload => 3.into_dart(),
+            Self::OversizedStealthPayload => 4.into_dart(),
+            Self::InvalidStealthPayload => 5.into_dart(),
+            Self::NotFound => 6.into_dart(),
+            Self::PermissionDenied => 7.into_dart(),
+            Self::Io => 8.into_dart(),
+            _ => unreachable!(),
+        }
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::error::MetadataErrorKind
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::error::MetadataErrorKind>
+    for crate::api::error::MetadataErrorKind
+{
+    fn into_into_dart(self) -> crate::api::error::MetadataErrorKind {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::models::ModelFile {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.file_path.into_into_dart().into_dart(),
+            self.file_size.into_into_dart().into_dart(),
+            self.file_last_modified.into_into_dart().into_dart(),
+            self.sha256.into_into_dart().into_dart(),
+            self.auto_v1.into_into_dart().into_dart(),
+            self.auto_v2.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::api::models::ModelFile {}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::models::ModelFile>
+    for crate::api::models::ModelFile
+{
+    fn into_into_dart(self) -> crate::api::models::ModelFile {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::nai::NaiCharCaption {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.char_caption.into_into_dart().into_dart(),
+            self.centers.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::nai::NaiCharCaption
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::nai::NaiCharCaption>
+    for crate::api::nai::NaiCharCaption
+{
+    fn into_into_dart(self) -> crate::api::nai::NaiCharCaption {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::nai::NaiCoordinate {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.x.into_into_dart().into_dart(),
+            self.y.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::nai::NaiCoordinate
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::nai::NaiCoordinate>
+    for crate::api::nai::NaiCoordinate
+{
+    fn into_into_dart(self) -> crate::api::nai::NaiCoordinate {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::nai::NaiMetadata {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.title.into_into_dart().into_dart(),
+            self.description.into_into_dart().into_dart(),
+            self.software.into_into_dart().into_dart(),
+            self.source.into_into_dart().into_dart(),
+            self.generation_time.into_into_dart().into_dart(),
+            self.prompt.into_into_dart().into_dart(),
+            self.negative_prompt.into_into_dart().into_dart(),
+            self.seed.into_into_dart().into_dart(),
+            self.steps.into_into_dart().into_dart(),
+            self.sampler.into_into_dart().into_dart(),
+            self.noise_schedule.into_into_dart().into_dart(),
+            self.scale.into_into_dart().into_dart(),
+            self.cfg_rescale.into_into_dart().into_dart(),
+            self.uncond_scale.into_into_dart().into_dart(),
+            self.width.into_into_dart().into_dart(),
+            self.height.into_into_dart().into_dart(),
+            self.n_samples.into_into_dart().into_dart(),
+            self.sm.into_into_dart().into_dart(),
+            self.sm_dyn.into_into_dart().into_dart(),
+            self.request_type.into_into_dart().into_dart(),
+            self.signed_hash.into_into_dart().into_dart(),
+            self.v4_prompt.into_into_dart().into_dart(),
+            self.v4_negative_prompt.into_into_dart().into_dart(),
+            self.reference_information_extracted
+                .into_into_dart()
+                .into_dart(),
+            self.reference_strength.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::api::nai::NaiMetadata {}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::nai::NaiMetadata>
+    for crate::api::nai::NaiMetadata
+{
+    fn into_into_dart(self) -> crate::api::nai::NaiMetadata {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::metadata::NaiSignatureStatus {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self {
+            Self::Valid => 0.into_dart(),
+            Self::Tampered => 1.into_dart(),
+            Self::Unsigned => 2.into_dart(),
+            _ => unreachable!(),
+        }
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::metadata::NaiSignatureStatus
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::metadata::NaiSignatureStatus>
+    for crate::api::metadata::NaiSignatureStatus
+{
+    fn into_into_dart(self) -> crate::api::metadata::NaiSignatureStatus {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::nai::NaiV4Prompt {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.base_caption.into_into_dart().into_dart(),
+            self.char_captions.into_into_dart().into_dart(),
+            self.use_coords.into_into_dart().into_dart(),
+            self.use_order.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::api::nai::NaiV4Prompt {}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::nai::NaiV4Prompt>
+    for crate::api::nai::NaiV4Prompt
+{
+    fn into_into_dart(self) -> crate::api::nai::NaiV4Prompt {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::networks::NetworkKind {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self {
+            Self::Lora => 0.into_dart(),
+            Self::Embedding => 1.into_dart(),
+            Self::Hypernetwork => 2.into_dart(),
+            _ => unreachable!(),
+        }
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::networks::NetworkKind
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::networks::NetworkKind>
+    for crate::api::networks::NetworkKind
+{
+    fn into_into_dart(self) -> crate::api::networks::NetworkKind {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::networks::NetworkReference {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.kind.into_into_dart().into_dart(),
+            self.name.into_into_dart().into_dart(),
+            self.weight.into_into_dart().into_dart(),
+            self.clip_weight.into_into_dart().into_dart(),
+            self.hash.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::networks::NetworkReference
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::networks::NetworkReference>
+    for crate::api::networks::NetworkReference
+{
+    fn into_into_dart(self) -> crate::api::networks::NetworkReference {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::metadata::PngTextChunk {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.chunk_type.into_into_dart().into_dart(),
+            self.keyword.into_into_dart().into_dart(),
+            self.text.into_into_dart().into_dart(),
+            self.language_tag.into_into_dart().into_dart(),
+            self.translated_keyword.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::metadata::PngTextChunk
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::metadata::PngTextChunk>
+    for crate::api::metadata::PngTextChunk
+{
+    fn into_into_dart(self) -> crate::api::metadata::PngTextChunk {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::metadata::PngTextChunkType {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self {
+            Self::Text => 0.into_dart(),
+            Self::CompressedText => 1.into_dart(),
+            Self::InternationalText => 2.into_dart(),
+            _ => unreachable!(),
+        }
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::metadata::PngTextChunkType
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::metadata::PngTextChunkType>
+    for crate::api::metadata::PngTextChunkType
+{
+    fn into_into_dart(self) -> crate::api::metadata::PngTextChunkType {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::prompt::PromptDialect {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self {
+            Self::NovelAi => 0.into_dart(),
+            Self::A1111 => 1.into_dart(),
+            _ => unreachable!(),
+        }
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::prompt::PromptDialect
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::prompt::PromptDialect>
+    for crate::api::prompt::PromptDialect
+{
+    fn into_into_dart(self) -> crate::api::prompt::PromptDialect {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::prompt::PromptTag {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.text.into_into_dart().into_dart(),
+            self.weight.into_into_dart().into_dart(),
+            self.kind.into_into_dart().into_dart(),
+            self.starts_at.into_into_dart().into_dart(),
+            self.ends_at.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::api::prompt::PromptTag {}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::prompt::PromptTag>
+    for crate::api::prompt::PromptTag
+{
+    fn into_into_dart(self) -> crate::api::prompt::PromptTag {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::prompt::PromptTagKind {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self {
+            Self::Tag => 0.into_dart(),
+            Self::Lora => 1.into_dart(),
+            Self::Hypernetwork => 2.into_dart(),
+            Self::Break => 3.into_dart(),
+            _ => unreachable!(),
+        }
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::prompt::PromptTagKind
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::prompt::PromptTagKind>
+    for crate::api::prompt::PromptTagKind
+{
+    fn into_into_dart(self) -> crate::api::prompt::PromptTagKind {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::scan::ScanProgress {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.total_to_process.into_into_dart().into_dart(),
+            self.processed.into_into_dart().into_dart(),
+            self.image_scan_results.into_into_dart().into_dart(),
+            self.folder_scan_result.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::scan::ScanProgress
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::scan::ScanProgress>
+    for crate::api::scan::ScanProgress
+{
+    fn into_into_dart(self) -> crate::api::scan::ScanProgress {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::metadata::StealthChannel {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self {
+            Self::Alpha => 0.into_dart(),
+            Self::Rgb => 1.into_dart(),
+            _ => unreachable!(),
+        }
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::metadata::StealthChannel
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::metadata::StealthChannel>
+    for crate::api::metadata::StealthChannel
+{
+    fn into_into_dart(self) -> crate::api::metadata::StealthChannel {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::strip::StripOptions {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.remove_text_chunks.into_into_dart().into_dart(),
+            self.remove_exif.into_into_dart().into_dart(),
+            self.remove_xmp.into_into_dart().into_dart(),
+            self.remove_c2pa.into_into_dart().into_dart(),
+            self.remove_stealth.into_into_dart().into_dart(),
+            self.keep_prompt.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::strip::StripOptions
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::strip::StripOptions>
+    for crate::api::strip::StripOptions
+{
+    fn into_into_dart(self) -> crate::api::strip::StripOptions {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::xmp::XmpMetadata {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.description.into_into_dart().into_dart(),
+            self.subject.into_into_dart().into_dart(),
+            self.rating.into_into_dart().into_dart(),
+            self.user_comment.into_into_dart().into_dart(),
+            self.properties.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::api::xmp::XmpMetadata {}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::xmp::XmpMetadata>
+    for crate::api::xmp::XmpMetadata
+{
+    fn into_into_dart(self) -> crate::api::xmp::XmpMetadata {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::xmp::XmpProperty {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.namespace.into_into_dart().into_dart(),
+            self.key.into_into_dart().into_dart(),
+            self.value.into_into_dart().into_dart(),
+            self.language.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::api::xmp::XmpProperty {}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::xmp::XmpProperty>
+    for crate::api::xmp::XmpProperty
+{
+    fn into_into_dart(self) -> crate::api::xmp::XmpProperty {
+        self
+    }
+}
+
+impl SseEncode for flutter_rust_bridge::for_generated::anyhow::Error {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(format!("{:?}", self), serializer);
+    }
+}
+
+impl SseEncode for std::collections::HashMap<String, u64> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <Vec<(String, u64)>>::sse_encode(self.into_iter().collect(), serializer);
+    }
+}
+
+impl SseEncode
+    for StreamSink<crate::api::scan::ScanProgress, flutter_rust_bridge::for_generated::SseCodec>
+{
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        unimplemented!("")
+    }
+}
+
+impl SseEncode for String {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <Vec<u8>>::sse_encode(self.into_bytes(), serializer);
+    }
+}
+
+impl SseEncode for crate::api::a1111::A1111Parameters {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.prompt, serializer);
+        <Option<String>>::sse_encode(self.negative_prompt, serializer);
+        <Option<u32>>::sse_encode(self.steps, serializer);
+        <Option<String>>::sse_encode(self.sampler, serializer);
+        <Option<String>>::sse_encode(self.schedule_type, serializer);
+        <Option<f64>>::sse_encode(self.cfg_scale, serializer);
+        <Option<u64>>::sse_encode(self.seed, serializer);
+        <Option<u32>>::sse_encode(self.width, serializer);
+        <Option<u32>>::sse_encode(self.height, serializer);
+        <Option<String>>::sse_encode(self.model, serializer);
+        <Option<String>>::sse_encode(self.model_hash, serializer);
+        <Option<f64>>::sse_encode(self.denoising_strength, serializer);
+        <Vec<(String, String)>>::sse_encode(self.settings, serializer);
+    }
+}
+
+impl SseEncode for bool {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        serializer.cursor.write_u8(self as _).unwrap();
+    }
+}
+
+impl SseEncode for crate::api::c2pa::C2paAction {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.action, serializer);
+        <Option<String>>::sse_encode(self.software_agent, serializer);
+        <Option<String>>::sse_encode(self.digital_source_type, serializer);
+        <Option<String>>::sse_encode(self.when, serializer);
+    }
+}
 
-// Codec=Dco (DartCObject based), see doc to use other codecs
-impl flutter_rust_bridge::IntoDart for FrbWrapper<DataReader> {
-    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
-        flutter_rust_bridge::for_generated::rust_auto_opaque_encode::<_, MoiArc<_>>(self.0)
-            .into_dart()
+impl SseEncode for crate::api::c2pa::C2paIngredient {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <Option<String>>::sse_encode(self.title, serializer);
+        <Option<String>>::sse_encode(self.format, serializer);
+        <Option<String>>::sse_encode(self.relationship, serializer);
+    }
+}
+
+impl SseEncode for crate::api::c2pa::C2paManifest {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.label, serializer);
+        <Option<String>>::sse_encode(self.claim_generator, serializer);
+        <Option<String>>::sse_encode(self.title, serializer);
+        <Option<String>>::sse_encode(self.format, serializer);
+        <Vec<crate::api::c2pa::C2paAction>>::sse_encode(self.actions, serializer);
+        <Vec<crate::api::c2pa::C2paIngredient>>::sse_encode(self.ingredients, serializer);
+        <Vec<String>>::sse_encode(self.assertion_labels, serializer);
     }
 }
-impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for FrbWrapper<DataReader> {}
 
-impl flutter_rust_bridge::IntoIntoDart<FrbWrapper<DataReader>> for DataReader {
-    fn into_into_dart(self) -> FrbWrapper<DataReader> {
-        self.into()
+impl SseEncode for crate::api::comfyui::ComfyUiLora {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.name, serializer);
+        <Option<f64>>::sse_encode(self.strength_model, serializer);
+        <Option<f64>>::sse_encode(self.strength_clip, serializer);
     }
 }
 
-// Codec=Dco (DartCObject based), see doc to use other codecs
-impl flutter_rust_bridge::IntoDart for crate::api::scan::FolderScanResult {
-    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
-        [
-            self.folder_path.into_into_dart().into_dart(),
-            self.scan_timestamp.into_into_dart().into_dart(),
-            self.total_image_count.into_into_dart().into_dart(),
-        ]
-        .into_dart()
+impl SseEncode for crate::api::comfyui::ComfyUiMetadata {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <Option<String>>::sse_encode(self.prompt_graph, serializer);
+        <Option<String>>::sse_encode(self.workflow, serializer);
+        <Option<crate::api::comfyui::ComfyUiSummary>>::sse_encode(self.summary, serializer);
+    }
+}
+
+impl SseEncode for crate::api::comfyui::ComfyUiSummary {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <Option<String>>::sse_encode(self.positive_prompt, serializer);
+        <Option<String>>::sse_encode(self.negative_prompt, serializer);
+        <Option<u64>>::sse_encode(self.seed, serializer);
+        <Option<u32>>::sse_encode(self.steps, serializer);
+        <Option<f64>>::sse_encode(self.cfg, serializer);
+        <Option<String>>::sse_encode(self.sampler_name, serializer);
+        <Option<String>>::sse_encode(self.scheduler, serializer);
+        <Option<f64>>::sse_encode(self.denoise, serializer);
+        <Option<String>>::sse_encode(self.checkpoint, serializer);
+        <Vec<crate::api::comfyui::ComfyUiLora>>::sse_encode(self.loras, serializer);
+    }
+}
+
+impl SseEncode for f64 {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        serializer.cursor.write_f64::<NativeEndian>(self).unwrap();
+    }
+}
+
+impl SseEncode for crate::api::scan::FolderScanResult {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.folder_path, serializer);
+        <u64>::sse_encode(self.scan_timestamp, serializer);
+        <u32>::sse_encode(self.total_image_count, serializer);
+    }
+}
+
+impl SseEncode for crate::api::generator::Generator {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(
+            match self {
+                crate::api::generator::Generator::NovelAi => 0,
+                crate::api::generator::Generator::A1111 => 1,
+                crate::api::generator::Generator::ComfyUi => 2,
+                crate::api::generator::Generator::InvokeAi => 3,
+                crate::api::generator::Generator::Fooocus => 4,
+                crate::api::generator::Generator::SwarmUi => 5,
+                crate::api::generator::Generator::Midjourney => 6,
+                crate::api::generator::Generator::C2pa => 7,
+                crate::api::generator::Generator::Unknown => 8,
+                _ => {
+                    unimplemented!("");
+                }
+            },
+            serializer,
+        );
+    }
+}
+
+impl SseEncode for i32 {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        serializer.cursor.write_i32::<NativeEndian>(self).unwrap();
+    }
+}
+
+impl SseEncode for crate::api::metadata::ImageInfo {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <f64>::sse_encode(self.aspect_ratio, serializer);
+        <crate::api::generator::Generator>::sse_encode(self.generator, serializer);
+        <Option<String>>::sse_encode(self.metadata_string, serializer);
+        <Option<crate::api::nai::NaiMetadata>>::sse_encode(self.nai_metadata, serializer);
+        <Option<crate::api::a1111::A1111Parameters>>::sse_encode(self.a1111_parameters, serializer);
+        <Option<crate::api::a1111::A1111Parameters>>::sse_encode(
+            self.fooocus_parameters,
+            serializer,
+        );
+        <Option<crate::api::a1111::A1111Parameters>>::sse_encode(
+            self.swarmui_parameters,
+            serializer,
+        );
+        <Option<crate::api::comfyui::ComfyUiMetadata>>::sse_encode(self.comfyui, serializer);
+        <Option<crate::api::invokeai::InvokeAiMetadata>>::sse_encode(self.invokeai, serializer);
+        <Vec<crate::api::networks::NetworkReference>>::sse_encode(self.networks, serializer);
+        <Vec<crate::api::metadata::PngTextChunk>>::sse_encode(self.text_chunks, serializer);
+        <Option<String>>::sse_encode(self.xmp_packet, serializer);
+        <Option<crate::api::xmp::XmpMetadata>>::sse_encode(self.xmp, serializer);
+        <Vec<crate::api::c2pa::C2paManifest>>::sse_encode(self.c2pa_manifests, serializer);
+        <Option<crate::api::error::MetadataErrorKind>>::sse_encode(
+            self.stealth_error_kind,
+            serializer,
+        );
+        <Option<String>>::sse_encode(self.stealth_error_message, serializer);
+    }
+}
+
+impl SseEncode for crate::api::scan::ImageScanResult {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.file_path, serializer);
+        <u64>::sse_encode(self.file_last_modified, serializer);
+        <Option<f64>>::sse_encode(self.image_aspect_ratio, serializer);
+        <Option<String>>::sse_encode(self.metadata_text, serializer);
+        <Option<crate::api::error::MetadataErrorKind>>::sse_encode(self.error_kind, serializer);
+        <Option<String>>::sse_encode(self.error_message, serializer);
+    }
+}
+
+impl SseEncode for crate::api::invokeai::InvokeAiControlLayer {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.adapter_type, serializer);
+        <Option<String>>::sse_encode(self.model, serializer);
+        <Option<f64>>::sse_encode(self.weight, serializer);
+        <Option<f64>>::sse_encode(self.begin_step_percent, serializer);
+        <Option<f64>>::sse_encode(self.end_step_percent, serializer);
+        <bool>::sse_encode(self.is_enabled, serializer);
+    }
+}
+
+impl SseEncode for crate::api::invokeai::InvokeAiLora {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.name, serializer);
+        <Option<f64>>::sse_encode(self.weight, serializer);
+    }
+}
+
+impl SseEncode for crate::api::invokeai::InvokeAiMetadata {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <Option<String>>::sse_encode(self.metadata, serializer);
+        <Option<String>>::sse_encode(self.graph, serializer);
+        <Option<String>>::sse_encode(self.app_version, serializer);
+        <Option<String>>::sse_encode(self.generation_mode, serializer);
+        <Option<String>>::sse_encode(self.positive_prompt, serializer);
+        <Option<String>>::sse_encode(self.negative_prompt, serializer);
+        <Option<String>>::sse_encode(self.positive_style_prompt, serializer);
+        <Option<String>>::sse_encode(self.negative_style_prompt, serializer);
+        <Option<u64>>::sse_encode(self.seed, serializer);
+        <Option<u32>>::sse_encode(self.steps, serializer);
+        <Option<f64>>::sse_encode(self.cfg_scale, serializer);
+        <Option<f64>>::sse_encode(self.cfg_rescale_multiplier, serializer);
+        <Option<String>>::sse_encode(self.scheduler, serializer);
+        <Option<u32>>::sse_encode(self.width, serializer);
+        <Option<u32>>::sse_encode(self.height, serializer);
+        <Option<String>>::sse_encode(self.model, serializer);
+        <Option<String>>::sse_encode(self.model_hash, serializer);
+        <Option<String>>::sse_encode(self.vae, serializer);
+        <Vec<crate::api::invokeai::InvokeAiLora>>::sse_encode(self.loras, serializer);
+        <Vec<crate::api::invokeai::InvokeAiRegionalPrompt>>::sse_encode(
+            self.regional_prompts,
+            serializer,
+        );
+        <Vec<crate::api::invokeai::InvokeAiControlLayer>>::sse_encode(
+            self.control_layers,
+            serializer,
+        );
+    }
+}
+
+impl SseEncode for crate::api::invokeai::InvokeAiRegionalPrompt {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <Option<String>>::sse_encode(self.name, serializer);
+        <Option<String>>::sse_encode(self.positive_prompt, serializer);
+        <Option<String>>::sse_encode(self.negative_prompt, serializer);
+        <bool>::sse_encode(self.is_enabled, serializer);
+    }
+}
+
+impl SseEncode for Vec<String> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <String>::sse_encode(item, serializer);
+        }
+    }
+}
+
+impl SseEncode for Vec<crate::api::c2pa::C2paAction> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::c2pa::C2paAction>::sse_encode(item, serializer);
+        }
+    }
+}
+
+impl SseEncode for Vec<crate::api::c2pa::C2paIngredient> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::c2pa::C2paIngredient>::sse_encode(item, serializer);
+        }
+    }
+}
+
+impl SseEncode for Vec<crate::api::c2pa::C2paManifest> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::c2pa::C2paManifest>::sse_encode(item, serializer);
+        }
+    }
+}
+
+impl SseEncode for Vec<crate::api::comfyui::ComfyUiLora> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::comfyui::ComfyUiLora>::sse_encode(item, serializer);
+        }
+    }
+}
+
+impl SseEncode for Vec<crate::api::scan::ImageScanResult> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::scan::ImageScanResult>::sse_encode(item, serializer);
+        }
+    }
+}
+
+impl SseEncode for Vec<crate::api::invokeai::InvokeAiControlLayer> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::invokeai::InvokeAiControlLayer>::sse_encode(item, serializer);
+        }
+    }
+}
+
+impl SseEncode for Vec<crate::api::invokeai::InvokeAiLora> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::invokeai::InvokeAiLora>::sse_encode(item, serializer);
+        }
+    }
+}
+
+impl SseEncode for Vec<crate::api::invokeai::InvokeAiRegionalPrompt> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::invokeai::InvokeAiRegionalPrompt>::sse_encode(item, serializer);
+        }
+    }
+}
+
+impl SseEncode for Vec<crate::api::models::ModelFile> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::models::ModelFile>::sse_encode(item, serializer);
+        }
+    }
+}
+
+impl SseEncode for Vec<crate::api::nai::NaiCharCaption> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::nai::NaiCharCaption>::sse_encode(item, serializer);
+        }
+    }
+}
+
+impl SseEncode for Vec<crate::api::nai::NaiCoordinate> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::nai::NaiCoordinate>::sse_encode(item, serializer);
+        }
+    }
+}
+
+impl SseEncode for Vec<crate::api::networks::NetworkReference> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::networks::NetworkReference>::sse_encode(item, serializer);
+        }
+    }
+}
+
+impl SseEncode for Vec<crate::api::metadata::PngTextChunk> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::metadata::PngTextChunk>::sse_encode(item, serializer);
+        }
+    }
+}
+
+impl SseEncode for Vec<f64> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <f64>::sse_encode(item, serializer);
+        }
+    }
+}
+
+impl SseEncode for Vec<u8> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <u8>::sse_encode(item, serializer);
+        }
+    }
+}
+
+impl SseEncode for Vec<crate::api::prompt::PromptTag> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::prompt::PromptTag>::sse_encode(item, serializer);
+        }
     }
 }
-impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
-    for crate::api::scan::FolderScanResult
-{
-}
-impl flutter_rust_bridge::IntoIntoDart<crate::api::scan::FolderScanResult>
-    for crate::api::scan::FolderScanResult
-{
-    fn into_into_dart(self) -> crate::api::scan::FolderScanResult {
-        self
+
+impl SseEncode for Vec<(String, String)> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <(String, String)>::sse_encode(item, serializer);
+        }
     }
 }
-// Codec=Dco (DartCObject based), see doc to use other codecs
-impl flutter_rust_bridge::IntoDart for crate::api::metadata::ImageInfo {
-    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
-        [
-            self.aspect_ratio.into_into_dart().into_dart(),
-            self.metadata_string.into_into_dart().into_dart(),
-        ]
-        .into_dart()
+
+impl SseEncode for Vec<(String, u64)> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <(String, u64)>::sse_encode(item, serializer);
+        }
     }
 }
-impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
-    for crate::api::metadata::ImageInfo
-{
-}
-impl flutter_rust_bridge::IntoIntoDart<crate::api::metadata::ImageInfo>
-    for crate::api::metadata::ImageInfo
-{
-    fn into_into_dart(self) -> crate::api::metadata::ImageInfo {
-        self
+
+impl SseEncode for Vec<crate::api::xmp::XmpProperty> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::xmp::XmpProperty>::sse_encode(item, serializer);
+        }
     }
 }
-// Codec=Dco (DartCObject based), see doc to use other codecs
-impl flutter_rust_bridge::IntoDart for crate::api::scan::ImageScanResult {
-    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
-        [
-            self.file_path.into_into_dart().into_dart(),
-            self.file_last_modified.into_into_dart().into_dart(),
-            self.image_aspect_ratio.into_into_dart().into_dart(),
-            self.metadata_text.into_into_dart().into_dart(),
-        ]
-        .into_dart()
+
+impl SseEncode for crate::api::error::MetadataErrorKind {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(
+            match self {
+                crate::api::error::MetadataErrorKind::UnsupportedFormat => 0,
+                crate::api::error::MetadataErrorKind::DecodeFailed => 1,
+                crate::api::error::MetadataErrorKind::InvalidDimensions => 2,
+                crate::api::error::MetadataErrorKind::TruncatedStealthPayload => 3,
+                crate::api::error::MetadataErrorKind::OversizedStealthPayload => 4,
+                crate::api::error::MetadataErrorKind::InvalidStealthPayload => 5,
+                crate::api::error::MetadataErrorKind::NotFound => 6,
+                crate::api::error::MetadataErrorKind::PermissionDenied => 7,
+                crate::api::error::MetadataErrorKind::Io => 8,
+                _ => {
+                    unimplemented!("");
+                }
+            },
+            serializer,
+        );
     }
 }
-impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
-    for crate::api::scan::ImageScanResult
-{
+
+impl SseEncode for crate::api::models::ModelFile {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.file_path, serializer);
+        <u64>::sse_encode(self.file_size, serializer);
+        <u64>::sse_encode(self.file_last_modified, serializer);
+        <String>::sse_encode(self.sha256, serializer);
+        <String>::sse_encode(self.auto_v1, serializer);
+        <String>::sse_encode(self.auto_v2, serializer);
+    }
 }
-impl flutter_rust_bridge::IntoIntoDart<crate::api::scan::ImageScanResult>
-    for crate::api::scan::ImageScanResult
-{
-    fn into_into_dart(self) -> crate::api::scan::ImageScanResult {
-        self
+
+impl SseEncode for crate::api::nai::NaiCharCaption {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.char_caption, serializer);
+        <Vec<crate::api::nai::NaiCoordinate>>::sse_encode(self.centers, serializer);
     }
 }
-// Codec=Dco (DartCObject based), see doc to use other codecs
-impl flutter_rust_bridge::IntoDart for crate::api::scan::ScanProgress {
-    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
-        [
-            self.total_to_process.into_into_dart().into_dart(),
-            self.processed.into_into_dart().into_dart(),
-            self.image_scan_results.into_into_dart().into_dart(),
-            self.folder_scan_result.into_into_dart().into_dart(),
-        ]
-        .into_dart()
+
+impl SseEncode for crate::api::nai::NaiCoordinate {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <f64>::sse_encode(self.x, serializer);
+        <f64>::sse_encode(self.y, serializer);
     }
 }
-impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
-    for crate::api::scan::ScanProgress
-{
+
+impl SseEncode for crate::api::nai::NaiMetadata {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <Option<String>>::sse_encode(self.title, serializer);
+        <Option<String>>::sse_encode(self.description, serializer);
+        <Option<String>>::sse_encode(self.software, serializer);
+        <Option<String>>::sse_encode(self.source, serializer);
+        <Option<f64>>::sse_encode(self.generation_time, serializer);
+        <Option<String>>::sse_encode(self.prompt, serializer);
+        <Option<String>>::sse_encode(self.negative_prompt, serializer);
+        <Option<u64>>::sse_encode(self.seed, serializer);
+        <Option<u32>>::sse_encode(self.steps, serializer);
+        <Option<String>>::sse_encode(self.sampler, serializer);
+        <Option<String>>::sse_encode(self.noise_schedule, serializer);
+        <Option<f64>>::sse_encode(self.scale, serializer);
+        <Option<f64>>::sse_encode(self.cfg_rescale, serializer);
+        <Option<f64>>::sse_encode(self.uncond_scale, serializer);
+        <Option<u32>>::sse_encode(self.width, serializer);
+        <Option<u32>>::sse_encode(self.height, serializer);
+        <Option<u32>>::sse_encode(self.n_samples, serializer);
+        <Option<bool>>::sse_encode(self.sm, serializer);
+        <Option<bool>>::sse_encode(self.sm_dyn, serializer);
+        <Option<String>>::sse_encode(self.request_type, serializer);
+        <Option<String>>::sse_encode(self.signed_hash, serializer);
+        <Option<crate::api::nai::NaiV4Prompt>>::sse_encode(self.v4_prompt, serializer);
+        <Option<crate::api::nai::NaiV4Prompt>>::sse_encode(self.v4_negative_prompt, serializer);
+        <Vec<f64>>::sse_encode(self.reference_information_extracted, serializer);
+        <Vec<f64>>::sse_encode(self.reference_strength, serializer);
+    }
+}
+
+impl SseEncode for crate::api::metadata::NaiSignatureStatus {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(
+            match self {
+                crate::api::metadata::NaiSignatureStatus::Valid => 0,
+                crate::api::metadata::NaiSignatureStatus::Tampered => 1,
+                crate::api::metadata::NaiSignatureStatus::Unsigned => 2,
+                _ => {
+                    unimplemented!("");
+                }
+            },
+            serializer,
+        );
+    }
 }
-impl flutter_rust_bridge::IntoIntoDart<crate::api::scan::ScanProgress>
-    for crate::api::scan::ScanProgress
-{
-    fn into_into_dart(self) -> crate::api::scan::ScanProgress {
-        self
+
+impl SseEncode for crate::api::nai::NaiV4Prompt {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.base_caption, serializer);
+        <Vec<crate::api::nai::NaiCharCaption>>::sse_encode(self.char_captions, serializer);
+        <Option<bool>>::sse_encode(self.use_coords, serializer);
+        <Option<bool>>::sse_encode(self.use_order, serializer);
     }
 }
 
-impl SseEncode for flutter_rust_bridge::for_generated::anyhow::Error {
+impl SseEncode for crate::api::networks::NetworkKind {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <String>::sse_encode(format!("{:?}", self), serializer);
+        <i32>::sse_encode(
+            match self {
+                crate::api::networks::NetworkKind::Lora => 0,
+                crate::api::networks::NetworkKind::Embedding => 1,
+                crate::api::networks::NetworkKind::Hypernetwork => 2,
+                _ => {
+                    unimplemented!("");
+                }
+            },
+            serializer,
+        );
     }
 }
 
-impl SseEncode for DataReader {
+impl SseEncode for crate::api::networks::NetworkReference {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<DataReader>>>::sse_encode(flutter_rust_bridge::for_generated::rust_auto_opaque_encode::<_, MoiArc<_>>(self), serializer);
+        <crate::api::networks::NetworkKind>::sse_encode(self.kind, serializer);
+        <String>::sse_encode(self.name, serializer);
+        <Option<f64>>::sse_encode(self.weight, serializer);
+        <Option<f64>>::sse_encode(self.clip_weight, serializer);
+        <Option<String>>::sse_encode(self.hash, serializer);
     }
 }
 
-impl SseEncode for std::collections::HashMap<String, u64> {
+impl SseEncode for Option<String> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <Vec<(String, u64)>>::sse_encode(self.into_iter().collect(), serializer);
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <String>::sse_encode(value, serializer);
+        }
     }
 }
 
-impl SseEncode
-    for RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<DataReader>>
-{
+impl SseEncode for Option<crate::api::a1111::A1111Parameters> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        let (ptr, size) = self.sse_encode_raw();
-        <usize>::sse_encode(ptr, serializer);
-        <i32>::sse_encode(size, serializer);
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <crate::api::a1111::A1111Parameters>::sse_encode(value, serializer);
+        }
     }
 }
 
-impl SseEncode
-    for StreamSink<crate::api::scan::ScanProgress, flutter_rust_bridge::for_generated::SseCodec>
-{
+impl SseEncode for Option<bool> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        unimplemented!("")
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <bool>::sse_encode(value, serializer);
+        }
     }
 }
 
-impl SseEncode for String {
+impl SseEncode for Option<crate::api::comfyui::ComfyUiMetadata> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <Vec<u8>>::sse_encode(self.into_bytes(), serializer);
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <crate::api::comfyui::ComfyUiMetadata>::sse_encode(value, serializer);
+        }
     }
 }
 
-impl SseEncode for f64 {
+impl SseEncode for Option<crate::api::comfyui::ComfyUiSummary> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        serializer.cursor.write_f64::<NativeEndian>(self).unwrap();
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <crate::api::comfyui::ComfyUiSummary>::sse_encode(value, serializer);
+        }
     }
 }
 
-impl SseEncode for crate::api::scan::FolderScanResult {
+impl SseEncode for Option<f64> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <String>::sse_encode(self.folder_path, serializer);
-        <u64>::sse_encode(self.scan_timestamp, serializer);
-        <u32>::sse_encode(self.total_image_count, serializer);
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <f64>::sse_encode(value, serializer);
+        }
     }
 }
 
-impl SseEncode for i32 {
+impl SseEncode for Option<crate::api::scan::FolderScanResult> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        serializer.cursor.write_i32::<NativeEndian>(self).unwrap();
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <crate::api::scan::FolderScanResult>::sse_encode(value, serializer);
+        }
     }
 }
 
-impl SseEncode for crate::api::metadata::ImageInfo {
+impl SseEncode for Option<crate::api::invokeai::InvokeAiMetadata> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <f64>::sse_encode(self.aspect_ratio, serializer);
-        <Option<String>>::sse_encode(self.metadata_string, serializer);
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <crate::api::invokeai::InvokeAiMetadata>::sse_encode(value, serializer);
+        }
     }
 }
 
-impl SseEncode for crate::api::scan::ImageScanResult {
+impl SseEncode for Option<crate::api::error::MetadataErrorKind> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <String>::sse_encode(self.file_path, serializer);
-        <u64>::sse_encode(self.file_last_modified, serializer);
-        <Option<f64>>::sse_encode(self.image_aspect_ratio, serializer);
-        <Option<String>>::sse_encode(self.metadata_text, serializer);
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <crate::api::error::MetadataErrorKind>::sse_encode(value, serializer);
+        }
     }
 }
 
-impl SseEncode for Vec<crate::api::scan::ImageScanResult> {
+impl SseEncode for Option<crate::api::models::ModelFile> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <i32>::sse_encode(self.len() as _, serializer);
-        for item in self {
-            <crate::api::scan::ImageScanResult>::sse_encode(item, serializer);
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <crate::api::models::ModelFile>::sse_encode(value, serializer);
         }
     }
 }
 
-impl SseEncode for Vec<u8> {
+impl SseEncode for Option<crate::api::nai::NaiMetadata> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <i32>::sse_encode(self.len() as _, serializer);
-        for item in self {
-            <u8>::sse_encode(item, serializer);
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <crate::api::nai::NaiMetadata>::sse_encode(value, serializer);
         }
     }
 }
 
-impl SseEncode for Vec<(String, u64)> {
+impl SseEncode for Option<crate::api::nai::NaiV4Prompt> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <i32>::sse_encode(self.len() as _, serializer);
-        for item in self {
-            <(String, u64)>::sse_encode(item, serializer);
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <crate::api::nai::NaiV4Prompt>::sse_encode(value, serializer);
         }
     }
 }
 
-impl SseEncode for Option<String> {
+impl SseEncode for Option<u32> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
         <bool>::sse_encode(self.is_some(), serializer);
         if let Some(value) = self {
-            <String>::sse_encode(value, serializer);
+            <u32>::sse_encode(value, serializer);
         }
     }
 }
 
-impl SseEncode for Option<f64> {
+impl SseEncode for Option<u64> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
         <bool>::sse_encode(self.is_some(), serializer);
         if let Some(value) = self {
-            <f64>::sse_encode(value, serializer);
+            <u64>::sse_encode(value, serializer);
         }
     }
 }
 
-impl SseEncode for Option<crate::api::scan::FolderScanResult> {
+impl SseEncode for Option<crate::api::xmp::XmpMetadata> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
         <bool>::sse_encode(self.is_some(), serializer);
         if let Some(value) = self {
-            <crate::api::scan::FolderScanResult>::sse_encode(value, serializer);
+            <crate::api::xmp::XmpMetadata>::sse_encode(value, serializer);
         }
     }
 }
@@ -1012,6 +3387,87 @@ impl SseEncode for Option<Vec<crate::api::scan::ImageScanResult>> {
     }
 }
 
+impl SseEncode for crate::api::metadata::PngTextChunk {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <crate::api::metadata::PngTextChunkType>::sse_encode(self.chunk_type, serializer);
+        <String>::sse_encode(self.keyword, serializer);
+        <String>::sse_encode(self.text, serializer);
+        <Option<String>>::sse_encode(self.language_tag, serializer);
+        <Option<String>>::sse_encode(self.translated_keyword, serializer);
+    }
+}
+
+impl SseEncode for crate::api::metadata::PngTextChunkType {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(
+            match self {
+                crate::api::metadata::PngTextChunkType::Text => 0,
+                crate::api::metadata::PngTextChunkType::CompressedText => 1,
+                crate::api::metadata::PngTextChunkType::InternationalText => 2,
+                _ => {
+                    unimplemented!("");
+                }
+            },
+            serializer,
+        );
+    }
+}
+
+impl SseEncode for crate::api::prompt::PromptDialect {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(
+            match self {
+                crate::api::prompt::PromptDialect::NovelAi => 0,
+                crate::api::prompt::PromptDialect::A1111 => 1,
+                _ => {
+                    unimplemented!("");
+                }
+            },
+            serializer,
+        );
+    }
+}
+
+impl SseEncode for crate::api::prompt::PromptTag {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.text, serializer);
+        <f64>::sse_encode(self.weight, serializer);
+        <crate::api::prompt::PromptTagKind>::sse_encode(self.kind, serializer);
+        <Option<f64>>::sse_encode(self.starts_at, serializer);
+        <Option<f64>>::sse_encode(self.ends_at, serializer);
+    }
+}
+
+impl SseEncode for crate::api::prompt::PromptTagKind {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(
+            match self {
+                crate::api::prompt::PromptTagKind::Tag => 0,
+                crate::api::prompt::PromptTagKind::Lora => 1,
+                crate::api::prompt::PromptTagKind::Hypernetwork => 2,
+                crate::api::prompt::PromptTagKind::Break => 3,
+                _ => {
+                    unimplemented!("");
+                }
+            },
+            serializer,
+        );
+    }
+}
+
+impl SseEncode for (String, String) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.0, serializer);
+        <String>::sse_encode(self.1, serializer);
+    }
+}
+
 impl SseEncode for (String, u64) {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
@@ -1036,6 +3492,34 @@ impl SseEncode for crate::api::scan::ScanProgress {
     }
 }
 
+impl SseEncode for crate::api::metadata::StealthChannel {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(
+            match self {
+                crate::api::metadata::StealthChannel::Alpha => 0,
+                crate::api::metadata::StealthChannel::Rgb => 1,
+                _ => {
+                    unimplemented!("");
+                }
+            },
+            serializer,
+        );
+    }
+}
+
+impl SseEncode for crate::api::strip::StripOptions {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.remove_text_chunks, serializer);
+        <bool>::sse_encode(self.remove_exif, serializer);
+        <bool>::sse_encode(self.remove_xmp, serializer);
+        <bool>::sse_encode(self.remove_c2pa, serializer);
+        <bool>::sse_encode(self.remove_stealth, serializer);
+        <bool>::sse_encode(self.keep_prompt, serializer);
+    }
+}
+
 impl SseEncode for u32 {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
@@ -1062,20 +3546,24 @@ impl SseEncode for () {
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {}
 }
 
-impl SseEncode for usize {
+impl SseEncode for crate::api::xmp::XmpMetadata {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        serializer
-            .cursor
-            .write_u64::<NativeEndian>(self as _)
-            .unwrap();
+        <Option<String>>::sse_encode(self.description, serializer);
+        <Vec<String>>::sse_encode(self.subject, serializer);
+        <Option<f64>>::sse_encode(self.rating, serializer);
+        <Option<String>>::sse_encode(self.user_comment, serializer);
+        <Vec<crate::api::xmp::XmpProperty>>::sse_encode(self.properties, serializer);
     }
 }
 
-impl SseEncode for bool {
+impl SseEncode for crate::api::xmp::XmpProperty {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        serializer.cursor.write_u8(self as _).unwrap();
+        <String>::sse_encode(self.namespace, serializer);
+        <String>::sse_encode(self.key, serializer);
+        <String>::sse_encode(self.value, serializer);
+        <Option<String>>::sse_encode(self.language, serializer);
     }
 }
 
@@ -1087,7 +3575,6 @@ mod io {
     // Section: imports
 
     use super::*;
-    use crate::api::metadata::*;
     use flutter_rust_bridge::for_generated::byteorder::{
         NativeEndian, ReadBytesExt, WriteBytesExt,
     };
@@ -1097,20 +3584,6 @@ mod io {
     // Section: boilerplate
 
     flutter_rust_bridge::frb_generated_boilerplate_io!();
-
-    #[unsafe(no_mangle)]
-    pub extern "C" fn frbgen_metadata_gallery_rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDataReader(
-        ptr: *const std::ffi::c_void,
-    ) {
-        MoiArc::<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<DataReader>>::increment_strong_count(ptr as _);
-    }
-
-    #[unsafe(no_mangle)]
-    pub extern "C" fn frbgen_metadata_gallery_rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDataReader(
-        ptr: *const std::ffi::c_void,
-    ) {
-        MoiArc::<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<DataReader>>::decrement_strong_count(ptr as _);
-    }
 }
 #[cfg(not(target_family = "wasm"))]
 pub use io::*;
@@ -1124,7 +3597,6 @@ mod web {
     // Section: imports
 
     use super::*;
-    use crate::api::metadata::*;
     use flutter_rust_bridge::for_generated::byteorder::{
         NativeEndian, ReadBytesExt, WriteBytesExt,
     };
@@ -1136,20 +3608,6 @@ mod web {
     // Section: boilerplate
 
     flutter_rust_bridge::frb_generated_boilerplate_web!();
-
-    #[wasm_bindgen]
-    pub fn rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDataReader(
-        ptr: *const std::ffi::c_void,
-    ) {
-        MoiArc::<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<DataReader>>::increment_strong_count(ptr as _);
-    }
-
-    #[wasm_bindgen]
-    pub fn rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDataReader(
-        ptr: *const std::ffi::c_void,
-    ) {
-        MoiArc::<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<DataReader>>::decrement_strong_count(ptr as _);
-    }
 }
 #[cfg(target_family = "wasm")]
 pub use web::*;
@@ -1,2 +1,5 @@
 pub mod api;
+mod fields;
 mod frb_generated;
+#[cfg(test)]
+mod test_fixtures;
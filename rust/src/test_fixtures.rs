@@ -0,0 +1,92 @@
+//! 各模块测试共用的图片和元数据构造函数
+
+use std::io::Cursor;
+
+use image::{DynamicImage, ImageFormat};
+
+use crate::api::metadata::{PngTextChunk, PngTextChunkType, PNG_SIGNATURE};
+use crate::api::writer::{
+    complete_png_chunks, complete_webp_chunks, riff_container, write_png_chunk, write_webp_chunk,
+};
+
+/// NAI 隐写数据中的 JSON，Comment 本身也是 JSON 字符串
+pub(crate) const NAI_JSON: &str =
+    r#"{"Software":"NovelAI","Comment":"{\"prompt\":\"1girl\",\"uc\":\"lowres\",\"seed\":1234}"}"#;
+
+/// 渐变图片，`alpha` 为 `false` 时输出 RGB
+pub(crate) fn source_image(width: u32, height: u32, format: ImageFormat, alpha: bool) -> Vec<u8> {
+    let img = image::RgbaImage::from_fn(width, height, |x, y| {
+        image::Rgba([(x * 7) as u8, (y * 13) as u8, (x ^ y) as u8, 200])
+    });
+    let img = if alpha {
+        DynamicImage::ImageRgba8(img)
+    } else {
+        DynamicImage::ImageRgb8(DynamicImage::ImageRgba8(img).to_rgb8())
+    };
+    let mut output = Cursor::new(vec![]);
+    img.write_to(&mut output, format).unwrap();
+    output.into_inner()
+}
+
+pub(crate) fn make_text_chunk(keyword: &str, text: &str) -> PngTextChunk {
+    PngTextChunk {
+        chunk_type: PngTextChunkType::Text,
+        keyword: keyword.to_string(),
+        text: text.to_string(),
+        language_tag: None,
+        translated_keyword: None,
+    }
+}
+
+/// 在 IEND 之前插入一个数据块
+pub(crate) fn insert_png_chunk(png: &[u8], chunk_type: &[u8; 4], data: &[u8]) -> Vec<u8> {
+    let (chunks, _) = complete_png_chunks(png).unwrap();
+    let mut output = PNG_SIGNATURE.to_vec();
+    for (existing_type, existing_data) in chunks {
+        if &existing_type == b"IEND" {
+            write_png_chunk(&mut output, chunk_type, data);
+        }
+        write_png_chunk(&mut output, &existing_type, existing_data);
+    }
+    output
+}
+
+/// 在 SOI 之后插入一个段
+pub(crate) fn insert_jpeg_segment(jpeg: &[u8], marker: u8, data: &[u8]) -> Vec<u8> {
+    let mut output = jpeg[..2].to_vec();
+    output.extend_from_slice(&[0xFF, marker]);
+    output.extend_from_slice(&(data.len() as u16 + 2).to_be_bytes());
+    output.extend_from_slice(data);
+    output.extend_from_slice(&jpeg[2..]);
+    output
+}
+
+/// 在末尾追加一个块，不修改 VP8X 标志
+pub(crate) fn append_webp_chunk(webp: &[u8], fourcc: &[u8; 4], data: &[u8]) -> Vec<u8> {
+    let mut body = b"WEBP".to_vec();
+    for (existing_fourcc, existing_data) in complete_webp_chunks(webp).unwrap() {
+        write_webp_chunk(&mut body, &existing_fourcc, existing_data);
+    }
+    write_webp_chunk(&mut body, fourcc, data);
+    riff_container(&body)
+}
+
+pub(crate) fn jumbf_box(box_type: &[u8; 4], content: &[u8]) -> Vec<u8> {
+    let mut output = ((content.len() + 8) as u32).to_be_bytes().to_vec();
+    output.extend_from_slice(box_type);
+    output.extend_from_slice(content);
+    output
+}
+
+/// 带标签的 JUMBF 超级盒，类型 UUID 不参与解析，开关 0x03 表示有标签
+pub(crate) fn superbox(label: &str, children: &[Vec<u8>]) -> Vec<u8> {
+    let mut description = vec![0; 16];
+    description.push(0x03);
+    description.extend_from_slice(label.as_bytes());
+    description.push(0);
+    let mut content = jumbf_box(b"jumd", &description);
+    children
+        .iter()
+        .for_each(|child| content.extend_from_slice(child));
+    jumbf_box(b"jumb", &content)
+}